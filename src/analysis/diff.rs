@@ -0,0 +1,398 @@
+//! Finding the words a change to a strategy fixed or broke.
+//!
+//! Comparing summaries says whether a strategy got better, but not where. A
+//! [`RunDiff`] lines up two runs of the same strategies word by word and
+//! lists every word whose outcome changed: the ones that are now solved,
+//! the ones that are now missed, and the ones that need more or fewer
+//! guesses than before.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use wordle_rs::{strategy::stupid::Stupid, Harness, WordSelection};
+//! let run = || {
+//!     Harness::new()
+//!         .verbose(false)
+//!         .add_strategy(Box::new(Stupid), None)
+//!         .words(WordSelection::Range(0..20))
+//!         .run()
+//! };
+//! let (before, after) = (run()?, run()?);
+//!
+//! let diff = after.diff(&before);
+//! assert!(diff.is_unchanged());
+//! println!("{}", diff);
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::{collections::HashMap, fmt::Display};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Perf, Word};
+
+/// How one strategy did on one word in two runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct WordChange {
+    /// The word.
+    pub word: Word,
+
+    /// The number of guesses used to solve the word in the baseline run, or
+    /// `None` if it was missed.
+    pub before: Option<usize>,
+
+    /// The number of guesses used to solve the word in the new run, or
+    /// `None` if it was missed.
+    pub after: Option<usize>,
+}
+
+impl WordChange {
+    /// Indicates if the word was missed in the baseline run and solved in
+    /// the new one.
+    pub fn newly_solved(&self) -> bool {
+        self.before.is_none() && self.after.is_some()
+    }
+
+    /// Indicates if the word was solved in the baseline run and missed in
+    /// the new one.
+    pub fn newly_missed(&self) -> bool {
+        self.before.is_some() && self.after.is_none()
+    }
+
+    /// Gets how many more guesses the new run used, which is negative if it
+    /// used fewer, or `None` unless the word was solved in both runs.
+    pub fn guess_delta(&self) -> Option<i32> {
+        Some(self.after? as i32 - self.before? as i32)
+    }
+}
+
+/// The words whose outcome changed for one strategy between two runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct StrategyDiff {
+    /// The name of the strategy.
+    pub name: String,
+
+    /// The version of the strategy in the baseline run.
+    pub baseline_version: String,
+
+    /// The version of the strategy in the new run.
+    pub version: String,
+
+    /// The number of words the strategy tried in both runs.
+    pub num_compared: usize,
+
+    /// Every word tried in both runs with a different outcome, in
+    /// alphabetical order.
+    pub changes: Vec<WordChange>,
+}
+
+impl StrategyDiff {
+    /// Compares how the strategy did on each word in `baseline` and
+    /// `current`, two runs of the same strategy.
+    fn new(baseline: &Perf, current: &Perf) -> Self {
+        let mut before = HashMap::new();
+        for (word, attempts) in baseline.tries() {
+            before
+                .entry(*word)
+                .or_insert_with(|| attempts.solved(word).then(|| attempts.len()));
+        }
+
+        let mut num_compared = 0;
+        let mut changes = Vec::new();
+        for (word, attempts) in current.tries() {
+            // Only the first attempt on a word counts, like in the baseline.
+            let before = match before.remove(word) {
+                Some(before) => before,
+                None => continue,
+            };
+            num_compared += 1;
+
+            let after = attempts.solved(word).then(|| attempts.len());
+            if before != after {
+                changes.push(WordChange {
+                    word: *word,
+                    before,
+                    after,
+                });
+            }
+        }
+        changes.sort_by_key(|change| change.word);
+
+        StrategyDiff {
+            name: current.strategy_name().to_string(),
+            baseline_version: baseline.strategy_version().to_string(),
+            version: current.strategy_version().to_string(),
+            num_compared,
+            changes,
+        }
+    }
+
+    /// Gets the words that were missed before and are solved now.
+    pub fn newly_solved(&self) -> impl Iterator<Item = &WordChange> + '_ {
+        self.changes.iter().filter(|change| change.newly_solved())
+    }
+
+    /// Gets the words that were solved before and are missed now.
+    pub fn newly_missed(&self) -> impl Iterator<Item = &WordChange> + '_ {
+        self.changes.iter().filter(|change| change.newly_missed())
+    }
+
+    /// Gets the words that are solved in fewer guesses than before.
+    pub fn fewer_guesses(&self) -> impl Iterator<Item = &WordChange> + '_ {
+        self.changes
+            .iter()
+            .filter(|change| change.guess_delta().map_or(false, |delta| delta < 0))
+    }
+
+    /// Gets the words that need more guesses than before.
+    pub fn more_guesses(&self) -> impl Iterator<Item = &WordChange> + '_ {
+        self.changes
+            .iter()
+            .filter(|change| change.guess_delta().map_or(false, |delta| delta > 0))
+    }
+}
+
+impl Display for StrategyDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} v{}", self.name, self.baseline_version)?;
+        if self.version != self.baseline_version {
+            write!(f, " -> v{}", self.version)?;
+        }
+        writeln!(
+            f,
+            ": {} of {} words changed",
+            self.changes.len(),
+            self.num_compared
+        )?;
+
+        let guesses = |n: Option<usize>| n.map_or_else(|| "missed".to_string(), |n| n.to_string());
+        let sections: [(&str, Vec<&WordChange>); 4] = [
+            ("Newly solved", self.newly_solved().collect()),
+            ("Newly missed", self.newly_missed().collect()),
+            ("Fewer guesses", self.fewer_guesses().collect()),
+            ("More guesses", self.more_guesses().collect()),
+        ];
+        for (title, changes) in sections {
+            if changes.is_empty() {
+                continue;
+            }
+            writeln!(f, "  {} ({}):", title, changes.len())?;
+            for change in changes {
+                writeln!(
+                    f,
+                    "    {}  {} -> {}",
+                    change.word,
+                    guesses(change.before),
+                    guesses(change.after)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The per-word differences between two runs of the same strategies.
+///
+/// Strategies are matched by name and version, or by name alone when the
+/// version changed. The [`Display`] implementation lists the changed words
+/// of each strategy, grouped by how they changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct RunDiff {
+    strategies: Vec<StrategyDiff>,
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl RunDiff {
+    /// Compares the strategies in `current` against the same strategies in
+    /// `baseline`.
+    pub fn from_perfs(baseline: &[Perf], current: &[Perf]) -> Self {
+        let mut unmatched: Vec<Option<&Perf>> = baseline.iter().map(Some).collect();
+        let mut take = |perf: &Perf, same_version: bool| {
+            unmatched
+                .iter_mut()
+                .find(|other| {
+                    other.map_or(false, |other| {
+                        other.strategy_name() == perf.strategy_name()
+                            && (!same_version
+                                || other.strategy_version() == perf.strategy_version())
+                    })
+                })
+                .and_then(Option::take)
+        };
+
+        // Exact matches go first, so that running two versions of a strategy
+        // side by side compares each against itself.
+        let exact: Vec<Option<&Perf>> = current.iter().map(|perf| take(perf, true)).collect();
+        let mut strategies = Vec::new();
+        let mut added = Vec::new();
+        for (perf, matched) in current.iter().zip(exact) {
+            match matched.or_else(|| take(perf, false)) {
+                Some(other) => strategies.push(StrategyDiff::new(other, perf)),
+                None => added.push(perf.to_string()),
+            }
+        }
+        let removed = unmatched
+            .into_iter()
+            .flatten()
+            .map(ToString::to_string)
+            .collect();
+
+        RunDiff {
+            strategies,
+            added,
+            removed,
+        }
+    }
+
+    /// Gets the differences for each strategy in both runs, in the order of
+    /// the new run.
+    pub fn strategies(&self) -> &[StrategyDiff] {
+        &self.strategies
+    }
+
+    /// Gets the strategies that are only in the new run.
+    pub fn added(&self) -> &[String] {
+        &self.added
+    }
+
+    /// Gets the strategies that are only in the baseline run.
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    /// Indicates if every strategy did exactly the same on every word tried
+    /// in both runs.
+    pub fn is_unchanged(&self) -> bool {
+        self.strategies
+            .iter()
+            .all(|strategy| strategy.changes.is_empty())
+    }
+}
+
+impl Display for RunDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for strategy in &self.strategies {
+            write!(f, "{}", strategy)?;
+        }
+        for added in &self.added {
+            writeln!(f, "{}: only in the new run", added)?;
+        }
+        for removed in &self.removed {
+            writeln!(f, "{}: only in the baseline run", removed)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        mock::Mock, Attempts, AttemptsKey, Harness, Puzzle, Result, Strategy, WordSelection,
+    };
+
+    /// Plays like a [`Mock`], but has the same name whatever its guesses.
+    #[derive(Debug)]
+    struct Scripted(Mock, &'static str);
+
+    impl Strategy for Scripted {
+        fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+            self.0.solve(puzzle, key)
+        }
+
+        fn version(&self) -> &'static str {
+            self.1
+        }
+
+        fn hardmode(&self) -> bool {
+            false
+        }
+    }
+
+    impl Display for Scripted {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Scripted")
+        }
+    }
+
+    #[test]
+    fn diffs_runs() -> Result<()> {
+        let word = |w| Word::from_str(w);
+        let (nerds, tithe, doubt, parka) = (
+            word("nerds")?,
+            word("tithe")?,
+            word("doubt")?,
+            word("parka")?,
+        );
+        let run = |strategies: Vec<Box<dyn Strategy>>| {
+            let mut harness = Harness::new()
+                .verbose(false)
+                .words(WordSelection::Explicit(vec![nerds, tithe, doubt, parka]));
+            for strategy in strategies {
+                harness = harness.add_strategy(strategy, None);
+            }
+            harness.run()
+        };
+        let old = || Box::new(Scripted(Mock::new(vec!["tithe", "doubt", "parka"]), "1.0"));
+        let new = || Box::new(Scripted(Mock::new(vec!["parka", "nerds", "doubt"]), "1.1"));
+
+        let before = run(vec![old(), Box::new(Mock::new(None))])?;
+        let after = run(vec![new()])?;
+        let diff = after.diff(&before);
+
+        assert_eq!(diff.strategies().len(), 1);
+        assert!(diff.added().is_empty());
+        assert_eq!(diff.removed(), [Mock::new(None).to_string() + " v1.2.4"]);
+        assert!(!diff.is_unchanged());
+
+        let strategy = &diff.strategies()[0];
+        assert_eq!(strategy.num_compared, 4);
+        let words = |changes: Vec<&WordChange>| -> Vec<Word> {
+            changes.iter().map(|change| change.word).collect()
+        };
+        assert_eq!(words(strategy.newly_solved().collect()), [nerds]);
+        assert_eq!(words(strategy.newly_missed().collect()), [tithe]);
+        assert_eq!(words(strategy.fewer_guesses().collect()), [parka]);
+        assert_eq!(words(strategy.more_guesses().collect()), [doubt]);
+        assert_eq!(
+            strategy.changes[0],
+            WordChange {
+                word: doubt,
+                before: Some(2),
+                after: Some(3),
+            }
+        );
+        assert_eq!(strategy.changes[0].guess_delta(), Some(1));
+        assert_eq!(strategy.changes[3].guess_delta(), None);
+
+        let shown = diff.to_string();
+        assert!(shown.starts_with("Scripted v1.0 -> v1.1: 4 of 4 words changed"));
+        assert!(shown.contains("    tithe  1 -> missed"));
+        assert!(shown.contains("only in the baseline run"));
+
+        // The same version is matched first, even when it is not first.
+        let both = run(vec![old(), new()])?;
+        let diff = after.diff(&both);
+        assert!(diff.is_unchanged());
+        assert_eq!(diff.removed(), ["Scripted v1.0"]);
+
+        Ok(())
+    }
+}
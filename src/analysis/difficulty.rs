@@ -0,0 +1,231 @@
+//! Scoring how difficult each answer is.
+//!
+//! A word that many strategies need six guesses for, or miss entirely, is
+//! hard in a way that says more about the word than about any one strategy.
+//! A [`DifficultyRanking`] pools the attempts of any number of performance
+//! records, or of every run in a [`RunStore`](crate::storage::RunStore),
+//! and ranks each answer by how many guesses it took.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use wordle_rs::{analysis::difficulty::DifficultyRanking, strategy::stupid::Stupid, Harness};
+//! let record = Harness::new()
+//!     .verbose(false)
+//!     .add_strategy(Box::new(Stupid), None)
+//!     .test_num(20)
+//!     .run()?;
+//!
+//! let ranking = DifficultyRanking::from_perfs(record.iter());
+//! for word in ranking.hardest(5) {
+//!     println!("{}: {:.0}% missed", word.word, word.miss_rate() * 100.);
+//! }
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::{cmp::Ordering, collections::HashMap, fmt::Display};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Perf, SolveContext, Word};
+
+/// How difficult one word was across every attempt on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct WordDifficulty {
+    /// The word.
+    pub word: Word,
+
+    /// The number of times a strategy tried to solve the word.
+    pub num_tried: u32,
+
+    /// The number of times a strategy solved the word.
+    pub num_solved: u32,
+
+    /// The total number of guesses used in the attempts that solved the
+    /// word.
+    pub solved_guesses: u64,
+}
+
+impl WordDifficulty {
+    /// Gets the fraction of attempts that did not solve the word.
+    pub fn miss_rate(&self) -> f32 {
+        (self.num_tried - self.num_solved) as f32 / self.num_tried as f32
+    }
+
+    /// Gets the mean number of guesses used to solve the word, or `None` if
+    /// it was never solved.
+    pub fn mean_guesses(&self) -> Option<f32> {
+        (self.num_solved > 0).then(|| self.solved_guesses as f32 / self.num_solved as f32)
+    }
+
+    /// Gets the difficulty score of the word: the mean number of guesses
+    /// over every attempt, counting a miss as one more guess than the
+    /// maximum.
+    ///
+    /// Higher scores are more difficult.
+    pub fn score(&self) -> f32 {
+        let missed = (self.num_tried - self.num_solved) as u64;
+        let penalty = SolveContext::MAX_GUESSES as u64 + 1;
+        (self.solved_guesses + missed * penalty) as f32 / self.num_tried as f32
+    }
+}
+
+/// Every word that was attempted, ranked from most to least difficult.
+///
+/// Words are ranked by [`WordDifficulty::score()`], with ties broken by the
+/// miss rate and then alphabetically. The [`Display`] implementation prints
+/// the ranking as a table.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct DifficultyRanking {
+    words: Vec<WordDifficulty>,
+}
+
+impl DifficultyRanking {
+    /// Ranks the words attempted in `perfs`, pooling every attempt.
+    ///
+    /// The same word may be attempted by many strategies and in many runs.
+    pub fn from_perfs<'a>(perfs: impl IntoIterator<Item = &'a Perf>) -> Self {
+        let mut words: HashMap<Word, WordDifficulty> = HashMap::new();
+
+        for perf in perfs {
+            for (word, attempts) in perf.tries() {
+                let entry = words.entry(*word).or_insert(WordDifficulty {
+                    word: *word,
+                    num_tried: 0,
+                    num_solved: 0,
+                    solved_guesses: 0,
+                });
+                entry.num_tried += 1;
+                if attempts.solved(word) {
+                    entry.num_solved += 1;
+                    entry.solved_guesses += attempts.len() as u64;
+                }
+            }
+        }
+
+        let mut words: Vec<WordDifficulty> = words.into_values().collect();
+        words.sort_by(|a, b| {
+            b.score()
+                .partial_cmp(&a.score())
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| {
+                    b.miss_rate()
+                        .partial_cmp(&a.miss_rate())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .then_with(|| a.word.cmp(&b.word))
+        });
+
+        DifficultyRanking { words }
+    }
+
+    /// Ranks the words attempted in every run written into `store`.
+    #[cfg(feature = "storage")]
+    pub fn from_store(store: &crate::storage::RunStore) -> crate::Result<Self> {
+        let mut ids: Vec<i64> = store.runs()?.into_iter().map(|run| run.id).collect();
+        ids.dedup();
+
+        let mut perfs = Vec::new();
+        for id in ids {
+            perfs.extend(store.load(id)?.unwrap_or_default());
+        }
+
+        Ok(Self::from_perfs(&perfs))
+    }
+
+    /// Gets every word, from most to least difficult.
+    pub fn words(&self) -> &[WordDifficulty] {
+        &self.words
+    }
+
+    /// Gets the `n` most difficult words, or every word if fewer were
+    /// attempted.
+    pub fn hardest(&self, n: usize) -> &[WordDifficulty] {
+        &self.words[..n.min(self.words.len())]
+    }
+
+    /// Gets the difficulty of `word` and its position in the ranking, or
+    /// `None` if it was never attempted.
+    pub fn get(&self, word: &Word) -> Option<(usize, &WordDifficulty)> {
+        self.words
+            .iter()
+            .enumerate()
+            .find(|(_, difficulty)| difficulty.word == *word)
+    }
+}
+
+impl Display for DifficultyRanking {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>5}  {:<5}  {:>5}  {:>6}  {:>12}  {:>6}",
+            "Rank", "Word", "Tried", "Missed", "Mean guesses", "Score"
+        )?;
+        for (i, word) in self.words.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>5}  {:<5}  {:>5}  {:>5.1}%  {:>12}  {:>6.3}",
+                i + 1,
+                word.word.to_string(),
+                word.num_tried,
+                word.miss_rate() * 100.,
+                word.mean_guesses()
+                    .map_or_else(|| "-".to_string(), |m| format!("{:.3}", m)),
+                word.score()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, Harness, Result, WordSelection};
+
+    #[test]
+    fn ranks_words() -> Result<()> {
+        let word = |w| Word::from_str(w);
+        let (doubt, tithe, parka) = (word("doubt")?, word("tithe")?, word("parka")?);
+
+        // The first mock solves "tithe" in 2 guesses, "doubt" in 3 and
+        // "parka" in 5, and the second only solves "tithe".
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe"])), None)
+            .words(WordSelection::Explicit(vec![doubt, tithe, parka]))
+            .run()?;
+
+        let ranking = DifficultyRanking::from_perfs(record.iter());
+        assert_eq!(ranking.words().len(), 3);
+        assert_eq!(ranking.hardest(1)[0].word, parka);
+        assert_eq!(ranking.hardest(1)[0].score(), 6.);
+        assert_eq!(ranking.hardest(10).len(), 3);
+
+        let (rank, difficulty) = ranking.get(&tithe).unwrap();
+        assert_eq!(rank, 2);
+        assert_eq!(difficulty.num_solved, 2);
+        assert_eq!(difficulty.mean_guesses(), Some(1.5));
+        assert!(ranking.get(&word("crimp")?).is_none());
+        assert!(ranking
+            .words()
+            .windows(2)
+            .all(|w| w[0].score() >= w[1].score()));
+
+        assert_eq!(ranking.to_string().lines().count(), 4);
+
+        Ok(())
+    }
+}
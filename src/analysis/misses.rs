@@ -0,0 +1,308 @@
+//! Finding out why a strategy misses puzzles.
+//!
+//! Some words are hard because of how they are spelled: a repeated letter
+//! is easy to rule out too early, a rare letter is rarely guessed, and a
+//! word like "fight" shares four letters with so many other answers that
+//! there may not be enough guesses left to try them all. A
+//! [`MissBreakdown`] checks each puzzle a strategy attempted for these
+//! [`WordFeature`]s and compares how often the strategy missed the words
+//! with each feature against the words without it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use wordle_rs::{analysis::misses::MissBreakdown, strategy::stupid::Stupid, Harness};
+//! let record = Harness::new()
+//!     .verbose(false)
+//!     .add_strategy(Box::new(Stupid), None)
+//!     .test_num(20)
+//!     .run()?;
+//!
+//! println!("{}", MissBreakdown::from_perf(&record[0]));
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::fmt::Display;
+
+use crate::{Perf, Word};
+
+/// A feature of a word that can make it difficult to guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WordFeature {
+    /// The word contains some letter more than once, like "sassy".
+    RepeatedLetter,
+
+    /// The word contains one of the rarest letters: j, q, x or z.
+    RareLetter,
+
+    /// At least [`CROWDED`](WordFeature::CROWDED) other answers differ
+    /// from the word in only one letter, like "hatch", "latch" and "match".
+    Crowded,
+
+    /// The word ends with one of the [`ENDINGS`](WordFeature::ENDINGS), like
+    /// "-ight".
+    Ending(&'static str),
+}
+
+impl WordFeature {
+    /// The number of answers one letter away that makes a word
+    /// [`Crowded`](WordFeature::Crowded).
+    pub const CROWDED: usize = 4;
+
+    /// The endings shared by large families of answers.
+    pub const ENDINGS: [&'static str; 6] = ["ight", "atch", "ound", "aste", "ower", "ill"];
+
+    /// Gets every feature, in the order they are reported.
+    pub fn all() -> Vec<WordFeature> {
+        let mut all = vec![
+            WordFeature::RepeatedLetter,
+            WordFeature::RareLetter,
+            WordFeature::Crowded,
+        ];
+        all.extend(
+            Self::ENDINGS
+                .iter()
+                .map(|&ending| WordFeature::Ending(ending)),
+        );
+        all
+    }
+
+    /// Returns true if `word` has this feature.
+    pub fn matches(&self, word: &Word) -> bool {
+        let bytes = word.as_bytes();
+        match self {
+            WordFeature::RepeatedLetter => bytes
+                .iter()
+                .enumerate()
+                .any(|(i, c)| bytes[i + 1..].contains(c)),
+            WordFeature::RareLetter => bytes.iter().any(|c| b"jqxz".contains(c)),
+            WordFeature::Crowded => {
+                Word::ANSWERS
+                    .iter()
+                    .filter(|answer| {
+                        answer
+                            .bytes()
+                            .zip(bytes.iter())
+                            .filter(|(a, b)| a != *b)
+                            .count()
+                            == 1
+                    })
+                    .count()
+                    >= Self::CROWDED
+            }
+            WordFeature::Ending(ending) => word.ends_with(ending),
+        }
+    }
+}
+
+impl Display for WordFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordFeature::RepeatedLetter => write!(f, "repeated letter"),
+            WordFeature::RareLetter => write!(f, "rare letter"),
+            WordFeature::Crowded => write!(f, "crowded"),
+            WordFeature::Ending(ending) => write!(f, "-{}", ending),
+        }
+    }
+}
+
+/// How often a strategy missed the words with one [`WordFeature`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeatureMisses {
+    /// The feature.
+    pub feature: WordFeature,
+
+    /// The number of words with the feature that the strategy tried.
+    pub num_tried: u32,
+
+    /// The number of words with the feature that the strategy missed.
+    pub num_missed: u32,
+
+    /// The number of words without the feature that the strategy tried.
+    pub others_tried: u32,
+
+    /// The number of words without the feature that the strategy missed.
+    pub others_missed: u32,
+}
+
+impl FeatureMisses {
+    /// Gets the fraction of words with the feature that were missed, or
+    /// `None` if none were tried.
+    pub fn miss_rate(&self) -> Option<f32> {
+        (self.num_tried > 0).then(|| self.num_missed as f32 / self.num_tried as f32)
+    }
+
+    /// Gets the fraction of words without the feature that were missed, or
+    /// `None` if none were tried.
+    pub fn others_miss_rate(&self) -> Option<f32> {
+        (self.others_tried > 0).then(|| self.others_missed as f32 / self.others_tried as f32)
+    }
+
+    /// Gets how many times more likely the strategy was to miss a word with
+    /// the feature than one without it.
+    ///
+    /// Returns `None` if either group is empty or no word without the
+    /// feature was missed.
+    pub fn lift(&self) -> Option<f32> {
+        match (self.miss_rate(), self.others_miss_rate()) {
+            (Some(with), Some(without)) if without > 0. => Some(with / without),
+            _ => None,
+        }
+    }
+}
+
+/// The misses of one strategy, broken down by [`WordFeature`].
+///
+/// The [`Display`] implementation prints a table with a row for every
+/// feature that at least one attempted word has.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MissBreakdown {
+    num_tried: u32,
+    num_missed: u32,
+    features: Vec<FeatureMisses>,
+}
+
+impl MissBreakdown {
+    /// Breaks down the misses in `perf` by every [`WordFeature`].
+    pub fn from_perf(perf: &Perf) -> Self {
+        let tries: Vec<(&Word, bool)> = perf
+            .tries()
+            .iter()
+            .map(|(word, attempts)| (word, attempts.solved(word)))
+            .collect();
+        let num_tried = tries.len() as u32;
+        let num_missed = tries.iter().filter(|(_, solved)| !solved).count() as u32;
+
+        let features = WordFeature::all()
+            .into_iter()
+            .map(|feature| {
+                let (mut tried, mut missed) = (0, 0);
+                for (word, solved) in &tries {
+                    if feature.matches(word) {
+                        tried += 1;
+                        missed += !solved as u32;
+                    }
+                }
+                FeatureMisses {
+                    feature,
+                    num_tried: tried,
+                    num_missed: missed,
+                    others_tried: num_tried - tried,
+                    others_missed: num_missed - missed,
+                }
+            })
+            .collect();
+
+        MissBreakdown {
+            num_tried,
+            num_missed,
+            features,
+        }
+    }
+
+    /// Gets the number of puzzles the strategy tried.
+    pub fn num_tried(&self) -> u32 {
+        self.num_tried
+    }
+
+    /// Gets the number of puzzles the strategy missed.
+    pub fn num_missed(&self) -> u32 {
+        self.num_missed
+    }
+
+    /// Gets the misses for every feature, in the order of
+    /// [`WordFeature::all()`].
+    pub fn features(&self) -> &[FeatureMisses] {
+        &self.features
+    }
+
+    /// Gets the misses for `feature`, if it was checked.
+    pub fn get(&self, feature: WordFeature) -> Option<&FeatureMisses> {
+        self.features
+            .iter()
+            .find(|misses| misses.feature == feature)
+    }
+}
+
+impl Display for MissBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rate = |rate: Option<f32>| {
+            rate.map_or_else(|| "-".to_string(), |r| format!("{:.1}%", r * 100.))
+        };
+
+        writeln!(
+            f,
+            "{:<16}  {:>5}  {:>6}  {:>9}  {:>13}  {:>5}",
+            "Feature", "Tried", "Missed", "Miss rate", "Without it", "Lift"
+        )?;
+        for misses in self.features.iter().filter(|misses| misses.num_tried > 0) {
+            writeln!(
+                f,
+                "{:<16}  {:>5}  {:>6}  {:>9}  {:>13}  {:>5}",
+                misses.feature.to_string(),
+                misses.num_tried,
+                misses.num_missed,
+                rate(misses.miss_rate()),
+                rate(misses.others_miss_rate()),
+                misses
+                    .lift()
+                    .map_or_else(|| "-".to_string(), |l| format!("{:.2}x", l))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, Harness, Result, WordSelection};
+
+    #[test]
+    fn word_features() -> Result<()> {
+        let word = |w| Word::from_str(w).unwrap();
+
+        assert!(WordFeature::RepeatedLetter.matches(&word("sassy")));
+        assert!(!WordFeature::RepeatedLetter.matches(&word("crane")));
+        assert!(WordFeature::RareLetter.matches(&word("fuzzy")));
+        assert!(WordFeature::Crowded.matches(&word("match")));
+        assert!(!WordFeature::Crowded.matches(&word("fuzzy")));
+        assert!(WordFeature::Ending("ight").matches(&word("fight")));
+        assert_eq!(WordFeature::Ending("ight").to_string(), "-ight");
+
+        Ok(())
+    }
+
+    #[test]
+    fn breaks_down_misses() -> Result<()> {
+        let words = ["fight", "match", "doubt", "tithe"]
+            .iter()
+            .map(|w| Word::from_str(w))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Only solves the words it guesses, so misses "fight" and "match".
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(vec!["doubt", "tithe"])), None)
+            .words(WordSelection::Explicit(words))
+            .run()?;
+
+        let breakdown = MissBreakdown::from_perf(&record[0]);
+        assert_eq!((breakdown.num_tried(), breakdown.num_missed()), (4, 2));
+
+        let crowded = breakdown.get(WordFeature::Crowded).unwrap();
+        assert_eq!((crowded.num_tried, crowded.num_missed), (2, 2));
+        assert_eq!(crowded.miss_rate(), Some(1.));
+        assert_eq!(crowded.others_miss_rate(), Some(0.));
+        assert_eq!(crowded.lift(), None);
+
+        let repeated = breakdown.get(WordFeature::RepeatedLetter).unwrap();
+        assert_eq!((repeated.num_tried, repeated.num_missed), (1, 0));
+        assert_eq!(repeated.lift(), Some(0.));
+
+        assert!(breakdown.to_string().contains("-ight"));
+        assert!(!breakdown.to_string().contains("-ound"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! Analyses that combine results from many strategies and runs.
+//!
+//! Where [`perf`](crate::perf) looks at how one strategy did, the modules
+//! here look across strategies, e.g. to find which answers are hard for
+//! everyone, across runs, e.g. to find which words a change fixed, or beyond
+//! the numbers, e.g. to find out why a strategy misses.
+
+pub mod diff;
+pub mod difficulty;
+pub mod misses;
@@ -0,0 +1,328 @@
+//! Glue for tracking strategies with `cargo bench`.
+//!
+//! [`Bench`] runs the strategies configured in a [`Harness`] on batches of
+//! puzzles, cycling through the words the harness selects, and reports how
+//! they did in a [`Batch`]. This works with any benchmarking setup that can
+//! time a custom routine.
+//!
+//! With the `bench` feature, this module also integrates with
+//! [criterion](https://docs.rs/criterion). [`bench_harness()`] registers one
+//! benchmark per strategy, and the [`Guesses`] and [`Misses`] measurements
+//! let criterion track solve metrics instead of (or as well as) wall time.
+//!
+//! # Examples
+//!
+//! A `benches/strategies.rs` file using criterion might look like this:
+//!
+//! ```rust,ignore
+//! use criterion::{criterion_group, criterion_main, Criterion};
+//! use wordle_rs::{bench::{bench_harness, Guesses}, strategy::stupid::Stupid, Harness};
+//!
+//! fn harness() -> Harness {
+//!     Harness::new()
+//!         .add_strategy(Box::new(Stupid), None)
+//!         .test_all()
+//! }
+//!
+//! fn time(c: &mut Criterion) {
+//!     bench_harness(c, &mut harness()).unwrap();
+//! }
+//!
+//! fn guesses(c: &mut Criterion<Guesses>) {
+//!     bench_harness(c, &mut harness()).unwrap();
+//! }
+//!
+//! criterion_group!(time_benches, time);
+//! criterion_group! {
+//!     name = guess_benches;
+//!     config = Criterion::default().with_measurement(Guesses);
+//!     targets = guesses
+//! }
+//! criterion_main!(time_benches, guess_benches);
+//! ```
+
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "bench")]
+use criterion::{
+    measurement::{Measurement, ValueFormatter, WallTime},
+    Criterion, Throughput,
+};
+
+use crate::{
+    strategy::{AttemptsKey, Puzzle, SolveContext, Word},
+    Harness, HarnessError, Result,
+};
+
+/// Runs the strategies in a [`Harness`] on batches of puzzles.
+#[derive(Debug)]
+pub struct Bench<'a> {
+    harness: &'a Harness,
+    words: Vec<Word>,
+    // The position of the first word among all of the words the harness
+    // selects, and the number of those words, when the harness is sharded.
+    offset: usize,
+    total: usize,
+    first_day: Option<u32>,
+    next: usize,
+}
+
+impl<'a> Bench<'a> {
+    /// Creates a new bench over the strategies and words configured in
+    /// `harness`, calling [`Strategy::prepare()`](crate::Strategy::prepare())
+    /// on each strategy.
+    ///
+    /// Returns an error if the harness has no strategies or selects no words.
+    pub fn new(harness: &'a mut Harness) -> Result<Self> {
+        harness.pre_run_check()?;
+        let (words, offset, total) = harness.resolve_shard()?;
+        if words.is_empty() {
+            return Err(HarnessError::NoWordsSelected.into());
+        }
+
+        harness.prepare(words.len());
+        let first_day = harness.words.first_day();
+
+        Ok(Bench {
+            harness,
+            words,
+            offset,
+            total,
+            first_day,
+            next: 0,
+        })
+    }
+
+    /// Gets the number of strategies in the harness.
+    pub fn num_strategies(&self) -> usize {
+        self.harness.strategies.len()
+    }
+
+    /// Gets the full name of the strategy at `index`, including its version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn strategy_name(&self, index: usize) -> String {
+        let strategy = &self.harness.strategies[index].0;
+        format!("{} v{}", strategy, strategy.version())
+    }
+
+    /// Runs the strategy at `index` on the next `n` puzzles.
+    ///
+    /// The puzzles cycle through the words selected by the harness, picking
+    /// up where the last batch left off. Returns an error if the strategy
+    /// cheats.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn solve(&mut self, index: usize, n: u64) -> Result<Batch> {
+        let strategy = &self.harness.strategies[index].0;
+        let mut batch = Batch::default();
+
+        for _ in 0..n {
+            let word = self.words[self.next];
+
+            let index = self.offset + self.next;
+            let ctx =
+                SolveContext::new(index, self.total, self.first_day.map(|d| d + index as u32));
+            let mut puzzle = Puzzle::new(word);
+            let key = AttemptsKey::new(strategy.hardmode());
+            let start = Instant::now();
+            let attempts = strategy.solve_with_context(&mut puzzle, key, &ctx);
+            batch.elapsed += start.elapsed();
+
+            if let Some(poisoning) = puzzle.poisoning() {
+                return Err(HarnessError::StrategyCheated {
+                    strategy: format!("{}", strategy),
+                    word,
+                    poisoning,
+                }
+                .into());
+            }
+
+            self.next = (self.next + 1) % self.words.len();
+
+            batch.tried += 1;
+            batch.guesses += attempts.inner().len() as u64;
+            if attempts.solved(&word) {
+                batch.solved += 1;
+            }
+        }
+
+        Ok(batch)
+    }
+}
+
+/// The results of running a strategy on a batch of puzzles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Batch {
+    /// The number of puzzles attempted.
+    pub tried: u64,
+
+    /// The number of puzzles solved.
+    pub solved: u64,
+
+    /// The total number of guesses made, including on puzzles that were not
+    /// solved.
+    pub guesses: u64,
+
+    /// The time spent solving, not counting time spent setting up puzzles.
+    pub elapsed: Duration,
+}
+
+/// A criterion [`Measurement`] that can be taken from a [`Batch`].
+#[cfg(feature = "bench")]
+pub trait SolveMetric: Measurement {
+    /// Measures the batch.
+    fn measure(batch: &Batch) -> Self::Value;
+}
+
+#[cfg(feature = "bench")]
+impl SolveMetric for WallTime {
+    fn measure(batch: &Batch) -> Duration {
+        batch.elapsed
+    }
+}
+
+/// Measures the number of guesses made by a strategy.
+///
+/// Criterion reports this per puzzle, so the result is the mean number of
+/// guesses. This only produces meaningful values through [`bench_harness()`].
+#[cfg(feature = "bench")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Guesses;
+
+/// Measures the number of puzzles a strategy fails to solve.
+///
+/// Criterion reports this per puzzle, so the result is the fraction of
+/// puzzles missed. This only produces meaningful values through
+/// [`bench_harness()`].
+#[cfg(feature = "bench")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Misses;
+
+#[cfg(feature = "bench")]
+macro_rules! count_measurement {
+    ($measurement:ident, $formatter:ident, $unit:literal, $batch:ident => $value:expr) => {
+        impl Measurement for $measurement {
+            type Intermediate = ();
+            type Value = u64;
+
+            fn start(&self) {}
+
+            fn end(&self, _: ()) -> u64 {
+                0
+            }
+
+            fn add(&self, v1: &u64, v2: &u64) -> u64 {
+                v1 + v2
+            }
+
+            fn zero(&self) -> u64 {
+                0
+            }
+
+            fn to_f64(&self, value: &u64) -> f64 {
+                *value as f64
+            }
+
+            fn formatter(&self) -> &dyn ValueFormatter {
+                &$formatter
+            }
+        }
+
+        impl SolveMetric for $measurement {
+            fn measure($batch: &Batch) -> u64 {
+                $value
+            }
+        }
+
+        struct $formatter;
+
+        impl ValueFormatter for $formatter {
+            fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+                $unit
+            }
+
+            fn scale_throughputs(
+                &self,
+                _typical_value: f64,
+                _throughput: &Throughput,
+                _values: &mut [f64],
+            ) -> &'static str {
+                concat!($unit, "/elem")
+            }
+
+            fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+                $unit
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bench")]
+count_measurement!(Guesses, GuessesFormatter, "guesses", batch => batch.guesses);
+#[cfg(feature = "bench")]
+count_measurement!(Misses, MissesFormatter, "misses", batch => batch.tried - batch.solved);
+
+/// Registers a criterion benchmark for each strategy in `harness`.
+///
+/// Each benchmark is named after its strategy and runs it on as many puzzles
+/// as criterion asks for, measuring the results with `M`. Returns an error if
+/// the harness has no strategies or selects no words.
+///
+/// # Panics
+///
+/// The benchmarks panic if a strategy cheats.
+#[cfg(feature = "bench")]
+pub fn bench_harness<M: SolveMetric>(c: &mut Criterion<M>, harness: &mut Harness) -> Result<()> {
+    let mut bench = Bench::new(harness)?;
+    let mut group = c.benchmark_group("wordle_rs");
+
+    for index in 0..bench.num_strategies() {
+        let name = bench.strategy_name(index);
+        group.bench_function(name.as_str(), |b| {
+            b.iter_custom(|iters| {
+                let batch = bench.solve(index, iters).expect("strategy cheated");
+                M::measure(&batch)
+            })
+        });
+    }
+
+    group.finish();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::Mock;
+
+    #[test]
+    fn batches_cycle_words() -> Result<()> {
+        let mut harness = Harness::new()
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .test_num(10);
+
+        let mut bench = Bench::new(&mut harness)?;
+        assert_eq!(bench.num_strategies(), 2);
+        assert_eq!(bench.strategy_name(0), "Mock None v1.2.4");
+
+        let batch = bench.solve(0, 25)?;
+        assert_eq!(batch.tried, 25);
+        assert!(batch.solved <= batch.tried);
+        assert!(batch.guesses >= batch.tried);
+        assert_eq!(bench.next, 5);
+
+        let batch = bench.solve(1, 10)?;
+        assert!(batch.guesses <= 20);
+
+        assert!(Bench::new(&mut Harness::new()).is_err());
+
+        Ok(())
+    }
+}
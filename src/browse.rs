@@ -0,0 +1,311 @@
+//! An interactive terminal browser for the results of a run.
+
+use std::io::{self, Write};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::{perf::Perf, strategy::Attempts, Result, Summary};
+
+/// The width of the word list on the left side of the screen.
+const LIST_WIDTH: u16 = 16;
+
+const HELP: &str = "←/→ strategy  ↑/↓ word  PgUp/PgDn page  m missed only  b baseline  q quit";
+
+/// The state of the browser between key presses.
+pub(crate) struct Browser<'a> {
+    perfs: &'a [Perf],
+    summaries: Vec<Summary>,
+    baseline: Option<(Summary, Option<usize>)>,
+    strategy: usize,
+    selected: usize,
+    offset: usize,
+    missed_only: bool,
+    compare: bool,
+}
+
+impl<'a> Browser<'a> {
+    /// Creates a browser over `perfs`.
+    ///
+    /// `baseline` holds the summary of the baseline, along with its index in
+    /// `perfs` if it was run alongside the other strategies.
+    pub(crate) fn new(perfs: &'a [Perf], baseline: Option<(Summary, Option<usize>)>) -> Self {
+        Browser {
+            perfs,
+            summaries: perfs.iter().map(Perf::to_summary).collect(),
+            baseline,
+            strategy: 0,
+            selected: 0,
+            offset: 0,
+            missed_only: false,
+            compare: false,
+        }
+    }
+
+    /// Takes over the terminal and runs the browser until the user quits.
+    pub(crate) fn run(mut self) -> Result<()> {
+        if self.perfs.is_empty() {
+            return Ok(());
+        }
+
+        let mut stdout = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+
+        let res = self.event_loop(&mut stdout);
+
+        execute!(stdout, cursor::Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+
+        res
+    }
+
+    fn event_loop(&mut self, stdout: &mut impl Write) -> Result<()> {
+        loop {
+            let (width, height) = terminal::size()?;
+            self.draw(stdout, width, height)?;
+
+            if let Event::Key(KeyEvent { code, modifiers }) = event::read()? {
+                let page = height.saturating_sub(4) as usize;
+                match code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Ok(())
+                    }
+                    KeyCode::Left | KeyCode::Char('h') => self.switch_strategy(-1),
+                    KeyCode::Right | KeyCode::Char('l') => self.switch_strategy(1),
+                    KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+                    KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+                    KeyCode::PageUp => self.move_selection(-(page as isize)),
+                    KeyCode::PageDown => self.move_selection(page as isize),
+                    KeyCode::Char('m') => {
+                        self.missed_only = !self.missed_only;
+                        self.selected = 0;
+                        self.offset = 0;
+                    }
+                    KeyCode::Char('b') => self.compare = !self.compare,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// The indices into the current strategy's tries that are shown.
+    fn visible(&self) -> Vec<usize> {
+        self.perfs[self.strategy]
+            .tries()
+            .iter()
+            .enumerate()
+            .filter(|(_, (word, attempts))| !self.missed_only || !attempts.solved(word))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn switch_strategy(&mut self, by: isize) {
+        let len = self.perfs.len() as isize;
+        self.strategy = (self.strategy as isize + by).rem_euclid(len) as usize;
+        self.selected = 0;
+        self.offset = 0;
+    }
+
+    fn move_selection(&mut self, by: isize) {
+        let len = self.visible().len();
+        if len == 0 {
+            return;
+        }
+
+        self.selected = (self.selected as isize + by).clamp(0, len as isize - 1) as usize;
+    }
+
+    fn draw(&mut self, stdout: &mut impl Write, width: u16, height: u16) -> Result<()> {
+        queue!(stdout, Clear(ClearType::All))?;
+
+        let perf = &self.perfs[self.strategy];
+        let summary = &self.summaries[self.strategy];
+
+        // Header
+        let header = format!(
+            "[{}/{}] {}: solved {}/{} ({:.1}%), {:.2} guesses on average",
+            self.strategy + 1,
+            self.perfs.len(),
+            summary,
+            summary.num_solved(),
+            summary.num_tried(),
+            summary.frac_solved() * 100.,
+            summary.mean_guesses().unwrap_or(f32::NAN),
+        );
+        queue!(
+            stdout,
+            cursor::MoveTo(0, 0),
+            SetAttribute(Attribute::Bold),
+            Print(truncate(&header, width)),
+            SetAttribute(Attribute::Reset)
+        )?;
+
+        let comparison = match (&self.baseline, self.compare) {
+            (Some((baseline, _)), true) if baseline == summary => {
+                "This strategy is the baseline".to_string()
+            }
+            (Some((baseline, _)), true) => match summary.compare(baseline) {
+                Ok(comparison) => format!(
+                    "vs. {}: {:+.1}% solved, {:+.2} guesses",
+                    baseline,
+                    comparison.frac_solved_diff() * 100.,
+                    comparison.mean_guesses_diff().unwrap_or(f32::NAN),
+                ),
+                Err(_) => "Could not compare against the baseline".to_string(),
+            },
+            (None, true) => "No baseline was set for this run".to_string(),
+            (_, false) => String::new(),
+        };
+        let filter = if self.missed_only {
+            "showing missed words only"
+        } else {
+            "showing all words"
+        };
+        queue!(
+            stdout,
+            cursor::MoveTo(0, 1),
+            Print(truncate(&format!("{} {}", filter, comparison), width))
+        )?;
+
+        // Word list
+        let rows = height.saturating_sub(4) as usize;
+        let visible = self.visible();
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if rows > 0 && self.selected >= self.offset + rows {
+            self.offset = self.selected + 1 - rows;
+        }
+
+        for (row, &i) in visible.iter().skip(self.offset).take(rows).enumerate() {
+            let (word, attempts) = &perf.tries()[i];
+            let line = if attempts.solved(word) {
+                format!(" {} {}/6", word, attempts.inner().len())
+            } else {
+                format!(" {} X/6", word)
+            };
+
+            let y = row as u16 + 3;
+            if self.offset + row == self.selected {
+                queue!(
+                    stdout,
+                    cursor::MoveTo(0, y),
+                    SetAttribute(Attribute::Reverse),
+                    Print(truncate(&line, LIST_WIDTH)),
+                    SetAttribute(Attribute::Reset)
+                )?;
+            } else {
+                queue!(
+                    stdout,
+                    cursor::MoveTo(0, y),
+                    Print(truncate(&line, LIST_WIDTH))
+                )?;
+            }
+        }
+
+        // Detail panel
+        if let Some(&i) = visible.get(self.selected) {
+            let (word, attempts) = &perf.tries()[i];
+            let mut y = 3;
+            y = draw_attempts(stdout, y, &format!("Answer: {}", word), attempts)?;
+
+            if self.compare {
+                if let Some((_, Some(index))) = self.baseline {
+                    if index != self.strategy {
+                        let baseline = &self.perfs[index];
+                        if let Some((_, attempts)) =
+                            baseline.tries().iter().find(|(w, _)| w == word)
+                        {
+                            let title = format!("Baseline {}:", baseline);
+                            draw_attempts(stdout, y + 1, &title, attempts)?;
+                        }
+                    }
+                }
+            }
+        } else {
+            queue!(
+                stdout,
+                cursor::MoveTo(LIST_WIDTH + 2, 3),
+                Print("No words to show")
+            )?;
+        }
+
+        // Footer
+        queue!(
+            stdout,
+            cursor::MoveTo(0, height.saturating_sub(1)),
+            Print(truncate(HELP, width))
+        )?;
+
+        stdout.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Draws a titled list of attempts with their grades in the detail panel,
+/// returning the next free row.
+fn draw_attempts(stdout: &mut impl Write, y: u16, title: &str, attempts: &Attempts) -> Result<u16> {
+    let x = LIST_WIDTH + 2;
+    queue!(stdout, cursor::MoveTo(x, y), Print(title))?;
+
+    let mut y = y + 1;
+    for (guess, grid) in attempts.inner().iter().zip(attempts.share_grid().lines()) {
+        queue!(
+            stdout,
+            cursor::MoveTo(x, y),
+            Print(format!("{} {}", guess, grid))
+        )?;
+        y += 1;
+    }
+
+    Ok(y)
+}
+
+/// Cuts `line` down to at most `width` characters.
+fn truncate(line: &str, width: u16) -> String {
+    line.chars().take(width as usize).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, Harness};
+
+    #[test]
+    fn navigation() -> Result<()> {
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .test_num(10)
+            .run()?;
+
+        let mut browser = Browser::new(&record, None);
+
+        browser.move_selection(-1);
+        assert_eq!(browser.selected, 0);
+        browser.move_selection(100);
+        assert_eq!(browser.selected, 9);
+
+        browser.switch_strategy(-1);
+        assert_eq!(browser.strategy, 1);
+        assert_eq!(browser.selected, 0);
+        browser.switch_strategy(1);
+        assert_eq!(browser.strategy, 0);
+
+        browser.missed_only = true;
+        assert!(browser
+            .visible()
+            .iter()
+            .all(|&i| !record[0].tries()[i].1.solved(&record[0].tries()[i].0)));
+
+        Ok(())
+    }
+}
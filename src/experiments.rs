@@ -0,0 +1,774 @@
+//! Common experiments built on top of the [test harness](crate::Harness).
+//!
+//! These helpers answer questions that would otherwise need hand-written
+//! loops around the harness, like which opening word works best for a
+//! strategy. For searching over arbitrary parameters, see
+//! [`Harness::grid()`], and for how much results vary between sets of
+//! words, see [`Harness::kfold()`]. Some questions don't need a strategy at
+//! all: [`opener_report()`] rates opening words by the feedback they get
+//! alone.
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+
+use crate::{
+    strategy::grade_batch, Comparison, Harness, Metric, Result, Strategy, Summary, Word,
+    WordSelection,
+};
+
+/// Runs a strategy once per candidate opening word and ranks the openers.
+///
+/// `strategy_factory` is called with each word in `openers` and should
+/// return the strategy configured to use that word as its first guess.
+/// Every strategy is run on the same puzzles, chosen by `puzzles`, so the
+/// results can be compared directly.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use wordle_rs::{experiments::first_word_sweep, Strategy, Word, WordSelection};
+/// # fn my_strategy(opener: Word) -> Box<dyn Strategy> { unimplemented!() }
+/// let openers = ["crane", "slate", "trace"]
+///     .iter()
+///     .map(|w| Word::from_str(w))
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let sweep = first_word_sweep(
+///     my_strategy,
+///     &openers,
+///     WordSelection::RandomSample { n: 500, seed: Some(0) },
+/// )?;
+/// println!("{}", sweep);
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn first_word_sweep<F>(
+    strategy_factory: F,
+    openers: &[Word],
+    puzzles: WordSelection,
+) -> Result<OpenerSweep>
+where
+    F: Fn(Word) -> Box<dyn Strategy>,
+{
+    let grid = Harness::new()
+        .verbose(false)
+        .parallel(true)
+        .words(puzzles)
+        .grid(openers.iter().copied(), |&opener| strategy_factory(opener))?;
+
+    let mut ranked = grid.entries;
+    ranked.sort_by(|(_, a), (_, b)| Metric::SolveRate.compare(a, b));
+
+    Ok(OpenerSweep { ranked })
+}
+
+/// The results of a [`first_word_sweep()`], ranked from best to worst.
+///
+/// Openers are ranked by the fraction of puzzles solved, with ties broken by
+/// the mean number of guesses. The [`Display`] implementation prints the
+/// ranking as a table.
+#[derive(Debug, Clone)]
+pub struct OpenerSweep {
+    ranked: Vec<(Word, Summary)>,
+}
+
+impl OpenerSweep {
+    /// Gets each opener and the summary of the strategy using it, from best
+    /// to worst.
+    pub fn ranked(&self) -> &[(Word, Summary)] {
+        &self.ranked
+    }
+
+    /// Gets the best opener and the summary of the strategy using it, if any
+    /// openers were tried.
+    pub fn best(&self) -> Option<&(Word, Summary)> {
+        self.ranked.first()
+    }
+}
+
+impl Display for OpenerSweep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>4}  {:<6}  {:>7}  {:>12}",
+            "Rank", "Opener", "Solved", "Mean guesses"
+        )?;
+        for (i, (opener, summary)) in self.ranked.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}  {:<6}  {:>6.2}%  {:>12}",
+                i + 1,
+                opener.to_string(),
+                summary.frac_solved() * 100.,
+                format_mean(summary)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The results of a [`Harness::grid()`] search.
+///
+/// The [`Display`] implementation prints every point in the parameter
+/// space, using its [`Debug`] representation, followed by the best
+/// parameters for each [`Metric`].
+#[derive(Debug, Clone)]
+pub struct GridReport<P> {
+    entries: Vec<(P, Summary)>,
+}
+
+impl<P> GridReport<P> {
+    pub(crate) fn new(entries: Vec<(P, Summary)>) -> Self {
+        GridReport { entries }
+    }
+
+    /// Gets each point in the parameter space and the summary of the
+    /// strategy created from it, in the order they were searched.
+    pub fn entries(&self) -> &[(P, Summary)] {
+        &self.entries
+    }
+
+    /// Gets the best parameters by `metric` and the summary of the strategy
+    /// created from them, if the parameter space was not empty.
+    ///
+    /// When several parameters are equally good, the first is returned.
+    pub fn best(&self, metric: Metric) -> Option<&(P, Summary)> {
+        self.entries
+            .iter()
+            .min_by(|(_, a), (_, b)| metric.compare(a, b))
+    }
+
+    /// Gets every point in the parameter space and its summary, from best
+    /// to worst by `metric`.
+    pub fn ranked(&self, metric: Metric) -> Vec<&(P, Summary)> {
+        let mut ranked: Vec<_> = self.entries.iter().collect();
+        ranked.sort_by(|(_, a), (_, b)| metric.compare(a, b));
+        ranked
+    }
+
+    /// Compares every other point in the parameter space against the best by
+    /// `metric`.
+    ///
+    /// With the `stats` feature, each [`Comparison`] can tell whether the
+    /// best parameters are significantly better, using the default
+    /// significance level. Points whose summary is identical to the best are
+    /// skipped, since they cannot be compared.
+    pub fn compare_to_best(&self, metric: Metric) -> Result<Vec<(&P, Comparison<'_, '_>)>> {
+        let best = match self.best(metric) {
+            Some((_, best)) => best,
+            None => return Ok(Vec::new()),
+        };
+
+        self.entries
+            .iter()
+            .filter(|(_, summary)| summary != best)
+            .map(|(params, summary)| Ok((params, summary.compare(best)?)))
+            .collect()
+    }
+}
+
+impl<P: Debug> Display for GridReport<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(p, _)| format!("{:?}", p))
+            .collect();
+        let width = params.iter().map(String::len).max().unwrap_or(0).max(6);
+
+        writeln!(
+            f,
+            "{:<width$}  {:>7}  {:>12}",
+            "Params",
+            "Solved",
+            "Mean guesses",
+            width = width
+        )?;
+        for (params, (_, summary)) in params.iter().zip(&self.entries) {
+            writeln!(
+                f,
+                "{:<width$}  {:>6.2}%  {:>12}",
+                params,
+                summary.frac_solved() * 100.,
+                format_mean(summary),
+                width = width
+            )?;
+        }
+
+        for metric in Metric::ALL {
+            if let Some((params, _)) = self.best(metric) {
+                writeln!(f, "Best {}: {:?}", metric, params)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The mean and standard deviation of a measure across the folds of a
+/// [`KFoldReport`].
+///
+/// The [`Display`] implementation prints it as "mean ± std".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spread {
+    /// The mean across the folds.
+    pub mean: f64,
+
+    /// The sample standard deviation across the folds, or zero if there was
+    /// only one.
+    pub std_dev: f64,
+}
+
+impl Spread {
+    fn of(values: &[f64]) -> Option<Spread> {
+        if values.is_empty() {
+            return None;
+        }
+
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let std_dev = if values.len() > 1 {
+            let squares: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+            (squares / (n - 1.)).sqrt()
+        } else {
+            0.
+        };
+
+        Some(Spread { mean, std_dev })
+    }
+}
+
+impl Display for Spread {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.*} ± {:.*}",
+                precision, self.mean, precision, self.std_dev
+            ),
+            None => write!(f, "{} ± {}", self.mean, self.std_dev),
+        }
+    }
+}
+
+/// The results of [`Harness::kfold()`]: a summary of each strategy on each
+/// fold of the words.
+///
+/// How much a strategy's results vary from fold to fold shows how much they
+/// depend on the words chosen, without the assumptions behind the
+/// statistical tests in [`Comparison`].
+///
+/// The [`Display`] implementation prints the mean ± standard deviation of
+/// each strategy's solve rate and mean guesses as a table.
+#[derive(Debug, Clone)]
+pub struct KFoldReport {
+    names: Vec<String>,
+    folds: Vec<Vec<Summary>>,
+}
+
+impl KFoldReport {
+    pub(crate) fn new(names: Vec<String>, folds: Vec<Vec<Summary>>) -> Self {
+        KFoldReport { names, folds }
+    }
+
+    /// Gets the full name of each strategy, in the order they were added to
+    /// the harness.
+    pub fn strategies(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Gets the summary of each strategy on each fold, indexed by fold and
+    /// then by strategy.
+    pub fn folds(&self) -> &[Vec<Summary>] {
+        &self.folds
+    }
+
+    /// Gets the spread of the fraction of puzzles the `strategy`th strategy
+    /// solved in each fold.
+    ///
+    /// # Panics
+    ///
+    /// `strategy` must be less than the number of strategies.
+    pub fn solve_rate(&self, strategy: usize) -> Spread {
+        let rates: Vec<f64> = self
+            .folds
+            .iter()
+            .map(|fold| fold[strategy].frac_solved() as f64)
+            .collect();
+        Spread::of(&rates).expect("a k-fold run has at least one fold")
+    }
+
+    /// Gets the spread of the mean number of guesses the `strategy`th
+    /// strategy used to solve the puzzles in each fold, skipping folds where
+    /// it solved none, or `None` if it never solved any.
+    ///
+    /// # Panics
+    ///
+    /// `strategy` must be less than the number of strategies.
+    pub fn mean_guesses(&self, strategy: usize) -> Option<Spread> {
+        let means: Vec<f64> = self
+            .folds
+            .iter()
+            .filter_map(|fold| fold[strategy].mean_guesses())
+            .map(f64::from)
+            .collect();
+        Spread::of(&means)
+    }
+}
+
+impl Display for KFoldReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self.names.iter().map(String::len).max().unwrap_or(0).max(8);
+
+        writeln!(f, "{} folds", self.folds.len())?;
+        writeln!(
+            f,
+            "{:<width$}  {:>17}  {:>15}",
+            "Strategy",
+            "Solved",
+            "Mean guesses",
+            width = width
+        )?;
+        for (i, name) in self.names.iter().enumerate() {
+            let solved = self.solve_rate(i);
+            let solved = Spread {
+                mean: solved.mean * 100.,
+                std_dev: solved.std_dev * 100.,
+            };
+            writeln!(
+                f,
+                "{:<width$}  {:>16}%  {:>15}",
+                name,
+                format!("{:.2}", solved),
+                self.mean_guesses(i)
+                    .map_or_else(|| "-".to_string(), |m| format!("{:.3}", m)),
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Rates each word in `openers` as a first guess against every Wordle
+/// answer, without running a strategy.
+///
+/// For each opener, every answer is graded with
+/// [`grade_batch()`](crate::strategy::grade_batch()), and the report gives
+/// the mean number of green and yellow letters and the expected number of
+/// answers left after the feedback. Since the answer is unknown, the
+/// expected number left weights each possible set of feedback by how many
+/// answers would give it.
+///
+/// The report lists openers in the order given, and can be sorted with
+/// [`OpenerReport::sort()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use wordle_rs::{experiments::{opener_report, OpenerMetric}, Word};
+/// let openers = ["crane", "fuzzy", "slate"]
+///     .iter()
+///     .map(|w| Word::from_str(w))
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let mut report = opener_report(&openers);
+/// report.sort(OpenerMetric::Remaining);
+/// assert_eq!(report.entries()[2].opener, Word::from_str("fuzzy")?);
+/// println!("{}", report);
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn opener_report(openers: &[Word]) -> OpenerReport {
+    let answers = Word::ANSWERS;
+    let mut patterns = vec![0; answers.len()];
+
+    let entries = openers
+        .iter()
+        .map(|&opener| {
+            grade_batch(&opener, answers, &mut patterns);
+
+            let mut buckets = [0_u32; 243];
+            let (mut greens, mut yellows) = (0_u32, 0_u32);
+            for &pattern in &patterns {
+                buckets[pattern as usize] += 1;
+
+                let mut pattern = pattern;
+                while pattern > 0 {
+                    match pattern % 3 {
+                        2 => greens += 1,
+                        1 => yellows += 1,
+                        _ => {}
+                    }
+                    pattern /= 3;
+                }
+            }
+
+            let n = answers.len() as f32;
+            let squares: u64 = buckets.iter().map(|&c| c as u64 * c as u64).sum();
+            OpenerStats {
+                opener,
+                mean_greens: greens as f32 / n,
+                mean_yellows: yellows as f32 / n,
+                mean_remaining: squares as f32 / n,
+                patterns: buckets.iter().filter(|&&c| c > 0).count(),
+            }
+        })
+        .collect();
+
+    OpenerReport { entries }
+}
+
+/// How well one opening word splits the answers, as rated by
+/// [`opener_report()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpenerStats {
+    /// The opening guess.
+    pub opener: Word,
+
+    /// The mean number of letters graded [`Correct`](crate::Grade::Correct).
+    pub mean_greens: f32,
+
+    /// The mean number of letters graded [`Almost`](crate::Grade::Almost).
+    pub mean_yellows: f32,
+
+    /// The expected number of answers still possible after the feedback,
+    /// including the answer itself.
+    pub mean_remaining: f32,
+
+    /// The number of different sets of feedback the opener can get.
+    pub patterns: usize,
+}
+
+/// A measure by which to sort an [`OpenerReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OpenerMetric {
+    /// The fewest answers expected to remain, with ties broken by the most
+    /// different sets of feedback.
+    Remaining,
+
+    /// The most green letters on average.
+    Greens,
+
+    /// The most green and yellow letters together on average.
+    Letters,
+
+    /// The most different sets of feedback.
+    Patterns,
+}
+
+impl OpenerMetric {
+    /// Orders stats from best to worst by this metric.
+    fn compare(self, a: &OpenerStats, b: &OpenerStats) -> Ordering {
+        let by = |a: f32, b: f32| a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+        match self {
+            OpenerMetric::Remaining => {
+                by(a.mean_remaining, b.mean_remaining).then_with(|| b.patterns.cmp(&a.patterns))
+            }
+            OpenerMetric::Greens => by(b.mean_greens, a.mean_greens),
+            OpenerMetric::Letters => by(
+                b.mean_greens + b.mean_yellows,
+                a.mean_greens + a.mean_yellows,
+            ),
+            OpenerMetric::Patterns => b.patterns.cmp(&a.patterns),
+        }
+    }
+}
+
+/// The results of an [`opener_report()`].
+///
+/// The [`Display`] implementation prints the report as a table, in its
+/// current order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenerReport {
+    entries: Vec<OpenerStats>,
+}
+
+impl OpenerReport {
+    /// Gets the stats for each opener.
+    pub fn entries(&self) -> &[OpenerStats] {
+        &self.entries
+    }
+
+    /// Sorts the openers from best to worst by `metric`.
+    ///
+    /// The sort is stable, so openers that are equally good keep their
+    /// order.
+    pub fn sort(&mut self, metric: OpenerMetric) {
+        self.entries.sort_by(|a, b| metric.compare(a, b));
+    }
+
+    /// Gets the best opener by `metric`, if any openers were rated.
+    ///
+    /// When several openers are equally good, the first is returned.
+    pub fn best(&self, metric: OpenerMetric) -> Option<&OpenerStats> {
+        self.entries.iter().min_by(|a, b| metric.compare(a, b))
+    }
+}
+
+impl Display for OpenerReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<6}  {:>6}  {:>7}  {:>9}  {:>8}",
+            "Opener", "Greens", "Yellows", "Remaining", "Patterns"
+        )?;
+        for stats in &self.entries {
+            writeln!(
+                f,
+                "{:<6}  {:>6.3}  {:>7.3}  {:>9.2}  {:>8}",
+                stats.opener.to_string(),
+                stats.mean_greens,
+                stats.mean_yellows,
+                stats.mean_remaining,
+                stats.patterns
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the mean number of guesses of `summary` for a table.
+fn format_mean(summary: &Summary) -> String {
+    summary
+        .mean_guesses()
+        .map_or_else(|| "-".to_string(), |m| format!("{:.3}", m))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Attempts, AttemptsKey, Puzzle};
+
+    /// Guesses its opener and then gives up.
+    #[derive(Debug)]
+    struct Opener(Word);
+
+    impl Strategy for Opener {
+        fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+            let mut attempts = key.unlock();
+            puzzle.check(&self.0, &mut attempts).unwrap();
+            attempts
+        }
+
+        fn version(&self) -> &'static str {
+            "1.0.0"
+        }
+
+        fn hardmode(&self) -> bool {
+            false
+        }
+    }
+
+    impl Display for Opener {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Opener {}", self.0)
+        }
+    }
+
+    /// Guesses a list of words in order until one is correct.
+    #[derive(Debug)]
+    struct Guesses(Vec<Word>);
+
+    impl Strategy for Guesses {
+        fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+            let mut attempts = key.unlock();
+            for guess in &self.0 {
+                if puzzle.check(guess, &mut attempts).unwrap().1 {
+                    break;
+                }
+            }
+            attempts
+        }
+
+        fn version(&self) -> &'static str {
+            "1.0.0"
+        }
+
+        fn hardmode(&self) -> bool {
+            false
+        }
+    }
+
+    impl Display for Guesses {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Guesses {:?}", self.0)
+        }
+    }
+
+    #[test]
+    fn ranks_openers() -> Result<()> {
+        let word = |w| Word::from_str(w);
+        let (parka, doubt, tithe) = (word("parka")?, word("doubt")?, word("tithe")?);
+
+        let sweep = first_word_sweep(
+            |opener| Box::new(Opener(opener)),
+            &[parka, tithe, doubt],
+            WordSelection::Explicit(vec![doubt, doubt, tithe]),
+        )?;
+
+        let ranked: Vec<Word> = sweep.ranked().iter().map(|(w, _)| *w).collect();
+        assert_eq!(ranked, [doubt, tithe, parka]);
+        assert_eq!(sweep.best().unwrap().1.num_solved(), 2);
+        assert_eq!(sweep.to_string().lines().count(), 4);
+        assert!(sweep.to_string().lines().nth(1).unwrap().contains("doubt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn searches_grid() -> Result<()> {
+        let word = |w| Word::from_str(w);
+        let (doubt, tithe, parka) = (word("doubt")?, word("tithe")?, word("parka")?);
+
+        // Each strategy guesses one of two openers and then up to two of the
+        // other answers.
+        let report = Harness::new()
+            .verbose(false)
+            .words(WordSelection::Explicit(vec![doubt, doubt, tithe, parka]))
+            .grid(itertools::iproduct!(0..2, 0..3), |&(opener, rest)| {
+                let opener = [doubt, tithe][opener];
+                let mut guesses = vec![opener];
+                guesses.extend(
+                    [parka, doubt, tithe]
+                        .iter()
+                        .filter(|&&w| w != opener)
+                        .take(rest),
+                );
+                Box::new(Guesses(guesses))
+            })?;
+
+        assert_eq!(report.entries().len(), 6);
+        assert_eq!(report.best(Metric::SolveRate).unwrap().0, (0, 2));
+        assert_eq!(report.best(Metric::MeanGuesses).unwrap().0, (0, 0));
+        assert_eq!(report.ranked(Metric::SolveRate)[5].0, (1, 0));
+
+        let comparisons = report.compare_to_best(Metric::SolveRate)?;
+        assert_eq!(comparisons.len(), 5);
+        assert!(comparisons.iter().all(|(_, c)| c.frac_solved_diff() <= 0.));
+
+        let table = report.to_string();
+        assert!(table.contains("Best solve rate: (0, 2)"));
+        assert!(table.contains("Best mean guesses: (0, 0)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn kfold_spread() -> Result<()> {
+        let word = |w| Word::from_str(w);
+        let (doubt, tithe, parka, point) = (
+            word("doubt")?,
+            word("tithe")?,
+            word("parka")?,
+            word("point")?,
+        );
+        let mut harness = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Guesses(vec![doubt, tithe])), None)
+            .words(WordSelection::Explicit(vec![doubt, tithe, parka, point]));
+
+        let report = harness.kfold(2, 7)?;
+        assert_eq!(report.folds().len(), 2);
+        assert!(report.folds().iter().all(|fold| fold[0].num_tried() == 2));
+        assert_eq!(report.solve_rate(0).mean, 0.5);
+        let again = harness.kfold(2, 7)?;
+        assert_eq!(report.folds(), again.folds());
+
+        // With one word per fold, each fold either solved its word or not.
+        let report = harness.kfold(4, 7)?;
+        let solved = report.solve_rate(0);
+        assert_eq!(solved.mean, 0.5);
+        assert!((solved.std_dev - (1f64 / 3.).sqrt()).abs() < 1e-9);
+        assert_eq!(report.mean_guesses(0).unwrap().mean, 1.5);
+        assert_eq!(report.to_string().lines().count(), 3);
+
+        assert!(harness.kfold(0, 7).is_err());
+        assert!(harness.kfold(5, 7).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn kfold_contexts_cover_each_fold() -> Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        use crate::strategy::SolveContext;
+
+        /// Records the position of each puzzle it is given.
+        #[derive(Debug, Default)]
+        struct Contexts(Arc<Mutex<Vec<(usize, usize)>>>);
+
+        impl Display for Contexts {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Contexts")
+            }
+        }
+
+        impl Strategy for Contexts {
+            fn solve(&self, _puzzle: &mut Puzzle, _key: AttemptsKey) -> Attempts {
+                unreachable!()
+            }
+
+            fn solve_with_context(
+                &self,
+                puzzle: &mut Puzzle,
+                key: AttemptsKey,
+                ctx: &SolveContext,
+            ) -> Attempts {
+                self.0.lock().unwrap().push((ctx.index(), ctx.total()));
+                Guesses(vec![]).solve(puzzle, key)
+            }
+
+            fn version(&self) -> &'static str {
+                "1.0.0"
+            }
+
+            fn hardmode(&self) -> bool {
+                false
+            }
+        }
+
+        let contexts = Contexts::default();
+        let seen = contexts.0.clone();
+        Harness::new()
+            .verbose(false)
+            .parallel(false)
+            .add_strategy(Box::new(contexts), None)
+            .test_num(9)
+            .kfold(3, 7)?;
+
+        let expected: Vec<_> = (0..3).flat_map(|_| (0..3).map(|i| (i, 3))).collect();
+        assert_eq!(*seen.lock().unwrap(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rates_openers() -> Result<()> {
+        let word = |w| Word::from_str(w);
+        let (fuzzy, slate, crane) = (word("fuzzy")?, word("slate")?, word("crane")?);
+
+        let mut report = opener_report(&[fuzzy, slate, crane]);
+        assert_eq!(report.entries()[0].opener, fuzzy);
+
+        let n = Word::ANSWERS.len() as f32;
+        for stats in report.entries() {
+            assert!(stats.mean_remaining >= 1. && stats.mean_remaining <= n);
+            assert!(stats.patterns <= 243);
+            assert!(stats.mean_greens + stats.mean_yellows <= 5.0);
+        }
+
+        report.sort(OpenerMetric::Remaining);
+        assert_eq!(report.entries()[2].opener, fuzzy);
+        assert_eq!(
+            report.best(OpenerMetric::Remaining),
+            report.entries().first()
+        );
+        assert!(report
+            .entries()
+            .windows(2)
+            .all(|w| w[0].mean_remaining <= w[1].mean_remaining));
+        assert_eq!(report.to_string().lines().count(), 4);
+
+        Ok(())
+    }
+}
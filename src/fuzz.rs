@@ -0,0 +1,230 @@
+//! Property-based testing helpers for the grading and hardmode rules.
+//!
+//! Grading guesses with repeated letters is easy to get subtly wrong, and
+//! such bugs tend to be found one word at a time. This module provides
+//! [proptest](https://docs.rs/proptest) strategies that generate games,
+//! biased towards words with repeated letters, and checkers for invariants
+//! that every game must satisfy. The checkers return a
+//! [`TestCaseError`] on failure, so they can be used with `?` inside
+//! `proptest!`.
+//!
+//! This module requires the `fuzz` feature.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use proptest::prelude::*;
+//! use wordle_rs::fuzz::{check_game, game};
+//!
+//! proptest! {
+//!     #[test]
+//!     fn grading_is_consistent((answer, guesses) in game()) {
+//!         check_game(&answer, &guesses)?;
+//!     }
+//! }
+//! ```
+
+use itertools::Itertools;
+use proptest::{prelude::*, sample::select, test_runner::TestCaseError};
+
+use crate::{strategy::hardmode::is_valid_guess, Attempts, Grade, Puzzle, SolveContext, Word};
+
+/// Generates any word that may be guessed.
+pub fn word() -> impl Strategy<Value = Word> {
+    (0..Word::ALL.len()).prop_map(|i| Word::ALL[i])
+}
+
+/// Generates any word that may be an answer.
+pub fn answer() -> impl Strategy<Value = Word> {
+    (0..Word::ANSWERS.len()).prop_map(|i| Word::ANSWERS[i])
+}
+
+/// Generates words that contain at least one letter more than once.
+pub fn repeated_letter_word() -> impl Strategy<Value = Word> {
+    let words: Vec<Word> = Word::ALL
+        .iter()
+        .copied()
+        .filter(|word| !word.chars().all_unique())
+        .collect();
+    select(words)
+}
+
+/// Generates an answer and between one and six guesses.
+///
+/// Half of the answers and guesses have repeated letters, which is where
+/// grading rules are most likely to go wrong.
+pub fn game() -> impl Strategy<Value = (Word, Vec<Word>)> {
+    let answer = prop_oneof![answer(), repeated_letter_word()];
+    let guesses = proptest::collection::vec(
+        prop_oneof![word(), repeated_letter_word()],
+        1..=SolveContext::MAX_GUESSES,
+    );
+    (answer, guesses)
+}
+
+/// Checks that `grades` are the right grades for `guess` when the answer is
+/// `answer`.
+///
+/// For every letter, the guess must get a [`Correct`](Grade::Correct) grade
+/// exactly where it matches the answer, and the number of
+/// [`Correct`](Grade::Correct) and [`Almost`](Grade::Almost) grades for the
+/// letter together must be the smaller of the number of times it appears in
+/// the guess and in the answer. Extra copies of a letter are graded
+/// [`Almost`](Grade::Almost) from left to right.
+pub fn check_grades(guess: &Word, answer: &Word, grades: &[Grade; 5]) -> Result<(), TestCaseError> {
+    let (guess_bytes, answer_bytes) = (guess.as_bytes(), answer.as_bytes());
+
+    for (i, grade) in grades.iter().enumerate() {
+        prop_assert_eq!(
+            *grade == Grade::Correct,
+            guess_bytes[i] == answer_bytes[i],
+            "{} graded {:?} against {}: position {} is wrong",
+            guess,
+            grades,
+            answer,
+            i
+        );
+    }
+
+    for letter in guess_bytes.iter().unique() {
+        let in_guess = guess_bytes.iter().filter(|&c| c == letter).count();
+        let in_answer = answer_bytes.iter().filter(|&c| c == letter).count();
+        let graded = guess_bytes
+            .iter()
+            .zip(grades)
+            .filter(|&(c, g)| c == letter && *g != Grade::Incorrect)
+            .count();
+        prop_assert_eq!(
+            graded,
+            in_guess.min(in_answer),
+            "{} graded {:?} against {}: wrong count for '{}'",
+            guess,
+            grades,
+            answer,
+            *letter as char
+        );
+
+        // Almost grades go to the leftmost copies that are not correct.
+        let mut incorrect_seen = false;
+        for (c, g) in guess_bytes.iter().zip(grades) {
+            if c != letter || *g == Grade::Correct {
+                continue;
+            }
+            if *g == Grade::Incorrect {
+                incorrect_seen = true;
+            } else {
+                prop_assert!(
+                    !incorrect_seen,
+                    "{} graded {:?} against {}: '{}' is almost after an incorrect copy",
+                    guess,
+                    grades,
+                    answer,
+                    *letter as char
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the hardmode rules are monotonic over `history`, a list of
+/// guesses and the grades they received against `answer`.
+///
+/// The answer must always be a valid hardmode guess, and a guess that is
+/// valid after the whole history must also be valid after every part of it.
+pub fn check_hardmode(answer: &Word, history: &[(Word, [Grade; 5])]) -> Result<(), TestCaseError> {
+    for end in 0..=history.len() {
+        prop_assert!(
+            is_valid_guess(&history[..end], answer),
+            "the answer {} is not a valid hardmode guess after {:?}",
+            answer,
+            &history[..end]
+        );
+    }
+
+    for (guess, _) in history {
+        if is_valid_guess(history, guess) {
+            for end in 0..history.len() {
+                prop_assert!(
+                    is_valid_guess(&history[..end], guess),
+                    "{} is a valid hardmode guess after {:?} but not after {:?}",
+                    guess,
+                    history,
+                    &history[..end]
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Plays `guesses` on the puzzle with the given `answer` and checks every
+/// invariant in this module.
+///
+/// The guesses are played with [`Puzzle::check()`] on easymode, stopping
+/// after the answer is guessed. Each guess is checked with
+/// [`check_grades()`], and the whole game with [`check_hardmode()`].
+pub fn check_game(answer: &Word, guesses: &[Word]) -> Result<(), TestCaseError> {
+    let mut puzzle = Puzzle::new(*answer);
+    let mut attempts = Attempts::cheat(false);
+    let mut history = Vec::new();
+
+    for guess in guesses.iter().take(SolveContext::MAX_GUESSES) {
+        let (grades, correct) = puzzle
+            .check(guess, &mut attempts)
+            .map_err(|e| TestCaseError::fail(e.to_string()))?;
+        check_grades(guess, answer, &grades)?;
+        prop_assert_eq!(correct, guess == answer);
+
+        history.push((*guess, grades));
+        if correct {
+            break;
+        }
+    }
+
+    check_hardmode(answer, &history)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn games_follow_the_rules((answer, guesses) in game()) {
+            check_game(&answer, &guesses)?;
+        }
+
+        #[test]
+        fn repeated_letters_are_graded((answer, guess) in (repeated_letter_word(), repeated_letter_word())) {
+            check_game(&answer, &[guess])?;
+        }
+    }
+
+    #[test]
+    fn catches_bad_grades() {
+        let (guess, answer) = (
+            Word::from_str("spool").unwrap(),
+            Word::from_str("sober").unwrap(),
+        );
+        let bad = [
+            Grade::Correct,
+            Grade::Incorrect,
+            Grade::Almost,
+            Grade::Almost,
+            Grade::Incorrect,
+        ];
+        assert!(check_grades(&guess, &answer, &bad).is_err());
+
+        let good = [
+            Grade::Correct,
+            Grade::Incorrect,
+            Grade::Almost,
+            Grade::Incorrect,
+            Grade::Incorrect,
+        ];
+        assert!(check_grades(&guess, &answer, &good).is_ok());
+    }
+}
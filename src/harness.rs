@@ -1,32 +1,94 @@
 //! The test harness for running Wordle strategies.
 
 use std::{
-    ops::Deref,
+    collections::HashSet,
+    io::Write,
+    ops::{Deref, Range},
     path::{Path, PathBuf},
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant, SystemTime},
 };
+#[cfg(feature = "serde")]
+use std::{fs::File, sync::atomic::AtomicUsize};
 
-#[cfg(all(feature = "fancy", feature = "parallel"))]
-use indicatif::ParallelProgressIterator;
 #[cfg(feature = "fancy")]
-use indicatif::ProgressIterator;
-use rand::seq::index::sample;
+use indicatif::{HumanDuration, ProgressBar, ProgressStyle};
+use rand::{
+    rngs::StdRng,
+    seq::{index::sample, SliceRandom},
+    SeedableRng,
+};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "serde")]
+use crate::{analysis::difficulty::DifficultyRanking, perf::Format};
 use crate::{
-    perf::Perf,
-    strategy::{AttemptsKey, Puzzle, Strategy, Word},
-    words::ANSWERS,
+    analysis::{diff::RunDiff, misses::MissBreakdown},
+    experiments::{GridReport, KFoldReport},
+    perf::{
+        ColorChoice, Disqualification, Perf, Regression, RegressionReport, RegressionThresholds,
+    },
+    sink::{ResultSink, StrategyId},
+    strategy::{Attempts, AttemptsKey, Puzzle, RunContext, SolveContext, Strategy, Word},
+    words::{
+        daily::{DailyAnswers, Date},
+        ANSWERS,
+    },
     HarnessError, Result, Summary, WordleError,
 };
 
+#[cfg(feature = "stats")]
+use crate::perf::TestKind;
+#[cfg(all(feature = "serde", feature = "stats"))]
+use crate::perf::{saved_path, Metric};
+
+/// Reports progress from the harness.
+///
+/// With the `tracing` feature, the message is emitted as an info event,
+/// leaving filtering to the subscriber. Otherwise, it is printed to stderr if
+/// the harness is verbose.
+macro_rules! progress {
+    ($verbose:expr, $($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        {
+            let _ = $verbose;
+            tracing::info!($($arg)*);
+        }
+        #[cfg(not(feature = "tracing"))]
+        if $verbose {
+            eprintln!($($arg)*);
+        }
+    }};
+}
+
+/// Reports a problem with a strategy from the harness.
+///
+/// With the `tracing` feature, the message is emitted as a warning event.
+/// Otherwise, it is always printed to stderr.
+macro_rules! warning {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        tracing::warn!($($arg)*);
+        #[cfg(not(feature = "tracing"))]
+        eprintln!("warning: {}", format_args!($($arg)*));
+    }};
+}
+
 /// A test harness that can run many strategies on many puzzles.
 ///
 /// When you want to test your strategies, create a new test harness
 /// with [`new()`](Harness::new()). You can then configure it using various
 /// methods. Note that these configuration methods consume the existing
-/// [`Harness`] and return a new one.
+/// [`Harness`] and return a new one. To configure a harness through `&mut`
+/// references instead, use [`Harness::builder()`].
 ///
 /// # Examples
 ///
@@ -34,7 +96,7 @@ use crate::{
 /// # use wordle_rs::harness::Harness;
 /// use wordle_rs::strategy::stupid::Stupid;
 ///
-/// let harness = Harness::new()
+/// let mut harness = Harness::new()
 ///     .verbose(false)
 ///     .add_strategy(Box::new(Stupid), None)
 ///     .parallel(false)
@@ -44,11 +106,25 @@ use crate::{
 /// ```
 #[derive(Debug)]
 pub struct Harness {
-    strategies: Vec<(Box<dyn Strategy>, Option<String>)>,
+    pub(crate) strategies: Vec<(Box<dyn Strategy>, Option<String>)>,
     verbose: bool,
-    num_guesses: Option<usize>,
-    baseline: BaselineOpt,
+    pub(crate) words: WordSelection,
+    // The index of the slice of `words` to run on and the number of slices.
+    shard: (usize, usize),
+    baselines: Vec<BaselineOpt>,
     parallel: bool,
+    schedule: Schedule,
+    record_checks: bool,
+    // Behind a lock since the harness runs through `&self`, even in parallel.
+    sinks: Mutex<Vec<Box<dyn ResultSink>>>,
+    #[cfg(feature = "serde")]
+    checkpoint: Option<(usize, PathBuf)>,
+    #[cfg(feature = "serde")]
+    resume: Option<Record>,
+    #[cfg(feature = "serde")]
+    version_mismatch: VersionMismatch,
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    best_baseline: Option<(String, Option<PathBuf>, Metric)>,
 }
 
 impl Default for Harness {
@@ -56,9 +132,21 @@ impl Default for Harness {
         Harness {
             strategies: Vec::new(),
             verbose: true,
-            num_guesses: Some(100),
-            baseline: BaselineOpt::None,
+            words: WordSelection::default(),
+            shard: (0, 1),
+            baselines: Vec::new(),
             parallel: false,
+            schedule: Schedule::default(),
+            record_checks: false,
+            sinks: Mutex::default(),
+            #[cfg(feature = "serde")]
+            checkpoint: None,
+            #[cfg(feature = "serde")]
+            resume: None,
+            #[cfg(feature = "serde")]
+            version_mismatch: VersionMismatch::default(),
+            #[cfg(all(feature = "serde", feature = "stats"))]
+            best_baseline: None,
         }
     }
 }
@@ -80,15 +168,174 @@ impl Harness {
     /// Makes the harness verbose while testing.
     ///
     /// As of right now, this consists of a progress bar and nothing else.
+    /// With the `tracing` feature, progress messages are emitted as `tracing`
+    /// events whether or not the harness is verbose, nested under a span for
+    /// the run and for each puzzle.
     pub fn verbose(self, verbose: bool) -> Self {
         Harness { verbose, ..self }
     }
 
+    /// Tells the harness to write every run into the
+    /// [`RunStore`](crate::storage::RunStore) at `path`, tagged with `label`.
+    ///
+    /// The database is created if it does not exist yet. This is the same as
+    /// adding a [`StoreSink`](crate::sink::StoreSink) with
+    /// [`add_sink()`](Self::add_sink()).
+    #[cfg(feature = "storage")]
+    pub fn archive(self, path: impl Into<PathBuf>, label: Option<&str>) -> Self {
+        self.add_sink(Box::new(crate::sink::StoreSink::new(path, label)))
+    }
+
+    /// Adds a [`ResultSink`] that is told about every result as the harness
+    /// runs, and about the [`Record`] of each completed run.
+    ///
+    /// See the [`sink`](crate::sink) module for the built-in sinks.
+    pub fn add_sink(mut self, sink: Box<dyn ResultSink>) -> Self {
+        self.sinks.get_mut().unwrap().push(sink);
+        self
+    }
+
+    /// Tells the harness to save the results so far to `path` after every
+    /// `n` words, so that a long run can be picked up again with
+    /// [`resume()`](Self::resume()) if it crashes or is stopped.
+    ///
+    /// The checkpoint is a [`Record`] in the same format as
+    /// [`Record::save()`]. It is also written when the run finishes or fails.
+    /// `n` is clamped to at least one. Resuming selects the words again, so
+    /// running fails if they are a [`WordSelection::RandomSample`] without a
+    /// seed.
+    #[cfg(feature = "serde")]
+    pub fn checkpoint_every(self, n: usize, path: impl Into<PathBuf>) -> Self {
+        Harness {
+            checkpoint: Some((n.max(1), path.into())),
+            ..self
+        }
+    }
+
+    /// Continues the run saved in the checkpoint at `path`, written by a
+    /// harness configured with [`checkpoint_every()`](Self::checkpoint_every()).
+    ///
+    /// The results in the checkpoint are kept, and each strategy skips the
+    /// words it has already been run on. The harness must have the same
+    /// strategies, in the same order, as the one that wrote the checkpoint,
+    /// or running fails. Since the words are selected again, running also
+    /// fails on a [`WordSelection::RandomSample`] without a seed. Only
+    /// [`run()`](Self::run()) and [`run_streaming()`](Self::run_streaming())
+    /// resume.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use wordle_rs::{strategy::stupid::Stupid, Harness};
+    /// let harness = Harness::new()
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .test_all()
+    ///     .checkpoint_every(100, "stupid.checkpoint.json");
+    ///
+    /// let mut harness = if std::path::Path::new("stupid.checkpoint.json").exists() {
+    ///     harness.resume("stupid.checkpoint.json")?
+    /// } else {
+    ///     harness
+    /// };
+    /// let record = harness.run()?;
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn resume(self, path: impl AsRef<Path>) -> Result<Self> {
+        let record = Record::read(path.as_ref())?;
+        Ok(Harness {
+            resume: Some(record),
+            ..self
+        })
+    }
+
     /// Tells the harness to run strategies in parallel.
     pub fn parallel(self, parallel: bool) -> Self {
         Harness { parallel, ..self }
     }
 
+    /// Sets how the harness splits a run into pieces of work.
+    ///
+    /// The default, [`Schedule::ByWord`], runs every strategy on a word
+    /// together. When fast and slow strategies are mixed in a parallel run,
+    /// [`Schedule::Interleaved`] keeps the fast ones from waiting on the slow
+    /// ones.
+    pub fn schedule(self, schedule: Schedule) -> Self {
+        Harness { schedule, ..self }
+    }
+
+    /// Tells the harness to record every call each strategy makes to
+    /// [`Puzzle::check()`], including guesses that are rejected for breaking
+    /// the hardmode rules or coming after the last attempt.
+    ///
+    /// The calls are kept in each [`Perf`] and can be read with
+    /// [`Perf::checks()`]. This is off by default, since it makes records
+    /// larger.
+    pub fn record_checks(self, record_checks: bool) -> Self {
+        Harness {
+            record_checks,
+            ..self
+        }
+    }
+
+    /// Sets what the harness does when a baseline loaded with
+    /// [`load_baseline()`](Self::load_baseline()) was produced by a different
+    /// version of an added strategy.
+    ///
+    /// Comparing against an old version is often the point of a baseline, so
+    /// by default the harness only [warns](VersionMismatch::Warn).
+    #[cfg(feature = "serde")]
+    pub fn on_version_mismatch(self, version_mismatch: VersionMismatch) -> Self {
+        Harness {
+            version_mismatch,
+            ..self
+        }
+    }
+
+    /// Keeps the saved baseline `name` as the best strategy seen so far by
+    /// `metric`.
+    ///
+    /// After each complete run, the harness picks the strategy that did best
+    /// in `metric` and compares it against the saved baseline. The baseline
+    /// is replaced only when the strategy is significantly better, using the
+    /// same tests as [`Summary::compare()`], and is saved for the first time
+    /// if it does not exist yet. The file is replaced atomically, so a crash
+    /// cannot leave a broken baseline behind. What happened is available
+    /// from [`RunInfo::baseline_update()`].
+    ///
+    /// The baseline is kept in `dir`, or the default directory from
+    /// [`get_save_dir()`] when that is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use wordle_rs::{harness::BaselineUpdate, strategy::stupid::Stupid, Harness, Metric};
+    /// let record = Harness::new()
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .update_baseline_if_better("best", None, Metric::SolveRate)
+    ///     .run()?;
+    ///
+    /// if let Some(BaselineUpdate::Replaced { strategy, .. }) =
+    ///     record.info().and_then(|info| info.baseline_update())
+    /// {
+    ///     println!("{} is the new best", strategy);
+    /// }
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    pub fn update_baseline_if_better<'a>(
+        self,
+        name: &str,
+        dir: impl Into<Option<&'a Path>>,
+        metric: Metric,
+    ) -> Self {
+        let dir = dir.into().map(Path::to_path_buf);
+        Harness {
+            best_baseline: Some((name.to_string(), dir, metric)),
+            ..self
+        }
+    }
+
     /// Adds a strategy to the harness for testing.
     pub fn add_strategy<'a>(
         self,
@@ -107,7 +354,7 @@ impl Harness {
         Harness { strategies, ..self }
     }
 
-    /// Adds a strategy to the harness for testing and sets it as the baseline
+    /// Adds a strategy to the harness for testing and sets it as a baseline
     /// for comparison.
     pub fn add_baseline<'a>(
         self,
@@ -117,67 +364,326 @@ impl Harness {
         self.add_strategy(strat, save_name).and_baseline()
     }
 
-    /// Sets the most recently added strategy as the baseline for comparisons.
-    pub fn and_baseline(self) -> Result<Self> {
-        match self.baseline {
-            BaselineOpt::None => Ok(Self {
-                baseline: BaselineOpt::Run(
-                    self.strategies.len() - 1,
-                    self.strategies
-                        .last()
-                        .ok_or(HarnessError::NoStrategiesAdded)?
-                        .1
-                        .clone(),
-                ),
-                ..self
-            }),
-            _ => Err(HarnessError::BaselineAlreadySet.into()),
-        }
+    /// Sets the most recently added strategy as a baseline for comparisons.
+    ///
+    /// A harness can have any number of baselines, like the previous release
+    /// of a strategy and its best version ever, and its report compares every
+    /// strategy against each of them in turn. The same baseline cannot be set
+    /// twice.
+    pub fn and_baseline(mut self) -> Result<Self> {
+        let index = self
+            .strategies
+            .len()
+            .checked_sub(1)
+            .ok_or(HarnessError::NoStrategiesAdded)?;
+        let name = self.strategies[index].1.clone();
+        push_baseline(&mut self.baselines, BaselineOpt::Run(index, name))?;
+        Ok(self)
     }
 
-    /// Adds a saved performance record as the baseline for comparisons.
+    /// Adds a saved performance record as a baseline for comparisons.
     ///
     /// The `name` must match the name of a baseline saved previously.
+    ///
+    /// Before running, the harness checks the version of the strategy that
+    /// produced the baseline against the version of any added strategy with
+    /// the same name. See [`on_version_mismatch()`](Self::on_version_mismatch())
+    /// for what happens when they differ.
     #[cfg(feature = "serde")]
-    pub fn load_baseline<'a>(self, name: &str, dir: impl Into<Option<&'a Path>>) -> Result<Self> {
-        match self.baseline {
-            BaselineOpt::None => {
-                let dir = get_save_dir(dir)?;
-                let baseline = Summary::from_saved(name, dir)?;
-                Ok(Self {
-                    baseline: BaselineOpt::Saved(Box::new(baseline), name.to_string()),
-                    ..self
-                })
+    pub fn load_baseline<'a>(
+        mut self,
+        name: &str,
+        dir: impl Into<Option<&'a Path>>,
+    ) -> Result<Self> {
+        let dir = get_save_dir(dir)?;
+        let baseline = Summary::from_saved(name, dir)?;
+        push_baseline(
+            &mut self.baselines,
+            BaselineOpt::Saved(Box::new(baseline), name.to_string()),
+        )?;
+        Ok(self)
+    }
+
+    /// Adds every saved performance record whose name matches `pattern` as a
+    /// baseline for comparisons, in order of their names.
+    ///
+    /// In the pattern, `*` matches any run of characters and `?` matches any
+    /// one character, so `"minimax-*"` matches both `"minimax-v1"` and
+    /// `"minimax-best"`. Baselines that were already loaded are skipped, and
+    /// it is an error for nothing to match.
+    ///
+    /// See [`load_baseline()`](Self::load_baseline()).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use wordle_rs::{strategy::stupid::Stupid, Harness};
+    /// let record = Harness::new()
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .load_baselines("stupid-*", None)?
+    ///     .run()?;
+    ///
+    /// for (name, summary) in record.baselines() {
+    ///     println!("{}: {:.2}% solved", name, summary.frac_solved() * 100.);
+    /// }
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn load_baselines<'a>(
+        mut self,
+        pattern: &str,
+        dir: impl Into<Option<&'a Path>>,
+    ) -> Result<Self> {
+        let dir = get_save_dir(dir)?;
+        for (name, baseline) in saved_baselines(pattern, &dir)? {
+            let baseline = BaselineOpt::Saved(Box::new(baseline), name);
+            if !self.baselines.iter().any(|b| b.is(&baseline)) {
+                self.baselines.push(baseline);
             }
-            _ => Err(HarnessError::BaselineAlreadySet.into()),
         }
+        Ok(self)
+    }
+
+    /// Sets the words the harness tests each strategy on.
+    ///
+    /// See [`WordSelection`] for the options.
+    pub fn words(self, words: WordSelection) -> Self {
+        Harness { words, ..self }
     }
 
     /// Sets the harness to test each strategy on each possible Wordle answer.
+    ///
+    /// This is the same as `words(WordSelection::All)`.
     pub fn test_all(self) -> Self {
-        Harness {
-            num_guesses: None,
-            ..self
-        }
+        self.words(WordSelection::All)
     }
 
     /// Sets the harness to test each strategy on `n` random Wordle answers.
+    ///
+    /// This is the same as `words(WordSelection::RandomSample { n, seed: None })`,
+    /// except that `n` is clamped to the number of possible answers.
     pub fn test_num(self, n: usize) -> Self {
-        Harness {
-            num_guesses: Some(n.clamp(0, ANSWERS.len())),
+        self.words(WordSelection::RandomSample {
+            n: n.clamp(0, ANSWERS.len()),
+            seed: None,
+        })
+    }
+
+    /// Sets the harness to test each strategy on the daily puzzles from the
+    /// start of `dates` up to but not including the end, using the built-in
+    /// [`DailyAnswers`].
+    ///
+    /// This is the same as `words(WordSelection::Daily(dates))`, except that
+    /// an unknown answer is reported immediately rather than when running.
+    /// To test on puzzles that are not built in, supply the answers with
+    /// [`DailyAnswers::load_updates()`] and pass [`DailyAnswers::range()`] to
+    /// [`words()`](Self::words()).
+    pub fn test_daily_range(self, dates: Range<Date>) -> Result<Self> {
+        let words = WordSelection::Daily(dates);
+        words.resolve()?;
+        Ok(self.words(words))
+    }
+
+    /// Tells the harness to run on only one of `total` slices of the selected
+    /// words, the one at `index`.
+    ///
+    /// The words are split into contiguous slices of nearly equal size, so
+    /// running every shard and combining the results with [`Record::merge()`]
+    /// gives the same record as running on all of the words at once. This
+    /// lets a slow strategy be tested on many machines in parallel. The
+    /// selection must resolve to the same words on every machine, so use
+    /// a seed with [`WordSelection::RandomSample`].
+    ///
+    /// Returns an error if `index` is not less than `total`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{strategy::stupid::Stupid, Harness, Record, WordSelection};
+    /// let shard = |index| {
+    ///     Harness::new()
+    ///         .verbose(false)
+    ///         .add_strategy(Box::new(Stupid), None)
+    ///         .words(WordSelection::Range(0..10))
+    ///         .shard(index, 3)?
+    ///         .run()
+    /// };
+    ///
+    /// let record = Record::merge(&[shard(0)?, shard(1)?, shard(2)?])?;
+    /// assert_eq!(record[0].num_tried(), 10);
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn shard(self, index: usize, total: usize) -> Result<Self> {
+        if index >= total {
+            return Err(HarnessError::InvalidShard { index, total }.into());
+        }
+
+        Ok(Harness {
+            shard: (index, total),
             ..self
+        })
+    }
+
+    /// Creates a [`HarnessBuilder`], which configures a harness through
+    /// `&mut self` methods and validates the configuration when it is built.
+    pub fn builder() -> HarnessBuilder {
+        HarnessBuilder::default()
+    }
+
+    /// Describes what the harness would run, without running anything.
+    ///
+    /// This is useful for logging or checking the configuration before
+    /// starting a long run. The number of words is only known if the
+    /// selected words can be resolved, e.g. if the file they are read from
+    /// exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{strategy::stupid::Stupid, Harness, WordSelection};
+    /// let harness = Harness::new()
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .words(WordSelection::RandomSample { n: 50, seed: Some(7) });
+    ///
+    /// let plan = harness.plan();
+    /// assert_eq!(plan.num_words, Some(50));
+    /// assert_eq!(plan.seed, Some(7));
+    /// println!("{}", plan);
+    /// ```
+    pub fn plan(&self) -> RunPlan {
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|(strategy, save_name)| PlannedStrategy {
+                name: strategy.to_string(),
+                version: strategy.version().to_string(),
+                save_name: save_name.clone(),
+                hardmode: strategy.hardmode(),
+            })
+            .collect();
+
+        let seed = match self.words {
+            WordSelection::RandomSample { seed, .. } => seed,
+            _ => None,
+        };
+
+        let baselines = self
+            .baselines
+            .iter()
+            .map(|baseline| match baseline {
+                BaselineOpt::Run(index, _) => PlannedBaseline::Run(*index),
+                #[cfg(feature = "serde")]
+                BaselineOpt::Saved(summary, name) => PlannedBaseline::Saved {
+                    name: name.clone(),
+                    strategy: summary.to_string(),
+                },
+            })
+            .collect();
+
+        RunPlan {
+            strategies,
+            words: self.words.clone(),
+            num_words: self.resolve_shard().ok().map(|(words, _, _)| words.len()),
+            seed,
+            shard: self.shard,
+            parallel: self.parallel,
+            baselines,
         }
     }
 
-    fn pre_run_check(&self) -> Result<()> {
+    /// Resolves the selected words and returns the ones in this harness's
+    /// shard, along with the position of the first of them among all of the
+    /// selected words and the number of selected words.
+    pub(crate) fn resolve_shard(&self) -> Result<(Vec<Word>, usize, usize)> {
+        let mut words = self.words.resolve()?;
+        let total = words.len();
+
+        let (index, shards) = self.shard;
+        let start = total * index / shards;
+        let end = total * (index + 1) / shards;
+        words.truncate(end);
+        words.drain(..start);
+
+        Ok((words, start, total))
+    }
+
+    pub(crate) fn pre_run_check(&self) -> Result<()> {
         if self.strategies.is_empty() {
             return Err(HarnessError::NoStrategiesAdded.into());
         }
 
-        if let Some(0) = self.num_guesses {
+        if self.words.is_empty() {
             return Err(HarnessError::NoWordsSelected.into());
         }
 
+        for baseline in &self.baselines {
+            match *baseline {
+                BaselineOpt::Run(index, _) if index >= self.strategies.len() => {
+                    return Err(HarnessError::BaselineOutOfRange(index).into());
+                }
+                _ => {}
+            }
+        }
+
+        // Resuming draws the words again, so they have to be the same.
+        #[cfg(feature = "serde")]
+        if (self.checkpoint.is_some() || self.resume.is_some())
+            && matches!(self.words, WordSelection::RandomSample { seed: None, .. })
+        {
+            return Err(HarnessError::UnseededCheckpoint.into());
+        }
+
+        #[cfg(feature = "serde")]
+        self.check_baseline_version()?;
+
+        Ok(())
+    }
+
+    /// Checks the version of each saved baseline against the added strategy
+    /// with the same name, if there is one.
+    #[cfg(feature = "serde")]
+    fn check_baseline_version(&self) -> Result<()> {
+        for baseline in &self.baselines {
+            if let BaselineOpt::Saved(baseline, _) = baseline {
+                self.check_version_of(baseline)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    fn check_version_of(&self, baseline: &Summary) -> Result<()> {
+        let (name, baseline_version) = (baseline.strategy_name(), baseline.strategy_version());
+
+        let current = self
+            .strategies
+            .iter()
+            .map(|(strategy, _)| strategy)
+            .find(|strategy| {
+                format!("{}", strategy) == name && strategy.version() != baseline_version
+            });
+
+        if let Some(strategy) = current {
+            match self.version_mismatch {
+                VersionMismatch::Ignore => {}
+                VersionMismatch::Warn => warning!(
+                    "the baseline was produced by {} v{}, but v{} is being tested",
+                    name,
+                    baseline_version,
+                    strategy.version()
+                ),
+                VersionMismatch::Error => {
+                    return Err(HarnessError::BaselineVersionMismatch {
+                        strategy: name.to_string(),
+                        baseline: baseline_version.to_string(),
+                        current: strategy.version().to_string(),
+                    }
+                    .into())
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -188,13 +694,15 @@ impl Harness {
     /// is useful for finding bugs in [`Strategy`](crate::Strategy) implementations.
     ///
     /// If `None` is passed instead of a word list, the harness will run the
-    /// strategies on all possible answers.
+    /// strategies on the words selected with [`words()`](Self::words()).
     ///
-    /// Note that this function will ignore the testing and parallelism settings
-    /// of the harness.
-    pub fn debug_run(&self, words: Option<&[Word]>) -> Result<Record> {
+    /// Note that this function will ignore the parallelism settings of the
+    /// harness.
+    pub fn debug_run(&mut self, words: Option<&[Word]>) -> Result<Record> {
         use std::panic::{self, AssertUnwindSafe};
 
+        let started = SystemTime::now();
+
         match self.pre_run_check() {
             Err(WordleError::Harness {
                 kind: HarnessError::NoWordsSelected,
@@ -205,304 +713,3987 @@ impl Harness {
 
         let mut perfs = Vec::new();
         for strat in &self.strategies {
-            perfs.push(Perf::new(strat.0.as_ref()))
+            perfs.push(Perf::new(strat.0.as_ref(), strat.1.as_deref()))
         }
 
-        let words = match words {
-            Some(w) => Vec::from(w),
-            None => ANSWERS
-                .iter()
-                .map(|&i| Word::from_index(i))
-                .collect::<Result<Vec<_>>>()
-                .unwrap(),
+        let selected;
+        let (words, offset, total, first_day) = match words {
+            Some(w) => (w, 0, w.len(), None),
+            None => {
+                let (shard, offset, total) = self.resolve_shard()?;
+                selected = shard;
+                (&selected[..], offset, total, self.words.first_day())
+            }
         };
 
-        for word in words.iter() {
+        self.prepare(words.len());
+
+        for (index, word) in (offset..).zip(words) {
+            let ctx = SolveContext::new(index, total, first_day.map(|d| d + index as u32));
             for (i, (strategy, _)) in self.strategies.iter().enumerate() {
+                if perfs[i].disqualification.is_some() {
+                    continue;
+                }
                 let key = AttemptsKey::new(strategy.hardmode());
                 let res = {
                     let wrapper = AssertUnwindSafe(strategy);
-                    panic::catch_unwind(|| {
-                        let mut puzzle = Puzzle::new(*word);
-                        let attempts = (*wrapper).solve(&mut puzzle, key);
+                    let mut puzzle = self.new_puzzle(*word);
+                    panic::catch_unwind(move || {
+                        let attempts = (*wrapper).solve_with_context(&mut puzzle, key, &ctx);
                         (puzzle, attempts)
                     })
                 }
                 .map_or_else(
                     |_| {
-                        println!("strategy {strategy} panicked on puzzle {word}");
-                        println!("------------");
+                        warning!("strategy {} panicked on puzzle {}", strategy, word);
                         None
                     },
                     Some,
                 );
-                if let Some((puzzle, solution)) = res {
-                    perfs[i].tries.push((*word, solution));
+                if let Some((mut puzzle, solution)) = res {
+                    if let Some(poisoning) = puzzle.poisoning() {
+                        let disqualification = Disqualification::new(*word, poisoning);
+                        warning!(
+                            "strategy {} was disqualified because it {}",
+                            strategy,
+                            disqualification
+                        );
+                        perfs[i].disqualification = Some(disqualification);
+                        continue;
+                    }
 
-                    if puzzle.poisoned {
-                        return Err(HarnessError::StrategyCheated(format!("{}", strategy)).into());
+                    perfs[i].tries.push((*word, solution));
+                    if let Some(checks) = puzzle.take_checks() {
+                        perfs[i].checks.push((*word, checks));
                     }
                 }
             }
         }
 
-        Ok(Record::new(perfs, self.baseline.clone()))
+        let info = self.run_info(started, false, &perfs);
+        let mut record = Record::new(perfs, self.baselines.clone());
+        record.info = Some(info);
+        Ok(record)
     }
 
     /// Runs the harness and produces performances for each strategy.
     ///
     /// The [`Perf`]s will be in the same order as the strategies were added
-    /// to the harness.
-    pub fn run(&self) -> Result<Record> {
-        fn cleanup(perfs: Arc<Mutex<Vec<Perf>>>, this: &Harness) -> Result<Record> {
-            let perfs = Arc::try_unwrap(perfs).unwrap().into_inner().unwrap();
+    /// to the harness. Before solving any puzzles, the harness calls
+    /// [`Strategy::prepare()`] on each strategy.
+    pub fn run(&mut self) -> Result<Record> {
+        self.run_with(RunState::new(self, None, None))
+    }
 
-            #[cfg(feature = "serde")]
-            for ((_, name), perf) in this.strategies.iter().zip(perfs.iter()) {
-                if let Some(name) = name {
-                    let summary = perf.to_summary();
-                    let dir = get_save_dir(None)?;
-                    summary.save(name, &dir, false)?;
-                }
-            }
+    /// Runs the harness until it finishes or `token` is cancelled.
+    ///
+    /// This is the same as [`run()`](Self::run()), except that cancelling
+    /// `token` from another thread stops the run early. The strategies finish
+    /// the puzzles they are working on, and then this returns the results so
+    /// far in a [`Record`] marked as incomplete (see
+    /// [`Record::is_complete()`]). Incomplete records are not saved as
+    /// baselines or archived.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{harness::CancelToken, strategy::stupid::Stupid, Harness};
+    /// let token = CancelToken::new();
+    /// let mut harness = Harness::new()
+    ///     .verbose(false)
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .test_all();
+    ///
+    /// // Usually called from another thread, e.g. by a "stop" button.
+    /// token.cancel();
+    ///
+    /// let record = harness.run_cancellable(&token)?;
+    /// assert!(!record.is_complete());
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn run_cancellable(&mut self, token: &CancelToken) -> Result<Record> {
+        let mut state = RunState::new(self, None, Some(token.0.clone()));
+        state.keep_partial = true;
+        self.run_with(state)
+    }
 
-            Ok(Record::new(perfs, this.baseline.clone()))
-        }
+    /// Runs one strategy per point in a parameter space and reports which
+    /// parameters worked best.
+    ///
+    /// `factory` is called with each parameter in `param_space`, which can
+    /// be anything from a list of numbers to the product of several lists,
+    /// e.g. made with [`itertools::iproduct!`]. The strategies it returns
+    /// replace any strategies added to the harness, along with the baseline,
+    /// and are run together on the selected words using the rest of the
+    /// harness's configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use wordle_rs::{Harness, Metric, Strategy};
+    /// # fn my_strategy(depth: u32, width: usize) -> Box<dyn Strategy> { unimplemented!() }
+    /// let space = itertools::iproduct!(1..=3, [10, 100, 1000]);
+    ///
+    /// let report = Harness::new()
+    ///     .test_num(500)
+    ///     .grid(space, |&(depth, width)| my_strategy(depth, width))?;
+    ///
+    /// println!("{}", report);
+    /// let (best, _) = report.best(Metric::MeanGuesses).unwrap();
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn grid<P, I, F>(self, param_space: I, factory: F) -> Result<GridReport<P>>
+    where
+        I: IntoIterator<Item = P>,
+        F: Fn(&P) -> Box<dyn Strategy>,
+    {
+        let params: Vec<P> = param_space.into_iter().collect();
+        let strategies = params.iter().map(|p| (factory(p), None)).collect();
 
-        self.pre_run_check()?;
+        let mut harness = Harness {
+            strategies,
+            baselines: Vec::new(),
+            ..self
+        };
+        let record = harness.run()?;
 
-        if self.verbose {
-            if self.parallel {
-                eprintln!(
-                    "Running {} strategies on {} words in parallel",
-                    self.strategies.len(),
-                    self.num_guesses.unwrap_or(ANSWERS.len())
-                );
-            } else {
-                eprintln!(
-                    "Running {} strategies on {} words sequentially",
-                    self.strategies.len(),
-                    self.num_guesses.unwrap_or(ANSWERS.len())
-                );
-            }
-        }
+        Ok(GridReport::new(
+            params
+                .into_iter()
+                .zip(record.iter().map(Perf::to_summary))
+                .collect(),
+        ))
+    }
 
-        let perfs = Arc::new(Mutex::new(Vec::new()));
-        {
-            let mut perfs = perfs.lock().unwrap();
-            for strat in &self.strategies {
-                perfs.push(Perf::new(strat.0.as_ref()))
+    /// Splits the selected words into `k` disjoint folds at random, runs
+    /// every strategy on each fold separately, and reports how much their
+    /// results vary between folds.
+    ///
+    /// The words are shuffled with `seed` before they are split, so the same
+    /// seed always gives the same folds. Since every word is in exactly one
+    /// fold, this takes as long as a single run on the selected words.
+    /// Each fold is run as though it were a separate run, so the
+    /// [`SolveContext`] a strategy is given counts only the words in its
+    /// fold.
+    ///
+    /// This function ignores any [`shard()`](Self::shard()), and the
+    /// strategies are not saved. Sinks added with
+    /// [`add_sink()`](Self::add_sink()) are told about each result and how
+    /// far along each fold is, but
+    /// [`on_complete()`](crate::sink::ResultSink::on_complete()) is never
+    /// called, since no single [`Record`] covers the whole run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{strategy::stupid::Stupid, Harness};
+    /// let report = Harness::new()
+    ///     .verbose(false)
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .test_num(50)
+    ///     .kfold(5, 2022)?;
+    ///
+    /// println!("{}", report);
+    /// let solved = report.solve_rate(0);
+    /// println!("solved {:.3} ± {:.3}", solved.mean, solved.std_dev);
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn kfold(&mut self, k: usize, seed: u64) -> Result<KFoldReport> {
+        self.pre_run_check()?;
+
+        let mut words = self.words.resolve()?;
+        if k == 0 || k > words.len() {
+            return Err(HarnessError::InvalidFolds {
+                folds: k,
+                words: words.len(),
             }
+            .into());
         }
+        words.shuffle(&mut StdRng::seed_from_u64(seed));
 
-        let mut rng = rand::thread_rng();
+        self.prepare(words.len());
 
-        let words: Vec<usize> = if let Some(n) = self.num_guesses {
-            sample(&mut rng, ANSWERS.len(), n).iter().collect()
-        } else {
-            (0..ANSWERS.len()).into_iter().collect()
-        };
+        let n = words.len();
+        let mut names = Vec::new();
+        let mut folds = Vec::with_capacity(k);
+        for fold in 0..k {
+            let (start, end) = (fold * n / k, (fold + 1) * n / k);
 
-        #[cfg(feature = "parallel")]
-        if self.parallel {
-            // parallel
+            let mut state = RunState::new(self, None, None);
+            state.total = end - start;
+            #[cfg(feature = "tracing")]
+            {
+                state.span = tracing::info_span!(
+                    "run",
+                    strategies = self.strategies.len(),
+                    words = end - start,
+                    parallel = self.parallel,
+                    fold
+                );
+            }
+            self.run_words(&words[start..end], 0, &mut state)?;
 
-            #[cfg(feature = "fancy")]
-            if self.verbose {
-                // parallel and fancy
-                words
-                    .iter()
-                    .par_bridge()
-                    .progress_count(words.len() as u64)
-                    .try_for_each(|&i| self.run_inner(ANSWERS[i], perfs.clone()))?;
+            progress!(self.verbose, "Ran fold {} of {}", fold + 1, k);
 
-                return cleanup(perfs, self);
-            }
+            names = state.names;
+            let perfs = state.perfs.into_inner().unwrap();
+            folds.push(perfs.iter().map(Perf::to_summary).collect());
+        }
 
-            // parallel but not fancy
-            words
-                .iter()
-                .par_bridge()
-                .try_for_each(|&i| self.run_inner(ANSWERS[i], perfs.clone()))?;
+        Ok(KFoldReport::new(names, folds))
+    }
 
-            return cleanup(perfs, self);
+    /// Runs `strategy` on the same words `trials` times and reports any word
+    /// it played differently between trials.
+    ///
+    /// Baselines and comparisons assume that a strategy makes the same
+    /// guesses every time it sees the same puzzle. Iterating over a
+    /// `HashMap`, breaking ties by the order threads finish, or seeding an
+    /// RNG from the clock can quietly break that, so this is worth checking
+    /// for every new strategy. `words` is resolved once, so even a random
+    /// sample is the same in every trial. Each trial is a separate run in a
+    /// quiet harness, using the same instance of the strategy.
+    ///
+    /// Returns an error if `trials` is less than 2 or the strategy cheated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{strategy::stupid::Stupid, Harness, WordSelection};
+    /// let report = Harness::check_determinism(
+    ///     Box::new(Stupid),
+    ///     WordSelection::RandomSample { n: 20, seed: None },
+    ///     3,
+    /// )?;
+    ///
+    /// assert!(report.is_deterministic());
+    /// println!("{}", report);
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn check_determinism(
+        mut strategy: Box<dyn Strategy>,
+        words: WordSelection,
+        trials: usize,
+    ) -> Result<DeterminismReport> {
+        if trials < 2 {
+            return Err(HarnessError::InvalidTrials(trials).into());
         }
+        let words = words.resolve()?;
 
-        {
-            // not parallel
-            #[cfg(feature = "fancy")]
-            if self.verbose {
-                // not parallel but fancy
-                words
-                    .iter()
-                    .progress_count(words.len() as u64)
-                    .try_for_each(|&i| self.run_inner(ANSWERS[i], perfs.clone()))?;
+        let mut name = strategy.to_string();
+        let mut first: Vec<Vec<Word>> = Vec::new();
+        let mut differences: Vec<Nondeterminism> = Vec::new();
+        for trial in 0..trials {
+            let mut harness = Harness::new()
+                .verbose(false)
+                .add_strategy(strategy, None)
+                .words(WordSelection::Explicit(words.clone()));
+            let record = harness.run()?;
+            strategy = harness.strategies.remove(0).0;
 
-                return cleanup(perfs, self);
+            let perf = &record[0];
+            name = perf.to_string();
+            if let Some(disqualification) = perf.disqualification() {
+                return Err(HarnessError::StrategyCheated {
+                    strategy: name,
+                    word: disqualification.word(),
+                    poisoning: disqualification.poisoning(),
+                }
+                .into());
             }
 
-            // neither parallel nor fancy
-            words
+            let guesses = perf
+                .tries()
                 .iter()
-                .try_for_each(|&i| self.run_inner(ANSWERS[i], perfs.clone()))?;
+                .map(|(_, attempts)| attempts.inner().to_vec());
+            if trial == 0 {
+                first = guesses.collect();
+                continue;
+            }
+            for ((index, guesses), expected) in guesses.enumerate().zip(&first) {
+                let known = differences.iter().any(|d| d.index == index);
+                if guesses != *expected && !known {
+                    differences.push(Nondeterminism {
+                        word: words[index],
+                        index,
+                        first: expected.clone(),
+                        trial,
+                        guesses,
+                    });
+                }
+            }
         }
+        differences.sort_by_key(|difference| difference.index);
 
-        cleanup(perfs, self)
+        Ok(DeterminismReport {
+            strategy: name,
+            trials,
+            num_words: words.len(),
+            differences,
+        })
     }
 
-    fn run_inner(&self, index: usize, perfs: Arc<Mutex<Vec<Perf>>>) -> Result<()> {
-        let word = Word::from_index(index).unwrap();
-        let mut puzzle = Puzzle::new(word);
+    /// Runs the harness on a background thread and streams the result of
+    /// each puzzle as soon as it is completed.
+    ///
+    /// This consumes the harness and returns a [`RecordHandle`] along with
+    /// the receiving end of a channel. The channel produces a [`PuzzleResult`]
+    /// for each strategy on each word, and it closes when the run finishes.
+    /// Call [`RecordHandle::join()`] to get the complete [`Record`], or
+    /// [`RecordHandle::abort()`] to stop the run early, for instance after
+    /// interim results show that a strategy is clearly broken.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::harness::Harness;
+    /// use wordle_rs::strategy::stupid::Stupid;
+    ///
+    /// let (handle, results) = Harness::new()
+    ///     .verbose(false)
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .test_num(10)
+    ///     .run_streaming();
+    ///
+    /// for result in results {
+    ///     println!("{} guessed {}", result.strategy_name, result.word);
+    /// }
+    ///
+    /// let record = handle.join()?;
+    /// assert_eq!(record[0].num_tried(), 10);
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn run_streaming(mut self) -> (RecordHandle, Receiver<PuzzleResult>) {
+        let (sender, receiver) = mpsc::channel();
+        let abort = Arc::new(AtomicBool::new(false));
 
-        for (i, strategy) in self.strategies.iter().enumerate() {
-            let key = AttemptsKey::new(strategy.0.hardmode());
-            let solution = strategy.0.solve(&mut puzzle, key);
-            {
-                let mut perfs = perfs.lock().unwrap();
-                perfs[i].tries.push((word, solution));
-            }
-            if puzzle.poisoned {
-                return Err(HarnessError::StrategyCheated(format!("{}", strategy.0)).into());
-            }
-        }
+        let state = RunState::new(&self, Some(sender), Some(abort.clone()));
+        let progress = state.progress.clone();
+        let handle = thread::spawn(move || self.run_with(state));
 
-        Ok(())
+        (
+            RecordHandle {
+                handle,
+                abort,
+                progress,
+            },
+            receiver,
+        )
     }
-}
 
-#[derive(Debug, Clone)]
-pub(crate) enum BaselineOpt {
-    None,
-    Run(usize, Option<String>),
-    #[cfg(feature = "serde")]
-    Saved(Box<Summary>, String),
-}
+    fn run_with(&mut self, mut state: RunState) -> Result<Record> {
+        self.pre_run_check()?;
 
-impl BaselineOpt {
-    pub(crate) fn get_summary(&self, perfs: &[Perf]) -> Option<Summary> {
-        match self {
-            Self::None => None,
-            Self::Run(n, _) => Some(perfs[*n].to_summary()),
-            #[cfg(feature = "serde")]
-            Self::Saved(s, _) => Some(s.deref().clone()),
+        let (words, offset, total) = self.resolve_shard()?;
+        if words.is_empty() {
+            return Err(HarnessError::NoWordsSelected.into());
         }
-    }
-}
 
-/// A record produced by the test harness of a particular run.
-///
-/// This struct contains the performance records of each strategy and
-/// information about the baseline set. It can print the performances and
-/// dereferences to the performance records themselves.
-#[derive(Debug, Clone)]
-pub struct Record {
-    perfs: Vec<Perf>,
-    baseline: BaselineOpt,
-}
+        self.prepare(words.len());
+        state.total = total;
+        state.first_day = self.words.first_day();
 
-impl Deref for Record {
-    type Target = [Perf];
+        #[cfg(feature = "serde")]
+        if let Some(record) = &self.resume {
+            state.resume_from(record)?;
+        }
+        #[cfg(feature = "serde")]
+        if let Some((n, path)) = &self.checkpoint {
+            state.checkpoint = Some((*n, path.clone(), self.baselines.clone()));
+        }
 
-    fn deref(&self) -> &Self::Target {
-        &self.perfs
-    }
-}
+        #[cfg(feature = "tracing")]
+        {
+            state.span = tracing::info_span!(
+                "run",
+                strategies = self.strategies.len(),
+                words = words.len(),
+                parallel = self.parallel
+            );
+        }
+        progress!(
+            self.verbose,
+            "Running {} strategies on {} words {}",
+            self.strategies.len(),
+            words.len(),
+            if self.parallel {
+                "in parallel"
+            } else {
+                "sequentially"
+            }
+        );
 
-impl Record {
-    /// Create a new [`Record`] from perfs and baseline configuration.
-    fn new(perfs: Vec<Perf>, baseline: BaselineOpt) -> Self {
-        Self { perfs, baseline }
+        let res = self.run_words(&words, offset, &mut state);
+
+        #[cfg(feature = "serde")]
+        state.write_checkpoint()?;
+
+        let complete = match res {
+            Ok(()) => true,
+            Err(WordleError::Harness {
+                kind: HarnessError::Aborted,
+            }) if state.keep_partial => false,
+            Err(e) => return Err(e),
+        };
+        self.finish(state, complete)
     }
 
-    /// Prints a report detailing each strategy's performance.
+    /// Keeps running the strategies on random words until each of them is
+    /// significantly different from the baselines or `max_words` words have
+    /// been tried.
     ///
-    /// This will use the baseline configuration passed to the test harness.
-    pub fn print_report(&self) -> Result<()> {
-        match self.baseline.get_summary(&self.perfs) {
-            Some(baseline_summary) => {
-                let mut printed_baseline = false;
-                for perf in self.perfs.iter() {
-                    let summary = perf.to_summary();
-                    match summary.print(
-                        Summary::print_options()
-                            .compare(&baseline_summary)
-                            .histogram(true),
-                    ) {
-                        Ok(()) => {}
-                        Err(WordleError::SelfComparison) => {
-                            printed_baseline = true;
-                            summary
-                                .print(
-                                    Summary::print_options()
-                                        .histogram(true)
-                                        .baseline(&self.baseline),
-                                )
-                                .unwrap()
+    /// The harness runs the strategies on batches of words. After each
+    /// batch, it compares every strategy against each baseline set with
+    /// [`and_baseline()`](Self::and_baseline()) or
+    /// [`load_baseline()`](Self::load_baseline()) and stops once all of the
+    /// comparisons show a significant difference in either the fraction of
+    /// puzzles solved or the number of guesses used. Since looking at the
+    /// results repeatedly inflates the chance of a false positive, each look
+    /// uses a threshold of `alpha` divided by the number of planned looks
+    /// (a Bonferroni correction), which keeps the overall error rate at or
+    /// below `alpha`.
+    ///
+    /// This function ignores the word count configured with
+    /// [`test_num()`](Self::test_num()) or [`test_all()`](Self::test_all()),
+    /// as well as any [`shard()`](Self::shard()). The words are sampled with
+    /// the seed of a [`WordSelection::RandomSample`] selection if it has one,
+    /// or a random seed otherwise. Either way, the record's [`RunInfo`] holds
+    /// the seed and the words that were tried, so the run can be repeated.
+    ///
+    /// Returns an error if `alpha` is not in (0, 1).
+    #[cfg(feature = "stats")]
+    pub fn until_significant(&mut self, alpha: f64, max_words: usize) -> Result<Record> {
+        const BATCH: usize = 100;
+
+        crate::perf::check_alpha(alpha)?;
+        if self.strategies.is_empty() {
+            return Err(HarnessError::NoStrategiesAdded.into());
+        }
+        if self.baselines.is_empty() {
+            return Err(HarnessError::NoBaseline.into());
+        }
+
+        let max_words = max_words.min(ANSWERS.len());
+        if max_words == 0 {
+            return Err(HarnessError::NoWordsSelected.into());
+        }
+
+        let looks = (max_words + BATCH - 1) / BATCH;
+        let look_alpha = alpha / looks as f64;
+
+        let seed = match self.words {
+            WordSelection::RandomSample {
+                seed: Some(seed), ..
+            } => seed,
+            _ => rand::random(),
+        };
+        let words = WordSelection::RandomSample {
+            n: max_words,
+            seed: Some(seed),
+        }
+        .resolve()?;
+
+        self.prepare(max_words);
+
+        let mut state = RunState::new(self, None, None);
+        state.total = max_words;
+        #[cfg(feature = "tracing")]
+        {
+            state.span = tracing::info_span!(
+                "run",
+                strategies = self.strategies.len(),
+                words = max_words,
+                parallel = self.parallel
+            );
+        }
+        let mut tried = 0;
+        for (look, batch) in words.chunks(BATCH).enumerate() {
+            self.run_words(batch, look * BATCH, &mut state)?;
+            tried += batch.len();
+
+            let perfs = state.perfs.lock().unwrap();
+            let baselines: Vec<Summary> = self
+                .baselines
+                .iter()
+                .filter_map(|baseline| baseline.get_summary(&perfs))
+                .collect();
+            let significant = perfs
+                .iter()
+                .enumerate()
+                .filter(|(i, perf)| {
+                    perf.disqualification().is_none()
+                        && !self
+                            .baselines
+                            .iter()
+                            .any(|baseline| baseline.is(&BaselineOpt::Run(*i, None)))
+                })
+                .map(|(_, perf)| perf.to_summary())
+                .all(|summary| {
+                    baselines.iter().all(|baseline| {
+                        match summary.compare_with(baseline, look_alpha, TestKind::default()) {
+                            Ok(comparison) => {
+                                comparison.is_sig_solved() || comparison.is_sig_guesses()
+                            }
+                            Err(_) => false,
                         }
-                        Err(e) => return Err(e),
-                    }
+                    })
+                });
+
+            progress!(
+                self.verbose,
+                "Ran {} words (look {} of {}), {}",
+                tried,
+                look + 1,
+                looks,
+                if significant {
+                    "all strategies differ significantly from the baselines"
+                } else {
+                    "continuing"
                 }
-                if !printed_baseline {
-                    baseline_summary
-                        .print(
-                            Summary::print_options()
-                                .histogram(true)
-                                .baseline(&self.baseline),
-                        )
-                        .unwrap()
+            );
+
+            if significant {
+                break;
+            }
+        }
+
+        state.sampled = Some((words[..tried].to_vec(), seed));
+        self.finish(state, true)
+    }
+
+    /// Calls [`Strategy::prepare()`] on every strategy before a run on
+    /// `num_puzzles` puzzles.
+    pub(crate) fn prepare(&mut self, num_puzzles: usize) {
+        let ctx = RunContext::new(num_puzzles, self.parallel);
+        for (strategy, _) in self.strategies.iter_mut() {
+            strategy.prepare(&ctx);
+        }
+    }
+
+    /// Runs every strategy on each of the given words, adding their attempts
+    /// to `state`.
+    ///
+    /// `offset` is the position of the first word among all of the words in
+    /// the run.
+    fn run_words(&self, words: &[Word], offset: usize, state: &mut RunState) -> Result<()> {
+        let n = self.strategies.len();
+        let tasks: Vec<(usize, Word, Range<usize>)> = match self.schedule {
+            Schedule::ByWord => words
+                .iter()
+                .enumerate()
+                .map(|(i, &word)| (offset + i, word, 0..n))
+                .collect(),
+            Schedule::Interleaved => words
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &word)| (0..n).map(move |s| (offset + i, word, s..s + 1)))
+                .collect(),
+        };
+
+        #[cfg(feature = "fancy")]
+        {
+            state.bar = if self.verbose {
+                let bar = ProgressBar::new((words.len() * n) as u64);
+                bar.set_style(
+                    ProgressStyle::default_bar().template("{wide_bar} {pos}/{len} ({eta})\n{msg}"),
+                );
+                Some(bar)
+            } else {
+                None
+            };
+        }
+        state.expect(words.len());
+        let state = &*state;
+
+        #[cfg(feature = "parallel")]
+        let res = if self.parallel {
+            tasks
+                .into_iter()
+                .par_bridge()
+                .try_for_each(|(i, word, strategies)| self.run_inner(i, word, strategies, state))
+        } else {
+            tasks
+                .into_iter()
+                .try_for_each(|(i, word, strategies)| self.run_inner(i, word, strategies, state))
+        };
+        #[cfg(not(feature = "parallel"))]
+        let res = tasks
+            .into_iter()
+            .try_for_each(|(i, word, strategies)| self.run_inner(i, word, strategies, state));
+
+        #[cfg(feature = "fancy")]
+        if let Some(bar) = &state.bar {
+            bar.finish();
+        }
+
+        res
+    }
+
+    /// Saves the named strategies and produces the final [`Record`].
+    ///
+    /// If the run did not `complete`, the record is marked as incomplete and
+    /// nothing is saved or sent to the sinks. Otherwise the sinks are sent
+    /// the record before anything is saved.
+    fn finish(&self, state: RunState, complete: bool) -> Result<Record> {
+        #[cfg(feature = "tracing")]
+        tracing::info!(parent: &state.span, complete, "finished run");
+
+        let perfs = state.perfs.into_inner().unwrap();
+        #[allow(unused_mut)]
+        let mut info = self.run_info(state.started, self.parallel, &perfs);
+        #[cfg(feature = "stats")]
+        if let Some((words, seed)) = state.sampled {
+            info.words = WordSelection::Explicit(words);
+            info.seed = Some(seed);
+        }
+
+        if !complete {
+            let mut record = Record::new(perfs, self.baselines.clone());
+            record.incomplete = true;
+            record.info = Some(info);
+            return Ok(record);
+        }
+
+        let mut record = Record::new(perfs, self.baselines.clone());
+        record.info = Some(info);
+
+        // The sinks are told before anything is saved, so that a failing sink
+        // leaves nothing behind. Every sink is still told if one fails.
+        let mut sinks_result = Ok(());
+        for sink in self.sinks.lock().unwrap().iter_mut() {
+            let res = sink.on_complete(&record);
+            if sinks_result.is_ok() {
+                sinks_result = res;
+            }
+        }
+        sinks_result?;
+
+        #[cfg(feature = "serde")]
+        for ((_, name), perf) in self.strategies.iter().zip(record.iter()) {
+            if perf.disqualification().is_some() {
+                continue;
+            }
+            if let Some(name) = name {
+                let summary = perf.to_summary();
+                let dir = get_save_dir(None)?;
+                summary.save(name, &dir, false)?;
+            }
+        }
+
+        #[cfg(all(feature = "serde", feature = "stats"))]
+        {
+            let update = self.update_best_baseline(&record)?;
+            if let Some(info) = record.info.as_mut() {
+                info.baseline_update = update;
+            }
+        }
+
+        Ok(record)
+    }
+
+    /// Compares the best strategy in `perfs` against the baseline set with
+    /// [`update_baseline_if_better()`](Self::update_baseline_if_better()),
+    /// replacing it if the strategy is significantly better.
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    fn update_best_baseline(&self, perfs: &[Perf]) -> Result<Option<BaselineUpdate>> {
+        let (name, dir, metric) = match &self.best_baseline {
+            Some((name, dir, metric)) => (name.clone(), get_save_dir(dir.as_deref())?, *metric),
+            None => return Ok(None),
+        };
+        let best = match perfs
+            .iter()
+            .filter(|perf| perf.disqualification().is_none())
+            .map(Perf::to_summary)
+            .min_by(|a, b| metric.compare(a, b))
+        {
+            Some(best) => best,
+            None => return Ok(None),
+        };
+        let strategy = best.to_string();
+
+        let (path, format) = saved_path(&name, &dir);
+        if !path.exists() {
+            best.replace_saved(&path, format)?;
+            return Ok(Some(BaselineUpdate::Created { name, strategy }));
+        }
+
+        let saved = Summary::from_saved(&name, &dir)?;
+        let better = best
+            .compare(&saved)
+            .map_or(false, |comparison| comparison.is_improvement(metric));
+        if better {
+            best.replace_saved(&path, format)?;
+            Ok(Some(BaselineUpdate::Replaced { name, strategy }))
+        } else {
+            Ok(Some(BaselineUpdate::Kept { name }))
+        }
+    }
+
+    /// Creates the puzzle strategies solve for `word`, recording checks if
+    /// the harness was told to.
+    fn new_puzzle(&self, word: Word) -> Puzzle {
+        if self.record_checks {
+            Puzzle::new_recording(word)
+        } else {
+            Puzzle::new(word)
+        }
+    }
+
+    /// Describes a run that started at `started` and has just finished.
+    fn run_info(&self, started: SystemTime, parallel: bool, perfs: &[Perf]) -> RunInfo {
+        let seed = match self.words {
+            WordSelection::RandomSample { seed, .. } => seed,
+            _ => None,
+        };
+
+        RunInfo {
+            started,
+            finished: SystemTime::now(),
+            host: hostname(),
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            words: self.words.clone(),
+            seed,
+            shard: self.shard,
+            parallel,
+            durations: perfs
+                .iter()
+                .map(|perf| (perf.to_string(), perf.elapsed()))
+                .collect(),
+            #[cfg(all(feature = "serde", feature = "stats"))]
+            baseline_update: None,
+        }
+    }
+
+    /// Runs the strategies at the indices in `strategies` on `word`, the
+    /// `index`th word in the run.
+    fn run_inner(
+        &self,
+        index: usize,
+        word: Word,
+        strategies: Range<usize>,
+        state: &RunState,
+    ) -> Result<()> {
+        if state.aborted() {
+            return Err(HarnessError::Aborted.into());
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::info_span!(parent: &state.span, "puzzle", index, word = %word).entered();
+
+        let ctx = SolveContext::new(
+            index,
+            state.total,
+            state.first_day.map(|d| d + index as u32),
+        );
+
+        let units = strategies.len();
+        for i in strategies {
+            let strategy = &self.strategies[i];
+            if state.done.get(i).map_or(false, |done| done.contains(&word))
+                || state.perfs.lock().unwrap()[i].disqualification.is_some()
+            {
+                self.tick(state, None)?;
+                continue;
+            }
+
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("solve", strategy = %state.names[i]).entered();
+
+            // Every strategy gets its own puzzle, so that nothing one does to
+            // it (like poisoning it) can leak into the next one's run.
+            let mut puzzle = self.new_puzzle(word);
+            let key = AttemptsKey::new(strategy.0.hardmode());
+            let start = Instant::now();
+            let solution = strategy.0.solve_with_context(&mut puzzle, key, &ctx);
+            let elapsed = start.elapsed();
+
+            // A strategy that cheats is disqualified, but the others carry on.
+            if let Some(poisoning) = puzzle.poisoning() {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(%poisoning, "strategy cheated and was disqualified");
+                state.perfs.lock().unwrap()[i]
+                    .disqualification
+                    .get_or_insert(Disqualification::new(word, poisoning));
+                state.stop(i);
+                self.tick(state, None)?;
+                continue;
+            }
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                elapsed_us = elapsed.as_micros() as u64,
+                guesses = solution.inner().len(),
+                solved = solution.solved(&word),
+                "solved puzzle"
+            );
+            if let Some(results) = &state.results {
+                let result = PuzzleResult {
+                    strategy_index: i,
+                    strategy_name: state.names[i].clone(),
+                    word,
+                    attempts: solution.clone(),
+                };
+                // The receiver hanging up should not stop the run.
+                let _ = results.lock().unwrap().send(result);
+            }
+            if state.has_sinks {
+                for sink in self.sinks.lock().unwrap().iter_mut() {
+                    let strategy = StrategyId {
+                        index: i,
+                        name: &state.names[i],
+                    };
+                    sink.on_result(strategy, word, &solution)?;
                 }
             }
-            None => {
-                for perf in self.perfs.iter() {
-                    let summary = perf.to_summary();
-                    summary.print(Summary::print_options().histogram(true))?;
+            {
+                let mut perfs = state.perfs.lock().unwrap();
+                perfs[i].tries.push((word, solution));
+                perfs[i].elapsed += elapsed;
+                if let Some(checks) = puzzle.take_checks() {
+                    perfs[i].checks.push((word, checks));
                 }
             }
+            self.tick(state, Some(i))?;
+        }
+
+        // Checkpoints are every `n` words' worth of work, however the work
+        // is split up.
+        #[cfg(feature = "serde")]
+        if let Some((n, _, _)) = &state.checkpoint {
+            let per_checkpoint = n * self.strategies.len();
+            let before = state.completed.fetch_add(units, Ordering::Relaxed);
+            if (before + units) / per_checkpoint > before / per_checkpoint {
+                state.write_checkpoint()?;
+            }
+        }
+        #[cfg(not(feature = "serde"))]
+        let _ = units;
+
+        Ok(())
+    }
+
+    /// Counts a strategy finishing a word, or a word being skipped when
+    /// `strategy` is `None`, and tells the sinks how far along the run is.
+    fn tick(&self, state: &RunState, strategy: Option<usize>) -> Result<()> {
+        if !state.has_sinks {
+            state.tick(strategy, false);
+            return Ok(());
+        }
+
+        // The sinks stay locked so that they see the progress in order.
+        let mut sinks = self.sinks.lock().unwrap();
+        if let Some(progress) = state.tick(strategy, true) {
+            for sink in sinks.iter_mut() {
+                sink.on_progress(&progress)?;
+            }
         }
 
         Ok(())
     }
 }
 
-/// Gets the save directory given an optional path to use.
+/// The words that the test harness runs strategies on.
 ///
-/// If a path is passed to this function, it will return that. Otherwise, it
-/// will return the value of the `WORDLE_BASELINE_DIR` environment variable.
-/// If that environment variable is not set, it will return the `wordle_baseline/`
-/// directory in the current working directory (the directory of the running
-/// process.)
+/// Set this with [`Harness::words()`]. It can be serialized alongside other
+/// configuration so that runs are reproducible.
+///
+/// # Examples
+///
+/// ```rust
+/// # use wordle_rs::harness::WordSelection;
+/// # use wordle_rs::strategy::Word;
+/// let seeded = WordSelection::RandomSample { n: 10, seed: Some(2022) };
+/// assert_eq!(seeded.resolve()?, seeded.resolve()?);
+///
+/// let first = WordSelection::Range(0..3).resolve()?;
+/// assert_eq!(first, &Word::ANSWERS[0..3]);
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum WordSelection {
+    /// Every possible Wordle answer.
+    All,
+
+    /// `n` answers chosen at random.
+    ///
+    /// When `seed` is provided, the same answers are chosen every time.
+    RandomSample {
+        /// The number of answers to choose.
+        n: usize,
+
+        /// The seed for the random number generator, if any.
+        seed: Option<u64>,
+    },
+
+    /// A specific list of words, which do not need to be possible answers.
+    Explicit(Vec<Word>),
+
+    /// The answers at these indices into [`ANSWERS`].
+    Range(Range<usize>),
+
+    /// The words listed in a file, separated by whitespace.
+    FromFile(PathBuf),
+
+    /// The answers to the daily puzzles from the start of the range up to
+    /// but not including the end, from the built-in
+    /// [`DailyAnswers`](crate::words::daily::DailyAnswers).
+    Daily(Range<Date>),
+
+    /// The `n` words that the strategies in a saved [`Record`] found
+    /// hardest, as ranked by
+    /// [`DifficultyRanking`](crate::analysis::difficulty::DifficultyRanking),
+    /// or every word in the record if it has fewer.
+    ///
+    /// This is useful for working on a strategy's weaknesses without running
+    /// it on every answer each time.
+    #[cfg(feature = "serde")]
+    Hardest {
+        /// The path of the record, like one written by [`Record::save()`].
+        from_record: PathBuf,
+
+        /// The number of words to choose.
+        n: usize,
+    },
+}
+
+impl Default for WordSelection {
+    fn default() -> Self {
+        Self::RandomSample { n: 100, seed: None }
+    }
+}
+
+impl WordSelection {
+    /// Produces the list of words this selection refers to.
+    ///
+    /// Returns an error if the selection refers to words that do not exist,
+    /// such as a sample larger than the number of answers, a range past the
+    /// end of [`ANSWERS`] or a file containing invalid words.
+    pub fn resolve(&self) -> Result<Vec<Word>> {
+        match self {
+            Self::All => Ok(Word::ANSWERS.to_vec()),
+            Self::RandomSample { n, seed } => {
+                if *n > ANSWERS.len() {
+                    return Err(HarnessError::TooManyWords(*n).into());
+                }
+
+                let indices = match seed {
+                    Some(seed) => sample(&mut StdRng::seed_from_u64(*seed), ANSWERS.len(), *n),
+                    None => sample(&mut rand::thread_rng(), ANSWERS.len(), *n),
+                };
+
+                Ok(indices.iter().map(|i| Word::ANSWERS[i]).collect())
+            }
+            Self::Explicit(words) => Ok(words.clone()),
+            Self::Range(range) => range.clone().map(Word::from_answer_index).collect(),
+            Self::FromFile(path) => std::fs::read_to_string(path)
+                .map_err(|e| HarnessError::WordsRead(Box::new(e)))?
+                .split_whitespace()
+                .map(Word::from_str)
+                .collect(),
+            Self::Daily(dates) => DailyAnswers::builtin().range(dates.clone()),
+            #[cfg(feature = "serde")]
+            Self::Hardest { from_record, n } => {
+                let record = Record::read(from_record)?;
+                let ranking = DifficultyRanking::from_perfs(record.iter());
+                Ok(ranking.hardest(*n).iter().map(|word| word.word).collect())
+            }
+        }
+    }
+
+    /// Gets the day number of the daily puzzle for the first word, if this
+    /// selection refers to daily puzzles.
+    pub(crate) fn first_day(&self) -> Option<u32> {
+        match self {
+            Self::Daily(dates) => dates.start.day_number(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this selection obviously contains no words.
+    ///
+    /// Selections read from a file are only known to be empty once resolved.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::RandomSample { n, .. } => *n == 0,
+            Self::Explicit(words) => words.is_empty(),
+            Self::Range(range) => range.is_empty(),
+            Self::Daily(dates) => dates.start >= dates.end,
+            #[cfg(feature = "serde")]
+            Self::Hardest { n, .. } => *n == 0,
+            Self::All | Self::FromFile(_) => false,
+        }
+    }
+}
+
+/// How the harness splits a run into pieces of work.
+///
+/// See [`Harness::schedule()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Schedule {
+    /// Runs every strategy on a word, one after another, as one piece of
+    /// work.
+    ///
+    /// Every strategy's results arrive at the same pace, which is the pace of
+    /// the slowest strategy.
+    ByWord,
+
+    /// Runs each strategy on each word as its own piece of work, taking the
+    /// strategies in turn for each word.
+    ///
+    /// In a parallel run, a fast strategy no longer waits for a slow one to
+    /// finish the same word, but every strategy still starts on the words in
+    /// the same order, so each one has partial results throughout the run.
+    /// Sequential runs are the same either way.
+    Interleaved,
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self::ByWord
+    }
+}
+
+/// What the harness does when a saved baseline was produced by a different
+/// version of a strategy being tested.
+///
+/// See [`Harness::on_version_mismatch()`].
 #[cfg(feature = "serde")]
-pub fn get_save_dir<'a>(user: impl Into<Option<&'a Path>>) -> Result<PathBuf> {
-    let user: Option<&Path> = user.into();
-    let var = std::env::var_os("WORDLE_BASELINE_DIR");
-    let default = std::env::current_dir()
-        .map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?
-        .join("wordle_baseline");
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VersionMismatch {
+    /// Runs without saying anything.
+    Ignore,
 
-    let dir = match &user {
-        Some(p) => p,
-        None => match &var {
-            Some(s) => Path::new(s),
-            None => default.as_path(),
-        },
-    };
+    /// Prints a warning to stderr and runs anyway.
+    Warn,
 
-    Ok(dir.to_path_buf())
+    /// Refuses to run, returning [`HarnessError::BaselineVersionMismatch`].
+    Error,
 }
 
-#[cfg(test)]
-mod test {
-    use std::path::Path;
+#[cfg(feature = "serde")]
+impl Default for VersionMismatch {
+    fn default() -> Self {
+        Self::Warn
+    }
+}
 
-    use super::*;
-    use crate::mock::Mock;
+/// A non-consuming builder for a [`Harness`].
+///
+/// The configuration methods on [`Harness`] consume it, which makes it awkward
+/// to configure conditionally. The methods on this builder take `&mut self`
+/// instead, and any problems with the configuration, such as setting the
+/// same baseline twice or selecting zero words, are reported by
+/// [`build()`](Self::build()) rather than when the harness runs.
+///
+/// # Examples
+///
+/// ```rust
+/// # use wordle_rs::harness::Harness;
+/// use wordle_rs::strategy::stupid::Stupid;
+///
+/// # let parallel = false;
+/// let mut builder = Harness::builder();
+/// builder.verbose(false).add_strategy(Box::new(Stupid), None).test_num(50);
+/// if parallel {
+///     builder.parallel(true);
+/// }
+///
+/// let harness = builder.build()?;
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct HarnessBuilder {
+    harness: Harness,
+    error: Option<WordleError>,
+}
 
-    #[test]
-    fn save_dir_passthru() -> Result<()> {
-        assert_eq!(
-            Path::new("/temp/testing"),
-            get_save_dir(Some(Path::new("/temp/testing")))?.as_path()
+impl HarnessBuilder {
+    /// Creates a new builder with the same defaults as [`Harness::new()`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the first configuration error, to be returned by `build()`.
+    fn fail(&mut self, error: impl Into<WordleError>) -> &mut Self {
+        if self.error.is_none() {
+            self.error = Some(error.into());
+        }
+        self
+    }
+
+    /// Makes the harness verbose while testing.
+    ///
+    /// See [`Harness::verbose()`].
+    pub fn verbose(&mut self, verbose: bool) -> &mut Self {
+        self.harness.verbose = verbose;
+        self
+    }
+
+    /// Tells the harness to run strategies in parallel.
+    ///
+    /// See [`Harness::parallel()`].
+    pub fn parallel(&mut self, parallel: bool) -> &mut Self {
+        self.harness.parallel = parallel;
+        self
+    }
+
+    /// Sets how the harness splits a run into pieces of work.
+    ///
+    /// See [`Harness::schedule()`].
+    pub fn schedule(&mut self, schedule: Schedule) -> &mut Self {
+        self.harness.schedule = schedule;
+        self
+    }
+
+    /// Tells the harness to record every call each strategy makes to
+    /// [`Puzzle::check()`].
+    ///
+    /// See [`Harness::record_checks()`].
+    pub fn record_checks(&mut self, record_checks: bool) -> &mut Self {
+        self.harness.record_checks = record_checks;
+        self
+    }
+
+    /// Tells the harness to write every run into the
+    /// [`RunStore`](crate::storage::RunStore) at `path`, tagged with `label`.
+    ///
+    /// See [`Harness::archive()`].
+    #[cfg(feature = "storage")]
+    pub fn archive(&mut self, path: impl Into<PathBuf>, label: Option<&str>) -> &mut Self {
+        self.add_sink(Box::new(crate::sink::StoreSink::new(path, label)))
+    }
+
+    /// Adds a [`ResultSink`] to the harness.
+    ///
+    /// See [`Harness::add_sink()`].
+    pub fn add_sink(&mut self, sink: Box<dyn ResultSink>) -> &mut Self {
+        self.harness.sinks.get_mut().unwrap().push(sink);
+        self
+    }
+
+    /// Adds a strategy to the harness for testing.
+    pub fn add_strategy<'a>(
+        &mut self,
+        strat: Box<dyn Strategy>,
+        save_name: impl Into<Option<&'a str>>,
+    ) -> &mut Self {
+        self.harness
+            .strategies
+            .push((strat, save_name.into().map(|s| s.to_string())));
+        self
+    }
+
+    /// Adds a [`Vec`] of strategies to the harness for testing.
+    pub fn add_strategies(
+        &mut self,
+        strats: Vec<(Box<dyn Strategy>, Option<String>)>,
+    ) -> &mut Self {
+        self.harness.strategies.extend(strats);
+        self
+    }
+
+    /// Adds a strategy to the harness for testing and sets it as a baseline
+    /// for comparison.
+    pub fn add_baseline<'a>(
+        &mut self,
+        strat: Box<dyn Strategy>,
+        save_name: impl Into<Option<&'a str>>,
+    ) -> &mut Self {
+        self.add_strategy(strat, save_name).and_baseline()
+    }
+
+    /// Sets the most recently added strategy as a baseline for comparisons.
+    ///
+    /// See [`Harness::and_baseline()`].
+    pub fn and_baseline(&mut self) -> &mut Self {
+        match self.harness.strategies.len() {
+            0 => self.fail(HarnessError::NoStrategiesAdded),
+            n => self.baseline_index(n - 1),
+        }
+    }
+
+    /// Sets the strategy at `index`, in the order strategies were added, as
+    /// a baseline for comparisons.
+    pub fn baseline_index(&mut self, index: usize) -> &mut Self {
+        let name = self
+            .harness
+            .strategies
+            .get(index)
+            .and_then(|(_, name)| name.clone());
+        match push_baseline(&mut self.harness.baselines, BaselineOpt::Run(index, name)) {
+            Ok(()) => self,
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Adds a saved performance record as a baseline for comparisons.
+    ///
+    /// See [`Harness::load_baseline()`].
+    #[cfg(feature = "serde")]
+    pub fn load_baseline<'a>(&mut self, name: &str, dir: impl Into<Option<&'a Path>>) -> &mut Self {
+        let baseline = get_save_dir(dir)
+            .and_then(|dir| Summary::from_saved(name, dir))
+            .and_then(|baseline| {
+                push_baseline(
+                    &mut self.harness.baselines,
+                    BaselineOpt::Saved(Box::new(baseline), name.to_string()),
+                )
+            });
+        match baseline {
+            Ok(()) => self,
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Adds every saved performance record whose name matches `pattern` as a
+    /// baseline for comparisons.
+    ///
+    /// See [`Harness::load_baselines()`].
+    #[cfg(feature = "serde")]
+    pub fn load_baselines<'a>(
+        &mut self,
+        pattern: &str,
+        dir: impl Into<Option<&'a Path>>,
+    ) -> &mut Self {
+        match get_save_dir(dir).and_then(|dir| saved_baselines(pattern, &dir)) {
+            Ok(baselines) => {
+                for (name, baseline) in baselines {
+                    let baseline = BaselineOpt::Saved(Box::new(baseline), name);
+                    if !self.harness.baselines.iter().any(|b| b.is(&baseline)) {
+                        self.harness.baselines.push(baseline);
+                    }
+                }
+                self
+            }
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Sets what the harness does when a saved baseline was produced by a
+    /// different version of an added strategy.
+    ///
+    /// See [`Harness::on_version_mismatch()`].
+    #[cfg(feature = "serde")]
+    pub fn on_version_mismatch(&mut self, version_mismatch: VersionMismatch) -> &mut Self {
+        self.harness.version_mismatch = version_mismatch;
+        self
+    }
+
+    /// Keeps the saved baseline `name` as the best strategy seen so far by
+    /// `metric`.
+    ///
+    /// See [`Harness::update_baseline_if_better()`].
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    pub fn update_baseline_if_better<'a>(
+        &mut self,
+        name: &str,
+        dir: impl Into<Option<&'a Path>>,
+        metric: Metric,
+    ) -> &mut Self {
+        let dir = dir.into().map(Path::to_path_buf);
+        self.harness.best_baseline = Some((name.to_string(), dir, metric));
+        self
+    }
+
+    /// Sets the words the harness tests each strategy on.
+    ///
+    /// See [`Harness::words()`].
+    pub fn words(&mut self, words: WordSelection) -> &mut Self {
+        self.harness.words = words;
+        self
+    }
+
+    /// Sets the harness to test each strategy on each possible Wordle answer.
+    pub fn test_all(&mut self) -> &mut Self {
+        self.words(WordSelection::All)
+    }
+
+    /// Sets the harness to test each strategy on `n` random Wordle answers.
+    ///
+    /// Unlike [`Harness::test_num()`], `n` is not clamped: asking for more
+    /// words than there are answers is an error when building.
+    pub fn test_num(&mut self, n: usize) -> &mut Self {
+        self.words(WordSelection::RandomSample { n, seed: None })
+    }
+
+    /// Sets the harness to test each strategy on the daily puzzles from the
+    /// start of `dates` up to but not including the end.
+    ///
+    /// If the answer to any of those puzzles is not known, building fails.
+    pub fn test_daily_range(&mut self, dates: Range<Date>) -> &mut Self {
+        let words = WordSelection::Daily(dates);
+        match words.resolve() {
+            Ok(_) => self.words(words),
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Tells the harness to run on only one of `total` slices of the selected
+    /// words.
+    ///
+    /// See [`Harness::shard()`]. If `index` is not less than `total`,
+    /// building fails.
+    pub fn shard(&mut self, index: usize, total: usize) -> &mut Self {
+        if index >= total {
+            return self.fail(HarnessError::InvalidShard { index, total });
+        }
+
+        self.harness.shard = (index, total);
+        self
+    }
+
+    /// Validates the configuration and produces the [`Harness`].
+    ///
+    /// This returns the first error encountered while configuring, or an
+    /// error if the configuration cannot run, e.g. because no strategies
+    /// were added, zero words were selected or the baseline does not refer
+    /// to an added strategy.
+    pub fn build(self) -> Result<Harness> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        if let WordSelection::RandomSample { n, .. } = self.harness.words {
+            if n > ANSWERS.len() {
+                return Err(HarnessError::TooManyWords(n).into());
+            }
+        }
+
+        self.harness.pre_run_check()?;
+
+        Ok(self.harness)
+    }
+}
+
+/// The state shared between the threads working on a single run.
+struct RunState {
+    perfs: Mutex<Vec<Perf>>,
+    // The number of puzzles in the run and the day number of the first, if
+    // they are daily puzzles, for building each `SolveContext`.
+    total: usize,
+    first_day: Option<u32>,
+    names: Vec<String>,
+    results: Option<Mutex<Sender<PuzzleResult>>>,
+    // Whether the harness has any sinks, so that a run without them never
+    // waits on their lock. Sinks cannot be added while a run goes on.
+    has_sinks: bool,
+    abort: Option<Arc<AtomicBool>>,
+    // Whether to produce an incomplete record instead of an error when the
+    // run is aborted.
+    keep_partial: bool,
+    // For each strategy, the words it was already run on before resuming.
+    done: Vec<HashSet<Word>>,
+    // How often and where to write checkpoints, and the number of times a
+    // strategy finished (or skipped) a word since the run started.
+    #[cfg(feature = "serde")]
+    checkpoint: Option<(usize, PathBuf, Vec<BaselineOpt>)>,
+    #[cfg(feature = "serde")]
+    completed: AtomicUsize,
+    // How far along each strategy is and how quickly it is going, shared
+    // with a `RecordHandle`.
+    progress: Arc<Mutex<Vec<Throughput>>>,
+    #[cfg(feature = "fancy")]
+    bar: Option<ProgressBar>,
+    // The span covering the whole run, which the spans for each puzzle are
+    // nested under even on other threads.
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    // When the run started, for the record's `RunInfo`.
+    started: SystemTime,
+    // The words `until_significant()` tried and the seed they were sampled
+    // with, recorded in place of the harness word selection.
+    #[cfg(feature = "stats")]
+    sampled: Option<(Vec<Word>, u64)>,
+}
+
+impl RunState {
+    fn new(
+        harness: &Harness,
+        results: Option<Sender<PuzzleResult>>,
+        abort: Option<Arc<AtomicBool>>,
+    ) -> Self {
+        let perfs: Vec<Perf> = harness
+            .strategies
+            .iter()
+            .map(|strat| Perf::new(strat.0.as_ref(), strat.1.as_deref()))
+            .collect();
+        let names = perfs.iter().map(Perf::to_string).collect();
+
+        RunState {
+            perfs: Mutex::new(perfs),
+            total: 0,
+            first_day: None,
+            names,
+            results: results.map(Mutex::new),
+            has_sinks: !harness.sinks.lock().unwrap().is_empty(),
+            abort,
+            keep_partial: false,
+            done: Vec::new(),
+            #[cfg(feature = "serde")]
+            checkpoint: None,
+            #[cfg(feature = "serde")]
+            completed: AtomicUsize::new(0),
+            progress: Arc::new(Mutex::new(
+                harness
+                    .strategies
+                    .iter()
+                    .map(|_| Throughput::default())
+                    .collect(),
+            )),
+            #[cfg(feature = "fancy")]
+            bar: None,
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::none(),
+            started: SystemTime::now(),
+            #[cfg(feature = "stats")]
+            sampled: None,
+        }
+    }
+
+    /// Starts from the results in `record`, so that each strategy skips the
+    /// words it was already run on.
+    #[cfg(feature = "serde")]
+    fn resume_from(&mut self, record: &Record) -> Result<()> {
+        let found: Vec<String> = record.iter().map(Perf::to_string).collect();
+        if found != self.names {
+            return Err(HarnessError::MismatchedStrategies {
+                expected: self.names.join(", "),
+                found: found.join(", "),
+            }
+            .into());
+        }
+
+        self.done = record
+            .iter()
+            .map(|perf| perf.tries().iter().map(|(word, _)| *word).collect())
+            .collect();
+        *self.perfs.get_mut().unwrap() = record.perfs.clone();
+        for (progress, done) in self.progress.lock().unwrap().iter_mut().zip(&self.done) {
+            progress.done = done.len();
+        }
+
+        Ok(())
+    }
+
+    /// Writes the results so far to the checkpoint file, if there is one.
+    ///
+    /// The file is replaced atomically so that a crash while writing does
+    /// not lose the previous checkpoint.
+    #[cfg(feature = "serde")]
+    fn write_checkpoint(&self) -> Result<()> {
+        if let Some((_, path, baselines)) = &self.checkpoint {
+            let record = Record::new(self.perfs.lock().unwrap().clone(), baselines.clone());
+
+            let mut tmp = path.clone().into_os_string();
+            tmp.push(".tmp");
+            let file = File::create(&tmp).map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+            serde_json::to_writer(file, &record)
+                .map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+            std::fs::rename(&tmp, path).map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Expects each strategy to be run on `num_words` more words, starting
+    /// now.
+    fn expect(&self, num_words: usize) {
+        let now = Instant::now();
+        for progress in self.progress.lock().unwrap().iter_mut() {
+            progress.total += num_words;
+            progress.last = Some(now);
+        }
+    }
+
+    /// Stops expecting the `strategy`th strategy to finish any more words,
+    /// since it was disqualified.
+    fn stop(&self, strategy: usize) {
+        self.progress.lock().unwrap()[strategy].stopped = true;
+    }
+
+    /// Counts a strategy finishing a word, or a word being skipped when
+    /// `strategy` is `None`, and updates the progress bar.
+    ///
+    /// Returns how far along each strategy is if `snapshot` is true.
+    fn tick(&self, strategy: Option<usize>, snapshot: bool) -> Option<Vec<StrategyProgress>> {
+        let progress = {
+            let mut progress = self.progress.lock().unwrap();
+            if let Some(i) = strategy {
+                progress[i].finish(Instant::now());
+            }
+
+            #[cfg(feature = "fancy")]
+            let snapshot = snapshot || self.bar.is_some();
+            if snapshot {
+                Some(
+                    progress
+                        .iter()
+                        .map(Throughput::snapshot)
+                        .collect::<Vec<_>>(),
+                )
+            } else {
+                None
+            }
+        };
+
+        #[cfg(feature = "fancy")]
+        if let Some(bar) = &self.bar {
+            bar.inc(1);
+            let message = self
+                .names
+                .iter()
+                .zip(progress.iter().flatten())
+                .map(|(name, progress)| {
+                    let mut line = format!("{}: {}/{}", name, progress.done, progress.total);
+                    if let Some(per_second) = progress.per_second {
+                        line += &format!(", {:.1}/s", per_second);
+                    }
+                    if let Some(remaining) = progress.remaining {
+                        line += &format!(", {} left", HumanDuration(remaining));
+                    }
+                    line
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            bar.set_message(message);
+        }
+
+        progress
+    }
+
+    fn aborted(&self) -> bool {
+        matches!(&self.abort, Some(abort) if abort.load(Ordering::Relaxed))
+    }
+}
+
+/// Tracks how far along a strategy is, and how quickly it is going.
+#[derive(Debug, Clone, Default)]
+struct Throughput {
+    done: usize,
+    total: usize,
+    // Whether the strategy was disqualified, so will not finish any more
+    // words.
+    stopped: bool,
+    // When the strategy last finished a word, or when the words it is
+    // working on were added.
+    last: Option<Instant>,
+    // The moving average of the time between the strategy finishing words,
+    // in seconds.
+    interval: Option<f64>,
+}
+
+impl Throughput {
+    /// How much each new interval counts towards the moving average.
+    const SMOOTHING: f64 = 0.1;
+
+    fn finish(&mut self, now: Instant) {
+        self.done += 1;
+        if let Some(last) = self.last {
+            let interval = now.duration_since(last).as_secs_f64();
+            self.interval = Some(match self.interval {
+                Some(average) => average + Self::SMOOTHING * (interval - average),
+                None => interval,
+            });
+        }
+        self.last = Some(now);
+    }
+
+    fn snapshot(&self) -> StrategyProgress {
+        let remaining = if self.stopped {
+            0
+        } else {
+            self.total.saturating_sub(self.done)
+        };
+        StrategyProgress {
+            done: self.done,
+            total: self.done + remaining,
+            per_second: self
+                .interval
+                .filter(|&interval| interval > 0.)
+                .map(|interval| 1. / interval),
+            remaining: self
+                .interval
+                .map(|interval| Duration::from_secs_f64(interval * remaining as f64)),
+        }
+    }
+}
+
+/// The result of one strategy attempting one puzzle, produced by
+/// [`Harness::run_streaming()`] while the harness runs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PuzzleResult {
+    /// The index of the strategy in the order it was added to the harness,
+    /// which is also its index in the final [`Record`].
+    pub strategy_index: usize,
+
+    /// The full name of the strategy, including its version, as displayed by
+    /// [`Perf`].
+    pub strategy_name: String,
+
+    /// The answer to the puzzle.
+    pub word: Word,
+
+    /// The attempts the strategy made to solve the puzzle.
+    pub attempts: Attempts,
+}
+
+impl PuzzleResult {
+    /// Produces a single line of JSON describing the result, for writing
+    /// results as [JSON Lines](https://jsonlines.org) while a run is going.
+    ///
+    /// The object has the fields `strategy_index`, `strategy`, `word`,
+    /// `hardmode`, `guesses` (the words guessed, in order), `num_guesses` and
+    /// `solved`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        let strategy = StrategyId {
+            index: self.strategy_index,
+            name: &self.strategy_name,
+        };
+        let line = crate::sink::result_json(strategy, self.word, &self.attempts);
+
+        serde_json::to_string(&line).map_err(|e| HarnessError::RecordWrite(Box::new(e)).into())
+    }
+}
+
+/// How far along a strategy is in a run, and how quickly it is going.
+///
+/// This is reported to [`ResultSink::on_progress()`] and by
+/// [`RecordHandle::progress()`] while the harness runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrategyProgress {
+    /// The number of words the strategy has finished, including any it
+    /// finished before the run was resumed.
+    ///
+    /// Words that the strategy was disqualified on are not counted.
+    pub done: usize,
+
+    /// The number of words the strategy will have finished once the run is
+    /// over.
+    ///
+    /// This stops growing once the strategy is disqualified, and may grow as
+    /// the run goes on with [`Harness::until_significant()`].
+    pub total: usize,
+
+    /// The number of words the strategy is finishing each second, as a
+    /// moving average, or `None` before it finishes one.
+    pub per_second: Option<f64>,
+
+    /// The estimated time until the strategy finishes its words, based on
+    /// the moving average, or `None` before it finishes one.
+    pub remaining: Option<Duration>,
+}
+
+/// A token for stopping a run started with [`Harness::run_cancellable()`].
+///
+/// Clones of a token share the same state, so a clone can be handed to
+/// another thread and cancelled from there.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Creates a new token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the runs using this token to stop as soon as possible.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if [`cancel()`](Self::cancel()) has been called on this
+    /// token or any of its clones.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// A handle to a harness run started with [`Harness::run_streaming()`].
+#[derive(Debug)]
+pub struct RecordHandle {
+    handle: JoinHandle<Result<Record>>,
+    abort: Arc<AtomicBool>,
+    progress: Arc<Mutex<Vec<Throughput>>>,
+}
+
+impl RecordHandle {
+    /// Asks the run to stop as soon as possible.
+    ///
+    /// The strategies will finish the puzzles they are currently working on,
+    /// and then [`join()`](Self::join()) will return
+    /// [`HarnessError::Aborted`].
+    pub fn abort(&self) {
+        self.abort.store(true, Ordering::Relaxed);
+    }
+
+    /// Gets how far along each strategy is so far, in the order the
+    /// strategies were added to the harness.
+    pub fn progress(&self) -> Vec<StrategyProgress> {
+        self.progress
+            .lock()
+            .unwrap()
+            .iter()
+            .map(Throughput::snapshot)
+            .collect()
+    }
+
+    /// Waits for the run to finish and returns its [`Record`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if a strategy panicked during the run.
+    pub fn join(self) -> Result<Record> {
+        match self.handle.join() {
+            Ok(res) => res,
+            Err(e) => std::panic::resume_unwind(e),
+        }
+    }
+}
+
+/// A description of what a [`Harness`] would run, produced by
+/// [`Harness::plan()`].
+///
+/// The [`Display`](std::fmt::Display) implementation prints a short,
+/// human-readable summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct RunPlan {
+    /// The strategies that would run, in order.
+    pub strategies: Vec<PlannedStrategy>,
+
+    /// The words the strategies would be run on.
+    pub words: WordSelection,
+
+    /// The number of words each strategy would be run on in this shard, if
+    /// the selected words could be resolved.
+    pub num_words: Option<usize>,
+
+    /// The seed used to choose random words, if any.
+    pub seed: Option<u64>,
+
+    /// The index of the shard that would run and the number of shards.
+    pub shard: (usize, usize),
+
+    /// Whether the strategies would run in parallel.
+    pub parallel: bool,
+
+    /// Where each baseline for comparisons comes from, in the order they
+    /// were added.
+    pub baselines: Vec<PlannedBaseline>,
+}
+
+/// A strategy that would run, as described in a [`RunPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct PlannedStrategy {
+    /// The name of the strategy.
+    pub name: String,
+
+    /// The version of the strategy.
+    pub version: String,
+
+    /// The name the strategy's summary would be saved under, if any.
+    pub save_name: Option<String>,
+
+    /// Whether the strategy plays hardmode.
+    pub hardmode: bool,
+}
+
+/// Where a baseline in a [`RunPlan`] comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum PlannedBaseline {
+    /// The strategy at this index in the run.
+    Run(usize),
+
+    /// A summary saved under `name`, produced by `strategy`.
+    Saved {
+        /// The name the summary was saved under.
+        name: String,
+
+        /// The full name of the strategy that produced the summary.
+        strategy: String,
+    },
+}
+
+impl std::fmt::Display for RunPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Strategies:")?;
+        for (i, strategy) in self.strategies.iter().enumerate() {
+            write!(
+                f,
+                "    {}: {} v{}, {}",
+                i,
+                strategy.name,
+                strategy.version,
+                if strategy.hardmode {
+                    "hardmode"
+                } else {
+                    "easymode"
+                }
+            )?;
+            if let Some(save_name) = &strategy.save_name {
+                write!(f, ", saved as \"{}\"", save_name)?;
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "Words: ")?;
+        match &self.words {
+            WordSelection::All => write!(f, "every answer")?,
+            WordSelection::RandomSample { n, seed } => {
+                write!(f, "{} random answers", n)?;
+                if let Some(seed) = seed {
+                    write!(f, " (seed {})", seed)?;
+                }
+            }
+            WordSelection::Explicit(words) => write!(f, "{} listed words", words.len())?,
+            WordSelection::Range(range) => write!(f, "answers {} to {}", range.start, range.end)?,
+            WordSelection::FromFile(path) => write!(f, "words from {}", path.display())?,
+            WordSelection::Daily(dates) => {
+                write!(f, "daily answers from {} to {}", dates.start, dates.end)?
+            }
+            #[cfg(feature = "serde")]
+            WordSelection::Hardest { from_record, n } => {
+                write!(f, "the {} hardest words in {}", n, from_record.display())?
+            }
+        }
+        if self.shard.1 > 1 {
+            write!(f, ", shard {} of {}", self.shard.0 + 1, self.shard.1)?;
+        }
+        match self.num_words {
+            Some(n) => writeln!(f, ", {} words", n)?,
+            None => writeln!(f, ", unknown number of words")?,
+        }
+
+        if self.baselines.is_empty() {
+            writeln!(f, "Baseline: none")?;
+        }
+        for baseline in &self.baselines {
+            match baseline {
+                PlannedBaseline::Run(index) => writeln!(f, "Baseline: strategy {}", index)?,
+                PlannedBaseline::Saved { name, strategy } => {
+                    writeln!(f, "Baseline: {} saved as \"{}\"", strategy, name)?
+                }
+            }
+        }
+
+        write!(f, "Parallel: {}", if self.parallel { "yes" } else { "no" })
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub(crate) enum BaselineOpt {
+    Run(usize, Option<String>),
+    #[cfg(feature = "serde")]
+    Saved(Box<Summary>, String),
+}
+
+impl BaselineOpt {
+    pub(crate) fn get_summary(&self, perfs: &[Perf]) -> Option<Summary> {
+        match self {
+            Self::Run(n, _) => Some(perfs[*n].to_summary()),
+            #[cfg(feature = "serde")]
+            Self::Saved(s, _) => Some(s.deref().clone()),
+        }
+    }
+
+    /// Names the baseline in reports: by the strategy's name if it was run,
+    /// or by the name it was saved with if it was loaded.
+    fn label(&self, perfs: &[Perf]) -> String {
+        match self {
+            Self::Run(n, _) => perfs[*n].to_string(),
+            #[cfg(feature = "serde")]
+            Self::Saved(_, name) => name.clone(),
+        }
+    }
+
+    /// Returns true if both refer to the same strategy in the run or the same
+    /// saved baseline.
+    fn is(&self, other: &BaselineOpt) -> bool {
+        match (self, other) {
+            (Self::Run(a, _), Self::Run(b, _)) => a == b,
+            #[cfg(feature = "serde")]
+            (Self::Saved(_, a), Self::Saved(_, b)) => a == b,
+            #[cfg(feature = "serde")]
+            _ => false,
+        }
+    }
+}
+
+/// Adds `baseline` to `baselines`, unless it is already there.
+fn push_baseline(baselines: &mut Vec<BaselineOpt>, baseline: BaselineOpt) -> Result<()> {
+    if baselines.iter().any(|b| b.is(&baseline)) {
+        return Err(HarnessError::BaselineAlreadySet.into());
+    }
+    baselines.push(baseline);
+    Ok(())
+}
+
+/// Loads every summary saved in `dir` whose name matches `pattern`, in order
+/// of their names.
+#[cfg(feature = "serde")]
+fn saved_baselines(pattern: &str, dir: &Path) -> Result<Vec<(String, Summary)>> {
+    let entries = std::fs::read_dir(dir).map_err(|e| HarnessError::BaselineRead(Box::new(e)))?;
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| HarnessError::BaselineRead(Box::new(e)))?
+            .path();
+        let is_summary = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Format::from_extension)
+            .is_some();
+        // Records are saved alongside baselines as "[name].record.json".
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .filter(|name| !name.ends_with(".record"));
+        if let (true, Some(name)) = (is_summary, name) {
+            if matches_pattern(pattern, name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    names.dedup();
+
+    if names.is_empty() {
+        return Err(HarnessError::BaselineRead(Box::new(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("no baselines matching {} in {}", pattern, dir.display()),
+        )))
+        .into());
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let summary = Summary::from_saved(&name, dir)?;
+            Ok((name, summary))
+        })
+        .collect()
+}
+
+/// Matches `name` against a pattern where `*` is any run of characters and
+/// `?` is any one character.
+#[cfg(feature = "serde")]
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Where to resume after the last `*`, if a later character fails.
+    let (mut p, mut n) = (0, 0);
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// The results of [`Harness::check_determinism()`].
+///
+/// The [`Display`](std::fmt::Display) implementation says whether the
+/// strategy repeated its guesses and lists every word it did not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeterminismReport {
+    strategy: String,
+    trials: usize,
+    num_words: usize,
+    differences: Vec<Nondeterminism>,
+}
+
+impl DeterminismReport {
+    /// Gets the full name of the strategy that was checked.
+    pub fn strategy(&self) -> &str {
+        &self.strategy
+    }
+
+    /// Gets the number of times the strategy was run on the words.
+    pub fn trials(&self) -> usize {
+        self.trials
+    }
+
+    /// Gets the number of words the strategy was run on in each trial.
+    pub fn num_words(&self) -> usize {
+        self.num_words
+    }
+
+    /// Indicates if the strategy made the same guesses on every word in
+    /// every trial.
+    pub fn is_deterministic(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Gets every word the strategy played differently, in the order of the
+    /// words.
+    pub fn differences(&self) -> &[Nondeterminism] {
+        &self.differences
+    }
+
+    /// Returns [`HarnessError::Nondeterministic`] unless the strategy was
+    /// deterministic, so that a check can fail with `?`.
+    pub fn ensure_deterministic(&self) -> Result<()> {
+        if self.is_deterministic() {
+            Ok(())
+        } else {
+            Err(HarnessError::Nondeterministic(self.strategy.clone()).into())
+        }
+    }
+}
+
+impl std::fmt::Display for DeterminismReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_deterministic() {
+            return writeln!(
+                f,
+                "{} made the same guesses on all {} words in {} trials",
+                self.strategy, self.num_words, self.trials
+            );
+        }
+
+        writeln!(
+            f,
+            "{} played {} of {} words differently in {} trials:",
+            self.strategy,
+            self.differences.len(),
+            self.num_words,
+            self.trials
+        )?;
+        for difference in &self.differences {
+            writeln!(f, "  {}", difference)?;
+        }
+        Ok(())
+    }
+}
+
+/// A word that a strategy played differently in two trials of
+/// [`Harness::check_determinism()`].
+///
+/// Only the first trial that differed from the first one is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nondeterminism {
+    /// The word.
+    pub word: Word,
+
+    /// The position of the word in the list of words, which tells apart
+    /// words that were listed more than once.
+    pub index: usize,
+
+    /// The guesses made in the first trial.
+    pub first: Vec<Word>,
+
+    /// The trial with different guesses, counting from 0.
+    pub trial: usize,
+
+    /// The guesses made in that trial.
+    pub guesses: Vec<Word>,
+}
+
+impl std::fmt::Display for Nondeterminism {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |guesses: &[Word]| {
+            guesses
+                .iter()
+                .map(Word::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        write!(
+            f,
+            "{}: {} in trial 1, {} in trial {}",
+            self.word,
+            join(&self.first),
+            join(&self.guesses),
+            self.trial + 1
+        )
+    }
+}
+
+/// What [`Harness::update_baseline_if_better()`] did with the saved baseline
+/// at the end of a run.
+///
+/// The [`Display`](std::fmt::Display) implementation describes it in a
+/// sentence.
+#[cfg(all(feature = "serde", feature = "stats"))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "serde_crate")]
+pub enum BaselineUpdate {
+    /// There was no baseline called `name`, so the best strategy was saved
+    /// as it.
+    Created {
+        /// The name of the baseline.
+        name: String,
+        /// The strategy that became the baseline.
+        strategy: String,
+    },
+
+    /// The best strategy was significantly better than the baseline called
+    /// `name` and replaced it.
+    Replaced {
+        /// The name of the baseline.
+        name: String,
+        /// The strategy that became the baseline.
+        strategy: String,
+    },
+
+    /// No strategy was significantly better than the baseline called `name`,
+    /// so it was kept.
+    Kept {
+        /// The name of the baseline.
+        name: String,
+    },
+}
+
+#[cfg(all(feature = "serde", feature = "stats"))]
+impl std::fmt::Display for BaselineUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BaselineUpdate::Created { name, strategy } => {
+                write!(f, "Saved {} as the new baseline {}", strategy, name)
+            }
+            BaselineUpdate::Replaced { name, strategy } => {
+                write!(f, "{} beat the baseline {} and replaced it", strategy, name)
+            }
+            BaselineUpdate::Kept { name } => {
+                write!(f, "Nothing beat the baseline {}, so it was kept", name)
+            }
+        }
+    }
+}
+
+/// Information about a harness run, kept in its [`Record`] so that saved
+/// records describe how they were produced.
+///
+/// Get this with [`Record::info()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct RunInfo {
+    started: SystemTime,
+    finished: SystemTime,
+    host: Option<String>,
+    crate_version: String,
+    words: WordSelection,
+    seed: Option<u64>,
+    shard: (usize, usize),
+    parallel: bool,
+    durations: Vec<(String, Duration)>,
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    #[serde(default)]
+    baseline_update: Option<BaselineUpdate>,
+}
+
+impl RunInfo {
+    /// Gets when the run started.
+    pub fn started(&self) -> SystemTime {
+        self.started
+    }
+
+    /// Gets when the run finished.
+    pub fn finished(&self) -> SystemTime {
+        self.finished
+    }
+
+    /// Gets how long the run took from start to finish.
+    pub fn duration(&self) -> Duration {
+        self.finished
+            .duration_since(self.started)
+            .unwrap_or_default()
+    }
+
+    /// Gets the name of the machine the run happened on, if it is known.
+    ///
+    /// This is read from the `HOSTNAME` or `COMPUTERNAME` environment
+    /// variables, or from `/etc/hostname`.
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    /// Gets the version of this crate that ran the harness.
+    pub fn crate_version(&self) -> &str {
+        &self.crate_version
+    }
+
+    /// Gets the words the harness was configured to run on.
+    pub fn words(&self) -> &WordSelection {
+        &self.words
+    }
+
+    /// Gets the seed used to choose random words, if any.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Gets the index of the shard that ran and the number of shards.
+    pub fn shard(&self) -> (usize, usize) {
+        self.shard
+    }
+
+    /// Returns true if the strategies ran in parallel.
+    pub fn parallel(&self) -> bool {
+        self.parallel
+    }
+
+    /// Gets the name of each strategy and the total time it spent solving
+    /// puzzles, in the order the strategies were run.
+    pub fn durations(&self) -> &[(String, Duration)] {
+        &self.durations
+    }
+
+    /// Gets what happened to the baseline set with
+    /// [`Harness::update_baseline_if_better()`], if there was one.
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    pub fn baseline_update(&self) -> Option<&BaselineUpdate> {
+        self.baseline_update.as_ref()
+    }
+
+    /// Combines the information from several runs of the same strategies,
+    /// such as the shards of one run.
+    ///
+    /// The combined run starts with the earliest start and finishes with the
+    /// latest finish, and the time each strategy spent solving is added up.
+    /// Everything else, including the shard, is taken from the first run.
+    /// Returns `None` if `infos` is empty.
+    pub fn merge(infos: &[RunInfo]) -> Option<RunInfo> {
+        let first = infos.first()?;
+
+        let mut durations = first.durations.clone();
+        for info in &infos[1..] {
+            for ((_, total), (_, duration)) in durations.iter_mut().zip(&info.durations) {
+                *total += *duration;
+            }
+        }
+
+        Some(RunInfo {
+            started: infos.iter().map(|i| i.started).min()?,
+            finished: infos.iter().map(|i| i.finished).max()?,
+            durations,
+            ..first.clone()
+        })
+    }
+}
+
+/// Gets the name of this machine, if it can be found.
+fn hostname() -> Option<String> {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+}
+
+/// A record produced by the test harness of a particular run.
+///
+/// This struct contains the performance records of each strategy and
+/// information about the baseline set. It can print the performances and
+/// dereferences to the performance records themselves.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct Record {
+    perfs: Vec<Perf>,
+    baselines: Vec<BaselineOpt>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    incomplete: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    info: Option<RunInfo>,
+}
+
+impl Deref for Record {
+    type Target = [Perf];
+
+    fn deref(&self) -> &Self::Target {
+        &self.perfs
+    }
+}
+
+impl Record {
+    /// Create a new [`Record`] from perfs and baseline configuration.
+    fn new(perfs: Vec<Perf>, baselines: Vec<BaselineOpt>) -> Self {
+        Self {
+            perfs,
+            baselines,
+            incomplete: false,
+            info: None,
+        }
+    }
+
+    /// Gets a name and summary for each baseline the strategies are compared
+    /// against, in the order they were added to the harness.
+    ///
+    /// A baseline that was one of the strategies in the run is named after
+    /// the strategy, and one loaded from disk by the name it was saved with.
+    pub fn baselines(&self) -> Vec<(String, Summary)> {
+        self.baseline_summaries()
+            .into_iter()
+            .map(|(baseline, summary)| (baseline.label(&self.perfs), summary))
+            .collect()
+    }
+
+    pub(crate) fn baseline_summaries(&self) -> Vec<(&BaselineOpt, Summary)> {
+        self.baselines
+            .iter()
+            .filter_map(|baseline| Some((baseline, baseline.get_summary(&self.perfs)?)))
+            .collect()
+    }
+
+    /// Gets information about the run that produced this record, such as
+    /// when and where it ran.
+    ///
+    /// This is `None` for records saved by older versions of this crate.
+    pub fn info(&self) -> Option<&RunInfo> {
+        self.info.as_ref()
+    }
+
+    /// Returns false if the run that produced this record was cancelled
+    /// before every strategy was run on every word.
+    ///
+    /// See [`Harness::run_cancellable()`].
+    pub fn is_complete(&self) -> bool {
+        !self.incomplete
+    }
+
+    /// Combines the records from several runs of the same strategies, such as
+    /// the shards of a run split with [`Harness::shard()`], into one.
+    ///
+    /// The performance records of each strategy are combined with
+    /// [`Perf::merge()`] and the baseline is taken from the first record.
+    /// If every record has [run information](Self::info()), it is combined
+    /// with [`RunInfo::merge()`].
+    /// Returns an error if `records` is empty or the records do not contain
+    /// the same strategies in the same order.
+    pub fn merge(records: &[Record]) -> Result<Record> {
+        let first = records.first().ok_or(HarnessError::NothingToMerge)?;
+        let names = |record: &Record| {
+            record
+                .perfs
+                .iter()
+                .map(Perf::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let expected = names(first);
+        if let Some(found) = records.iter().map(names).find(|found| *found != expected) {
+            return Err(HarnessError::MismatchedStrategies { expected, found }.into());
+        }
+
+        let perfs = (0..first.perfs.len())
+            .map(|i| {
+                let perfs: Vec<Perf> = records.iter().map(|r| r.perfs[i].clone()).collect();
+                Perf::merge(&perfs)
+            })
+            .collect::<Result<_>>()?;
+
+        let mut record = Record::new(perfs, first.baselines.clone());
+        record.incomplete = records.iter().any(|r| r.incomplete);
+        record.info = records
+            .iter()
+            .map(|r| r.info.clone())
+            .collect::<Option<Vec<_>>>()
+            .and_then(|infos| RunInfo::merge(&infos));
+        Ok(record)
+    }
+
+    /// Loads a record from a previously-saved file.
+    ///
+    /// The `dir` parameter is where the record was saved and `name` is the
+    /// name it was saved with.
+    ///
+    /// To get the `dir` the same way that the test harness does, use
+    /// [`get_save_dir()`].
+    #[cfg(feature = "serde")]
+    pub fn load(name: &str, dir: impl AsRef<Path>) -> Result<Record> {
+        Self::read(&dir.as_ref().join(format!("{}.record.json", name)))
+    }
+
+    /// Reads a record from the file at `path`.
+    #[cfg(feature = "serde")]
+    fn read(path: &Path) -> Result<Record> {
+        let file = File::options()
+            .read(true)
+            .open(path)
+            .map_err(|e| HarnessError::RecordRead(Box::new(e)))?;
+
+        let mut record: Record =
+            serde_json::from_reader(file).map_err(|e| HarnessError::RecordRead(Box::new(e)))?;
+        record.perfs.iter_mut().for_each(Perf::split_legacy_name);
+
+        Ok(record)
+    }
+
+    /// Saves the record with a particular name and in a particular directory.
+    ///
+    /// Unlike a [`Summary`], this keeps every attempt made by every strategy,
+    /// so the run can be re-analyzed later.
+    ///
+    /// When `force` is true, this will overwrite any "\[name\].record.json"
+    /// file in the passed directory.
+    ///
+    /// To get the `dir` the same way that the test harness does, use
+    /// [`get_save_dir()`].
+    #[cfg(feature = "serde")]
+    pub fn save(&self, name: &str, dir: impl AsRef<Path>, force: bool) -> Result<PathBuf> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+
+        let path = dir.join(format!("{}.record.json", name));
+
+        let mut file = File::options()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .create_new(!force)
+            .open(&path)
+            .map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+
+        serde_json::to_writer(&mut file, self)
+            .map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+
+        Ok(path)
+    }
+
+    /// Compares this record against `baseline`, an earlier run of the same
+    /// strategies, word by word.
+    ///
+    /// Strategies are matched by name and version, or by name alone when the
+    /// version changed. The [`RunDiff`] lists the words each strategy now
+    /// solves or misses, and those it needs more or fewer guesses for, so
+    /// that it is easy to see what a change to a strategy fixed or broke.
+    /// Words that were only tried in one of the runs are ignored.
+    pub fn diff(&self, baseline: &Record) -> RunDiff {
+        RunDiff::from_perfs(&baseline.perfs, &self.perfs)
+    }
+
+    /// Checks each strategy's performance against the baseline summaries from
+    /// the same strategy, failing if any has regressed by more than
+    /// `thresholds` allow.
+    ///
+    /// Summaries are matched by strategy name, ignoring the version, so a
+    /// strategy can be checked against several, like its last release and
+    /// its best ever. Strategies without a matching summary in `baselines`
+    /// are skipped.
+    /// This is meant for gating changes to a strategy in CI: save a summary
+    /// of a known-good version and check new versions against it.
+    pub fn check_regressions(
+        &self,
+        baselines: &[Summary],
+        thresholds: RegressionThresholds,
+    ) -> std::result::Result<(), RegressionReport> {
+        let regressions: Vec<Regression> = self
+            .perfs
+            .iter()
+            .map(Perf::to_summary)
+            .flat_map(|summary| {
+                baselines
+                    .iter()
+                    .filter(|b| b.strategy_name() == summary.strategy_name())
+                    .flat_map(|baseline| thresholds.check(&summary, baseline))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        if regressions.is_empty() {
+            Ok(())
+        } else {
+            Err(RegressionReport { regressions })
+        }
+    }
+
+    /// Opens an interactive terminal browser over the results of the run.
+    ///
+    /// The browser lets you page through strategies, filter to the words a
+    /// strategy missed, inspect the grades of every attempt and toggle a
+    /// comparison against the first baseline. It takes over the terminal
+    /// until you press `q`.
+    #[cfg(feature = "tui")]
+    pub fn browse(&self) -> Result<()> {
+        let baseline = self
+            .baseline_summaries()
+            .into_iter()
+            .next()
+            .map(|(baseline, summary)| {
+                let index = match baseline {
+                    BaselineOpt::Run(index, _) => Some(*index),
+                    _ => None,
+                };
+                (summary, index)
+            });
+
+        crate::browse::Browser::new(&self.perfs, baseline).run()
+    }
+
+    /// Prints a report detailing each strategy's performance.
+    ///
+    /// This will use the baseline configuration passed to the test harness,
+    /// with a section comparing each strategy against each baseline.
+    /// It is the same as [`print_report_with()`](Self::print_report_with())
+    /// with the default [`ReportOptions`].
+    pub fn print_report(&self) -> Result<()> {
+        self.print_report_with(ReportOptions::new())
+    }
+
+    /// Prints a report on the strategies selected by `options`, in as much
+    /// detail as its [`ReportLevel`] asks for.
+    ///
+    /// Strategies are still compared against the baselines when the
+    /// baselines themselves are not selected, unless the comparison cannot be
+    /// computed, such as for a strategy that solved no puzzles. This prints
+    /// to stdout; use [`print_report_to()`](Self::print_report_to()) to write
+    /// anywhere else.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{harness::{ReportLevel, ReportOptions}, strategy::stupid::Stupid, Harness};
+    /// let record = Harness::new()
+    ///     .verbose(false)
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .test_num(10)
+    ///     .run()?;
+    ///
+    /// record.print_report_with(ReportOptions::new().level(ReportLevel::Summary))?;
+    ///
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn print_report_with(&self, options: ReportOptions) -> Result<()> {
+        let colored = options.color.enabled(true);
+        self.write_report(&mut std::io::stdout(), &options, colored)
+    }
+
+    /// Prints the report selected by `options` to `writer` instead of
+    /// stdout, as [`print_report_with()`](Self::print_report_with()) does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{harness::ReportOptions, strategy::stupid::Stupid, Harness};
+    /// # let record = Harness::new()
+    /// #     .verbose(false)
+    /// #     .add_strategy(Box::new(Stupid), None)
+    /// #     .test_num(10)
+    /// #     .run()?;
+    /// let mut report = Vec::new();
+    /// record.print_report_to(&mut report, ReportOptions::new())?;
+    /// assert!(String::from_utf8(report).unwrap().contains("Stupid"));
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn print_report_to(&self, writer: &mut dyn Write, options: ReportOptions) -> Result<()> {
+        let colored = options.color.enabled(false);
+        self.write_report(writer, &options, colored)
+    }
+
+    /// Writes what [`print_report_with()`](Self::print_report_with())
+    /// prints to `out`, using colors if `colored` is true.
+    fn write_report(
+        &self,
+        out: &mut dyn Write,
+        options: &ReportOptions,
+        colored: bool,
+    ) -> Result<()> {
+        let baselines = self.baseline_summaries();
+        let selected: Vec<&Perf> = self
+            .perfs
+            .iter()
+            .enumerate()
+            .filter(|(i, perf)| options.includes(*i, perf))
+            .map(|(_, perf)| perf)
+            .collect();
+
+        if options.level == ReportLevel::Summary {
+            let width = selected
+                .iter()
+                .map(|perf| perf.to_string().chars().count())
+                .max()
+                .unwrap_or(0);
+            for perf in selected {
+                if let Some(disqualification) = perf.disqualification() {
+                    writeln!(
+                        out,
+                        "{:<width$}  disqualified: {}",
+                        perf.to_string(),
+                        disqualification,
+                        width = width
+                    )?;
+                    continue;
+                }
+                let summary = perf.to_summary();
+                write!(
+                    out,
+                    "{:<width$}  {:>6.2}% solved  {:.3} guesses",
+                    perf.to_string(),
+                    summary.frac_solved() * 100.,
+                    summary.mean_guesses().unwrap_or(f32::NAN),
+                    width = width
+                )?;
+                for (baseline, baseline_summary) in &baselines {
+                    // With one baseline, there is no need to say which.
+                    let against = if baselines.len() > 1 {
+                        format!(" vs {}", baseline.label(&self.perfs))
+                    } else {
+                        String::new()
+                    };
+                    if *baseline_summary == summary {
+                        write!(out, "  (baseline)")?;
+                        continue;
+                    }
+                    // Without any solved puzzles, there is nothing to compare.
+                    let comparison = match summary.compare(baseline_summary) {
+                        Ok(comparison) => comparison,
+                        Err(_) => continue,
+                    };
+                    write!(
+                        out,
+                        "  ({:+.2}%, {:+.3}{})",
+                        comparison.frac_solved_diff() * 100.,
+                        comparison.mean_guesses_diff().unwrap_or(f32::NAN),
+                        against
+                    )?;
+                }
+                writeln!(out)?;
+            }
+            return Ok(());
+        }
+
+        let mut printed_baselines = vec![false; baselines.len()];
+        for perf in selected {
+            if let Some(disqualification) = perf.disqualification() {
+                writeln!(
+                    out,
+                    "{} was disqualified because it {}",
+                    perf, disqualification
+                )?;
+                continue;
+            }
+            let summary = perf.to_summary();
+            let print_options = || Summary::print_options().histogram(true).diagnostics(true);
+
+            // A baseline that cannot be compared against, because one of the
+            // strategies solved nothing, gets no section.
+            let others: Vec<&Summary> = baselines
+                .iter()
+                .map(|(_, b)| b)
+                .filter(|b| **b != summary && summary.compare(b).is_ok())
+                .collect();
+            let own = baselines.iter().position(|(_, b)| *b == summary);
+            match own {
+                Some(i) => {
+                    printed_baselines[i] = true;
+                    summary.write_report(out, &print_options().baseline(baselines[i].0), colored)?
+                }
+                None if others.is_empty() => {
+                    summary.write_report(out, &print_options(), colored)?
+                }
+                None => {}
+            }
+            // A section for each baseline, where only the first repeats the
+            // diagnostics, since they do not depend on the baseline.
+            for (i, baseline) in others.into_iter().enumerate() {
+                summary.write_report(
+                    out,
+                    &print_options()
+                        .diagnostics(own.is_none() && i == 0)
+                        .compare(baseline),
+                    colored,
+                )?
+            }
+
+            if summary.num_missed() > 0 {
+                writeln!(out, "Misses by word feature:")?;
+                write!(out, "{}", MissBreakdown::from_perf(perf))?;
+            }
+            if options.level == ReportLevel::Full {
+                write_words(out, perf)?;
+            }
+        }
+
+        // A baseline loaded from disk is not one of the strategies, so it is
+        // printed on its own.
+        for ((baseline, summary), printed) in baselines.iter().zip(printed_baselines) {
+            if !printed && !matches!(baseline, BaselineOpt::Run(..)) {
+                summary.write_report(
+                    out,
+                    &Summary::print_options()
+                        .histogram(true)
+                        .diagnostics(true)
+                        .baseline(baseline),
+                    colored,
+                )?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes every puzzle `perf` was run on and the guesses made to `out`.
+fn write_words(out: &mut dyn Write, perf: &Perf) -> Result<()> {
+    #[cfg(feature = "fancy")]
+    writeln!(out, "{}", perf.table())?;
+
+    #[cfg(not(feature = "fancy"))]
+    for (word, attempts) in perf.tries() {
+        writeln!(
+            out,
+            "{}: {}{}",
+            word,
+            attempts
+                .iter()
+                .map(Word::to_string)
+                .collect::<Vec<_>>()
+                .join(" "),
+            if attempts.solved(word) {
+                ""
+            } else {
+                " (missed)"
+            }
+        )?;
+    }
+
+    Ok(())
+}
+
+/// How much detail [`Record::print_report_with()`] prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReportLevel {
+    /// One line per strategy, with the fraction of puzzles solved and the
+    /// mean number of guesses.
+    Summary,
+
+    /// The full summary of each strategy, with histograms, diagnostics and a
+    /// [breakdown of its misses](crate::analysis::misses::MissBreakdown).
+    /// This is the default.
+    Standard,
+
+    /// Everything in [`Standard`](Self::Standard), followed by a table of
+    /// the guesses each strategy made on every puzzle.
+    Full,
+}
+
+impl Default for ReportLevel {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// Options for [`Record::print_report_with()`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ReportOptions {
+    level: ReportLevel,
+    indices: Option<Vec<usize>>,
+    matching: Option<String>,
+    color: ColorChoice,
+}
+
+impl ReportOptions {
+    /// Creates a new instance with default configuration.
+    ///
+    /// By default, every strategy is reported at the
+    /// [`Standard`](ReportLevel::Standard) level.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how much detail to print.
+    pub fn level(self, level: ReportLevel) -> Self {
+        Self { level, ..self }
+    }
+
+    /// Only reports the strategies at these positions in the run.
+    pub fn strategies(self, indices: impl IntoIterator<Item = usize>) -> Self {
+        Self {
+            indices: Some(indices.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Only reports the strategies whose full name contains `pattern`.
+    ///
+    /// This can be combined with [`strategies()`](Self::strategies()), in
+    /// which case a strategy must be selected by both.
+    pub fn matching(self, pattern: &str) -> Self {
+        Self {
+            matching: Some(pattern.to_string()),
+            ..self
+        }
+    }
+
+    /// Sets whether to color the report.
+    ///
+    /// Colors are only used with the `fancy` build feature.
+    pub fn color(self, color: ColorChoice) -> Self {
+        Self { color, ..self }
+    }
+
+    /// Returns true if the strategy at `index` that produced `perf` should
+    /// be reported.
+    fn includes(&self, index: usize, perf: &Perf) -> bool {
+        self.indices
+            .as_ref()
+            .map_or(true, |indices| indices.contains(&index))
+            && self
+                .matching
+                .as_ref()
+                .map_or(true, |pattern| perf.to_string().contains(pattern.as_str()))
+    }
+}
+
+/// Gets the save directory given an optional path to use.
+///
+/// If a path is passed to this function, it will return that. Otherwise, it
+/// will return the value of the `WORDLE_BASELINE_DIR` environment variable.
+/// If that environment variable is not set, it will return the `wordle_baseline/`
+/// directory in the current working directory (the directory of the running
+/// process.)
+#[cfg(feature = "serde")]
+pub fn get_save_dir<'a>(user: impl Into<Option<&'a Path>>) -> Result<PathBuf> {
+    let user: Option<&Path> = user.into();
+    let var = std::env::var_os("WORDLE_BASELINE_DIR");
+    let default = std::env::current_dir()
+        .map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?
+        .join("wordle_baseline");
+
+    let dir = match &user {
+        Some(p) => p,
+        None => match &var {
+            Some(s) => Path::new(s),
+            None => default.as_path(),
+        },
+    };
+
+    Ok(dir.to_path_buf())
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "serde")]
+    use std::path::Path;
+
+    use super::*;
+    use crate::mock::{mock_words, Mock, Peeker};
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_dir_passthru() -> Result<()> {
+        assert_eq!(
+            Path::new("/temp/testing"),
+            get_save_dir(Some(Path::new("/temp/testing")))?.as_path()
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn record_round_trip() -> Result<()> {
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .test_num(10)
+            .run()?;
+
+        let dir = std::env::temp_dir().join("wordle_rs_record_round_trip");
+        record.save("mock", &dir, true)?;
+        assert!(record.save("mock", &dir, false).is_err());
+
+        let loaded = Record::load("mock", &dir)?;
+        assert_eq!(&*loaded, &*record);
+        assert_eq!(loaded.info(), record.info());
+        assert_eq!(loaded[0].to_summary(), record[0].to_summary());
+
+        std::fs::remove_dir_all(dir).unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    fn plans() -> Result<()> {
+        let harness = Harness::new()
+            .add_strategy(Box::new(Mock::new(None)), "mock")
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(WordSelection::RandomSample {
+                n: 10,
+                seed: Some(3),
+            })
+            .shard(1, 2)?
+            .and_baseline()?;
+
+        let plan = harness.plan();
+        assert_eq!(plan.strategies.len(), 2);
+        assert_eq!(plan.strategies[0].name, "Mock None");
+        assert_eq!(plan.strategies[0].save_name.as_deref(), Some("mock"));
+        assert_eq!(plan.num_words, Some(5));
+        assert_eq!(plan.seed, Some(3));
+        assert_eq!(plan.baselines, [PlannedBaseline::Run(1)]);
+        assert_eq!(
+            plan.to_string(),
+            "Strategies:\n    \
+             0: Mock None v1.2.4, easymode, saved as \"mock\"\n    \
+             1: Mock None v1.2.4, easymode\n\
+             Words: 10 random answers (seed 3), shard 2 of 2, 5 words\n\
+             Baseline: strategy 1\n\
+             Parallel: no"
+        );
+
+        let plan = Harness::new()
+            .words(WordSelection::FromFile("/does/not/exist".into()))
+            .plan();
+        assert_eq!(plan.num_words, None);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn baseline_versions() -> Result<()> {
+        let dir = std::env::temp_dir().join("wordle_rs_baseline_versions");
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .test_num(10)
+            .run()?;
+        let mut summary = record[0].to_summary();
+        summary.save("current", &dir, true)?;
+        summary.strategy_version = "1.0.0".to_string();
+        summary.save("old", &dir, true)?;
+
+        let harness = |name, policy| {
+            Harness::new()
+                .verbose(false)
+                .add_strategy(Box::new(Mock::new(None)), None)
+                .test_num(10)
+                .load_baseline(name, dir.as_path())
+                .map(|h| h.on_version_mismatch(policy))
+        };
+
+        assert!(harness("current", VersionMismatch::Error)?.run().is_ok());
+        assert!(harness("old", VersionMismatch::Warn)?.run().is_ok());
+        assert!(harness("old", VersionMismatch::Ignore)?.run().is_ok());
+        assert!(matches!(
+            harness("old", VersionMismatch::Error)?.run(),
+            Err(WordleError::Harness {
+                kind: HarnessError::BaselineVersionMismatch { .. }
+            })
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn checkpoint_and_resume() -> Result<()> {
+        let path = std::env::temp_dir().join("wordle_rs_checkpoint.json");
+        let harness = || {
+            Harness::new()
+                .verbose(false)
+                .add_strategy(Box::new(Mock::new(None)), None)
+                .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+        };
+
+        harness()
+            .words(WordSelection::Range(0..5))
+            .checkpoint_every(2, &path)
+            .run()?;
+        let partial = Record::read(&path)?;
+        assert_eq!(partial[0].num_tried(), 5);
+
+        let resumed = harness()
+            .words(WordSelection::Range(0..10))
+            .resume(&path)?
+            .run()?;
+        let whole = harness().words(WordSelection::Range(0..10)).run()?;
+        assert_eq!(resumed[0].tries(), whole[0].tries());
+        assert_eq!(resumed[1].tries(), whole[1].tries());
+
+        let mismatched = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(WordSelection::Range(0..10))
+            .resume(&path)?
+            .run();
+        assert!(matches!(
+            mismatched,
+            Err(WordleError::Harness {
+                kind: HarnessError::MismatchedStrategies { .. }
+            })
+        ));
+
+        let unseeded = harness()
+            .test_num(10)
+            .checkpoint_every(2, &path)
+            .resume(&path)?
+            .run();
+        assert!(matches!(
+            unseeded,
+            Err(WordleError::Harness {
+                kind: HarnessError::UnseededCheckpoint
+            })
+        ));
+
+        std::fs::remove_file(path).unwrap();
+        assert!(harness().resume("does/not/exist.json").is_err());
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    #[test]
+    fn updates_best_baseline() -> Result<()> {
+        let dir = std::env::temp_dir().join("wordle_rs_updates_best_baseline");
+        let _ = std::fs::remove_dir_all(&dir);
+        let words = mock_words();
+
+        let run = |strategies: Vec<Mock>| {
+            let mut harness = Harness::new().verbose(false).words(words.clone());
+            for strategy in strategies {
+                harness = harness.add_strategy(Box::new(strategy), None);
+            }
+            let record = harness
+                .update_baseline_if_better("best", dir.as_path(), Metric::SolveRate)
+                .run()?;
+            Ok::<_, WordleError>(record.info().unwrap().baseline_update().cloned())
+        };
+        let first = run(vec![Mock::new(vec!["tithe"])])?;
+        assert_eq!(
+            first,
+            Some(BaselineUpdate::Created {
+                name: "best".to_string(),
+                strategy: Mock::new(vec!["tithe"]).to_string() + " v1.2.4",
+            })
+        );
+
+        let kept = Some(BaselineUpdate::Kept {
+            name: "best".to_string(),
+        });
+        assert_eq!(run(vec![Mock::new(vec!["tithe"])])?, kept);
+
+        let better = run(vec![Mock::new(vec!["tithe"]), Mock::new(None)])?;
+        assert_eq!(
+            better,
+            Some(BaselineUpdate::Replaced {
+                name: "best".to_string(),
+                strategy: Mock::new(None).to_string() + " v1.2.4",
+            })
+        );
+        assert_eq!(Summary::from_saved("best", &dir)?.num_solved(), 6);
+
+        assert_eq!(run(vec![Mock::new(vec!["tithe", "doubt"])])?, kept);
+        assert_eq!(Summary::from_saved("best", &dir)?.num_solved(), 6);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn several_baselines() -> Result<()> {
+        let dir = std::env::temp_dir().join("wordle_rs_several_baselines");
+        let words = mock_words();
+        for (name, guesses) in [("mock-best", None), ("mock-release", Some(vec!["tithe"]))] {
+            let record = Harness::new()
+                .verbose(false)
+                .add_strategy(Box::new(Mock::new(guesses)), None)
+                .words(words.clone())
+                .run()?;
+            record[0].to_summary().save(name, &dir, true)?;
+            record.save(name, &dir, true)?;
+        }
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_baseline(Box::new(Mock::new(None)), None)?
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .load_baselines("mock-*", dir.as_path())?
+            .load_baselines("mock-be?t", dir.as_path())?
+            .words(words)
+            .run()?;
+
+        let names: Vec<String> = record
+            .baselines()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names[1..], ["mock-best", "mock-release"]);
+
+        let report: serde_json::Value = serde_json::from_str(&record.to_json()?).unwrap();
+        assert_eq!(report["config"]["baselines"].as_array().unwrap().len(), 3);
+        let comparisons = report["strategies"][1]["comparisons"].as_array().unwrap();
+        assert_eq!(comparisons.len(), 3);
+        assert!(report["strategies"][0]["comparisons"][0].is_null());
+
+        let markdown = record.to_markdown()?;
+        assert!(markdown.contains("#### Compared with mock-best"));
+        assert_eq!(markdown.matches("| Strategy | Solved |").count(), 3);
+
+        assert!(Harness::new()
+            .load_baselines("other-*", dir.as_path())
+            .is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+
+        assert!(matches_pattern("mock-*", "mock-best"));
+        assert!(matches_pattern("*-b*t", "mock-best"));
+        assert!(matches_pattern("m?ck", "mock"));
+        assert!(matches_pattern("*", ""));
+        assert!(!matches_pattern("mock-*", "mock"));
+        assert!(!matches_pattern("*-release", "mock-best"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn builder_validation() {
+        let mut builder = Harness::builder();
+        builder
+            .verbose(false)
+            .add_baseline(Box::new(Mock::new(None)), None)
+            .test_num(10);
+        let compare = true;
+        if compare {
+            builder.add_strategy(Box::new(Mock::new(vec!["tithe"])), None);
+        }
+        let harness = builder.build().unwrap();
+        assert_eq!(harness.strategies.len(), 2);
+        assert!(matches!(harness.baselines[..], [BaselineOpt::Run(0, None)]));
+
+        assert!(matches!(
+            Harness::builder().build(),
+            Err(WordleError::Harness {
+                kind: HarnessError::NoStrategiesAdded
+            })
+        ));
+
+        let mut builder = Harness::builder();
+        builder
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .test_num(0);
+        assert!(matches!(
+            builder.build(),
+            Err(WordleError::Harness {
+                kind: HarnessError::NoWordsSelected
+            })
+        ));
+
+        let mut builder = Harness::builder();
+        builder
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .test_num(ANSWERS.len() + 1);
+        assert!(matches!(
+            builder.build(),
+            Err(WordleError::Harness {
+                kind: HarnessError::TooManyWords(_)
+            })
+        ));
+
+        let mut builder = Harness::builder();
+        builder
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .baseline_index(3);
+        assert!(matches!(
+            builder.build(),
+            Err(WordleError::Harness {
+                kind: HarnessError::BaselineOutOfRange(3)
+            })
+        ));
+
+        let mut builder = Harness::builder();
+        builder
+            .add_baseline(Box::new(Mock::new(None)), None)
+            .add_baseline(Box::new(Mock::new(None)), None);
+        assert_eq!(builder.build().unwrap().baselines.len(), 2);
+
+        let mut builder = Harness::builder();
+        builder
+            .add_baseline(Box::new(Mock::new(None)), None)
+            .baseline_index(0);
+        assert!(matches!(
+            builder.build(),
+            Err(WordleError::Harness {
+                kind: HarnessError::BaselineAlreadySet
+            })
+        ));
+
+        let mut builder = Harness::builder();
+        builder.and_baseline();
+        assert!(matches!(
+            builder.build(),
+            Err(WordleError::Harness {
+                kind: HarnessError::NoStrategiesAdded
+            })
+        ));
+        assert!(Harness::new().and_baseline().is_err());
+    }
+
+    #[test]
+    fn word_selection() -> Result<()> {
+        assert_eq!(WordSelection::All.resolve()?.len(), ANSWERS.len());
+        assert_eq!(
+            WordSelection::RandomSample { n: 20, seed: None }
+                .resolve()?
+                .len(),
+            20
+        );
+        assert_eq!(
+            WordSelection::RandomSample {
+                n: 20,
+                seed: Some(1)
+            }
+            .resolve()?,
+            WordSelection::RandomSample {
+                n: 20,
+                seed: Some(1)
+            }
+            .resolve()?
+        );
+        assert!(WordSelection::RandomSample {
+            n: ANSWERS.len() + 1,
+            seed: None
+        }
+        .resolve()
+        .is_err());
+        assert!(WordSelection::Range(ANSWERS.len() - 1..ANSWERS.len() + 1)
+            .resolve()
+            .is_err());
+
+        let words = vec![Word::from_str("tithe")?, Word::from_str("aahed")?];
+        assert_eq!(WordSelection::Explicit(words.clone()).resolve()?, words);
+
+        let path = std::env::temp_dir().join("wordle_rs_word_selection.txt");
+        std::fs::write(&path, "tithe\naahed\n").unwrap();
+        assert_eq!(WordSelection::FromFile(path.clone()).resolve()?, words);
+        std::fs::write(&path, "tithe\nzzzzz\n").unwrap();
+        assert!(WordSelection::FromFile(path.clone()).resolve().is_err());
+        std::fs::remove_file(path).unwrap();
+
+        assert!(WordSelection::Explicit(Vec::new()).is_empty());
+        assert!(WordSelection::Range(4..4).is_empty());
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(WordSelection::Explicit(words.clone()))
+            .run()?;
+        let tried: Vec<Word> = record[0].tries().iter().map(|(w, _)| *w).collect();
+        assert_eq!(tried, words);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn hardest_words() -> Result<()> {
+        let words: Vec<Word> = ["tithe", "parka", "aahed", "doubt"]
+            .iter()
+            .map(|w| Word::from_str(w))
+            .collect::<Result<_>>()?;
+        let dir = std::env::temp_dir().join("wordle_rs_hardest_words");
+        let path = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(WordSelection::Explicit(words.clone()))
+            .run()?
+            .save("mock", &dir, true)?;
+
+        let hardest = WordSelection::Hardest {
+            from_record: path.clone(),
+            n: 2,
+        };
+        assert_eq!(hardest.resolve()?, [words[2], words[1]]);
+        let everything = WordSelection::Hardest {
+            from_record: path,
+            n: 10,
+        };
+        assert_eq!(everything.resolve()?.len(), 4);
+        std::fs::remove_dir_all(dir).unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    fn determinism() -> Result<()> {
+        // Opens with "tithe" on every third puzzle it sees, so the same word
+        // gets different guesses from one trial to the next.
+        #[derive(Debug, Default)]
+        struct Flaky(std::sync::atomic::AtomicUsize);
+
+        impl std::fmt::Display for Flaky {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Flaky")
+            }
+        }
+
+        impl Strategy for Flaky {
+            fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+                let mut attempts = key.unlock();
+                let n = self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                let guess = if n % 3 == 0 { "tithe" } else { "nerds" };
+                puzzle
+                    .check(&Word::from_str(guess).unwrap(), &mut attempts)
+                    .unwrap();
+                attempts
+            }
+
+            fn version(&self) -> &'static str {
+                "1.0.0"
+            }
+
+            fn hardmode(&self) -> bool {
+                false
+            }
+        }
+
+        let words = WordSelection::Range(0..10);
+        let report = Harness::check_determinism(Box::new(Mock::new(None)), words.clone(), 3)?;
+        assert!(report.is_deterministic());
+        assert_eq!((report.trials(), report.num_words()), (3, 10));
+        report.ensure_deterministic()?;
+
+        let report = Harness::check_determinism(Box::new(Flaky::default()), words.clone(), 3)?;
+        assert_eq!(report.strategy(), "Flaky v1.0.0");
+        assert_eq!(report.differences().len(), 10);
+        let tithe = Word::from_str("tithe")?;
+        let nerds = Word::from_str("nerds")?;
+        let first = &report.differences()[0];
+        assert_eq!((first.index, first.trial), (0, 1));
+        assert_eq!(
+            (&first.first[..], &first.guesses[..]),
+            (&[tithe][..], &[nerds][..])
+        );
+        assert_eq!(report.differences()[1].trial, 2);
+        assert!(report
+            .to_string()
+            .starts_with("Flaky v1.0.0 played 10 of 10 words differently in 3 trials:"));
+        assert!(matches!(
+            report.ensure_deterministic(),
+            Err(WordleError::Harness {
+                kind: HarnessError::Nondeterministic(_)
+            })
+        ));
+
+        assert!(Harness::check_determinism(Box::new(Flaky::default()), words, 1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn strategies_are_prepared() -> Result<()> {
+        #[derive(Debug)]
+        struct Prepared {
+            inner: Mock,
+            num_puzzles: Option<usize>,
+        }
+
+        impl std::fmt::Display for Prepared {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Prepared")
+            }
+        }
+
+        impl Strategy for Prepared {
+            fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+                assert_eq!(self.num_puzzles, Some(12));
+                self.inner.solve(puzzle, key)
+            }
+
+            fn version(&self) -> &'static str {
+                "1.0.0"
+            }
+
+            fn hardmode(&self) -> bool {
+                false
+            }
+
+            fn prepare(&mut self, ctx: &RunContext) {
+                assert_eq!(ctx.answers().len(), ANSWERS.len());
+                self.num_puzzles = Some(ctx.num_puzzles());
+            }
+        }
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(
+                Box::new(Prepared {
+                    inner: Mock::new(None),
+                    num_puzzles: None,
+                }),
+                None,
+            )
+            .test_num(12)
+            .run()?;
+        assert_eq!(record[0].num_tried(), 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn solve_context() -> Result<()> {
+        type Seen = Vec<(usize, usize, Option<u32>)>;
+
+        #[derive(Debug, Default)]
+        struct Contexts(Arc<Mutex<Seen>>);
+
+        impl std::fmt::Display for Contexts {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Contexts")
+            }
+        }
+
+        impl Strategy for Contexts {
+            fn solve(&self, _puzzle: &mut Puzzle, _key: AttemptsKey) -> Attempts {
+                unreachable!()
+            }
+
+            fn solve_with_context(
+                &self,
+                puzzle: &mut Puzzle,
+                key: AttemptsKey,
+                ctx: &SolveContext,
+            ) -> Attempts {
+                assert_eq!(ctx.max_guesses(), 6);
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((ctx.index(), ctx.total(), ctx.day()));
+                Mock::new(None).solve(puzzle, key)
+            }
+
+            fn version(&self) -> &'static str {
+                "1.0.0"
+            }
+
+            fn hardmode(&self) -> bool {
+                false
+            }
+        }
+
+        let contexts = Contexts::default();
+        let seen = contexts.0.clone();
+        Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(contexts), None)
+            .test_daily_range(Date::of_puzzle(10)..Date::of_puzzle(15))?
+            .run()?;
+
+        let mut seen = seen.lock().unwrap().clone();
+        seen.sort_unstable();
+        let expected: Vec<_> = (0..5).map(|i| (i, 5, Some(10 + i as u32))).collect();
+        assert_eq!(seen, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn checks_are_recorded() -> Result<()> {
+        use crate::strategy::CheckOutcome;
+
+        /// Plays hardmode, but keeps guessing a fixed list of words.
+        #[derive(Debug)]
+        struct Stubborn;
+
+        impl std::fmt::Display for Stubborn {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "Stubborn")
+            }
+        }
+
+        impl Strategy for Stubborn {
+            fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+                let mut attempts = key.unlock();
+                for guess in [
+                    "tithe", "doubt", "those", "those", "those", "those", "those", "those",
+                ] {
+                    let guess = Word::from_str(guess).unwrap();
+                    let _ = puzzle.check(&guess, &mut attempts);
+                }
+                attempts
+            }
+
+            fn version(&self) -> &'static str {
+                "1.0.0"
+            }
+
+            fn hardmode(&self) -> bool {
+                true
+            }
+        }
+
+        let words = WordSelection::Explicit(vec![Word::from_str("those")?]);
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Stubborn), None)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(words.clone())
+            .record_checks(true)
+            .run()?;
+
+        let checks = &record[0].checks()[0].1;
+        let outcomes: Vec<bool> = checks.iter().map(|c| c.outcome.is_rejected()).collect();
+        assert_eq!(
+            outcomes,
+            [false, true, false, false, false, false, false, true]
+        );
+        assert_eq!(checks[1].outcome, CheckOutcome::InvalidHardmodeGuess);
+        assert_eq!(checks[7].outcome, CheckOutcome::OutOfGuesses);
+        assert_eq!(record[0].num_rejected_checks(), 2);
+
+        // Each strategy gets only its own checks.
+        assert_eq!(record[1].checks()[0].1.len(), record[1].tries()[0].1.len());
+        assert_eq!(record[1].num_rejected_checks(), 0);
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Stubborn), None)
+            .words(words)
+            .run()?;
+        assert!(record[0].checks().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cheaters_are_disqualified() -> Result<()> {
+        let word = Word::from_str("doubt")?;
+        let words = vec![word, Word::from_str("those")?, Word::from_str("nerds")?];
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Peeker), None)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(WordSelection::Explicit(words.clone()))
+            .parallel(false)
+            .run()?;
+
+        let disqualification = record[0].disqualification().unwrap();
+        assert_eq!(disqualification.word(), word);
+        assert_eq!(
+            disqualification.poisoning().kind(),
+            crate::strategy::PoisonKind::HypotheticalGrade
+        );
+        assert_eq!(disqualification.poisoning().guess(), 2);
+        assert!(record[0].tries().is_empty());
+
+        // The honest strategy still runs on every word.
+        assert!(record[1].disqualification().is_none());
+        assert_eq!(record[1].num_tried(), 3);
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Peeker), None)
+            .words(WordSelection::Explicit(words))
+            .debug_run(None)?;
+        assert_eq!(record[0].disqualification(), Some(disqualification));
+
+        Ok(())
+    }
+
+    #[test]
+    fn strategies_are_isolated() -> Result<()> {
+        let words = WordSelection::Explicit(vec![Word::from_str("doubt")?]);
+
+        // The honest strategy's guesses must not count toward the cheater's.
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Peeker), None)
+            .words(words)
+            .run()?;
+        let disqualification = record[1].disqualification().unwrap();
+        assert_eq!(disqualification.poisoning().guess(), 2);
+
+        // The order strategies run in does not change their results.
+        let forward = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["roate", "slimy"])), None)
+            .words(WordSelection::Range(0..20))
+            .record_checks(true)
+            .run()?;
+        let backward = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(vec!["roate", "slimy"])), None)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(WordSelection::Range(0..20))
+            .record_checks(true)
+            .run()?;
+        for (a, b) in [(0, 1), (1, 0)] {
+            assert_eq!(forward[a].tries(), backward[b].tries());
+            assert_eq!(forward[a].checks(), backward[b].checks());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn daily_range() -> Result<()> {
+        let start = Date::new(2022, 1, 1)?;
+        let end = Date::new(2022, 1, 8)?;
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .test_daily_range(start..end)?
+            .run()?;
+        let tried: Vec<Word> = record[0].tries().iter().map(|(w, _)| *w).collect();
+        assert_eq!(tried.len(), 7);
+        assert_eq!(tried[0], DailyAnswers::builtin().on(start).unwrap());
+
+        assert!(Harness::new()
+            .test_daily_range(start..Date::new(2030, 1, 1)?)
+            .is_err());
+
+        let mut builder = Harness::builder();
+        builder
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .test_daily_range(start..Date::new(2030, 1, 1)?);
+        assert!(builder.build().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn shards_cover_selection() -> Result<()> {
+        let words = WordSelection::RandomSample {
+            n: 20,
+            seed: Some(4),
+        };
+        let whole = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(words.clone())
+            .run()?;
+
+        let mut shards = Vec::new();
+        for index in 0..3 {
+            let mut harness = Harness::new()
+                .verbose(false)
+                .add_strategy(Box::new(Mock::new(None)), None)
+                .words(words.clone())
+                .shard(index, 3)?;
+            shards.push(harness.run()?);
+        }
+        assert_eq!(shards[0][0].num_tried(), 6);
+        assert_eq!(shards[2][0].num_tried(), 7);
+
+        let merged = Record::merge(&shards)?;
+        assert_eq!(merged[0].tries(), whole[0].tries());
+        let info = merged.info().unwrap();
+        assert_eq!(info.started(), shards[0].info().unwrap().started());
+        assert_eq!(info.finished(), shards[2].info().unwrap().finished());
+        assert_eq!(info.durations()[0].1, merged[0].elapsed());
+
+        assert!(Harness::new().shard(3, 3).is_err());
+        let mut builder = Harness::builder();
+        builder
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .shard(0, 0);
+        assert!(builder.build().is_err());
+
+        let other = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(vec!["tithe"])), None)
+            .words(words)
+            .run()?;
+        assert!(Record::merge(&[whole, other]).is_err());
+        assert!(Record::merge(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn records_run_info() -> Result<()> {
+        let words = WordSelection::RandomSample {
+            n: 10,
+            seed: Some(7),
+        };
+        let record = Harness::new()
+            .verbose(false)
+            .parallel(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe"])), None)
+            .words(words.clone())
+            .run()?;
+
+        let info = record.info().unwrap();
+        assert!(info.started() <= info.finished());
+        assert_eq!(info.crate_version(), env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.words(), &words);
+        assert_eq!(info.seed(), Some(7));
+        assert_eq!(info.shard(), (0, 1));
+        assert!(!info.parallel());
+
+        let durations: Vec<_> = record
+            .iter()
+            .map(|p| (p.to_string(), p.elapsed()))
+            .collect();
+        assert_eq!(info.durations(), &durations[..]);
+
+        let debug = Harness::new()
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .debug_run(Some(&[Word::from_str("tithe")?]))?;
+        assert_eq!(debug.info().unwrap().durations().len(), 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn until_significant() -> Result<()> {
+        let harness = || {
+            Harness::new()
+                .verbose(false)
+                .add_baseline(Box::new(Mock::new(None)), None)
+                .unwrap()
+                .add_strategy(Box::new(Mock::new(None)), None)
+                .words(WordSelection::RandomSample {
+                    n: 10,
+                    seed: Some(7),
+                })
+        };
+
+        for alpha in [0., 1., 1.5, f64::NAN] {
+            assert!(matches!(
+                harness().until_significant(alpha, 150),
+                Err(WordleError::Harness {
+                    kind: HarnessError::InvalidAlpha(_)
+                })
+            ));
+        }
+
+        // A strategy that plays exactly like the baseline is never
+        // significantly different, so every word is tried.
+        let record = harness().until_significant(0.05, 150)?;
+        assert_eq!(record[1].num_tried(), 150);
+
+        let info = record.info().unwrap();
+        assert_eq!(info.seed(), Some(7));
+        let words: Vec<Word> = record[0].tries().iter().map(|(word, _)| *word).collect();
+        match info.words() {
+            WordSelection::Explicit(tried) => {
+                let mut tried = tried.clone();
+                let mut words = words.clone();
+                tried.sort();
+                words.sort();
+                assert_eq!(tried, words);
+            }
+            other => panic!("expected the words tried, got {:?}", other),
+        }
+
+        let again = harness().until_significant(0.05, 150)?;
+        assert_eq!(again.info().unwrap().words(), info.words());
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_options() -> Result<()> {
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe"])), None)
+            .and_baseline()?
+            .add_strategy(Box::new(Mock::new(vec!["crane"])), None)
+            .words(mock_words())
+            .run()?;
+
+        let selected = |options: ReportOptions| -> Vec<usize> {
+            (0..record.len())
+                .filter(|&i| options.includes(i, &record[i]))
+                .collect()
+        };
+        assert_eq!(selected(ReportOptions::new()), [0, 1, 2]);
+        assert_eq!(selected(ReportOptions::new().strategies([1])), [1]);
+        assert_eq!(
+            selected(ReportOptions::new().matching(&record[0].to_string())),
+            [0]
+        );
+        assert!(selected(ReportOptions::new().strategies([1]).matching("nothing")).is_empty());
+
+        for level in [
+            ReportLevel::Summary,
+            ReportLevel::Standard,
+            ReportLevel::Full,
+        ] {
+            record.print_report_with(ReportOptions::new().level(level).strategies([0]))?;
+            record.print_report_with(ReportOptions::new().level(level).strategies([2]))?;
+        }
+
+        let mut report = Vec::new();
+        record.print_report_to(
+            &mut report,
+            ReportOptions::new()
+                .level(ReportLevel::Summary)
+                .color(ColorChoice::Always),
+        )?;
+        let report = String::from_utf8(report).unwrap();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with(&record[0].to_string()));
+        assert!(lines[0].ends_with("(+83.33%, +2.500)"));
+        assert!(lines[1].ends_with("(baseline)"));
+        #[cfg(feature = "stats")]
+        assert!(lines[2].ends_with("0.00% solved  NaN guesses"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cancelled_runs_are_incomplete() -> Result<()> {
+        let token = CancelToken::new();
+        let mut harness = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .test_num(10);
+
+        let record = harness.run_cancellable(&token)?;
+        assert!(record.is_complete());
+        assert_eq!(record[0].num_tried(), 10);
+
+        let clone = token.clone();
+        clone.cancel();
+        assert!(token.is_cancelled());
+
+        let record = harness.run_cancellable(&token)?;
+        assert!(!record.is_complete());
+        assert_eq!(record[0].num_tried(), 0);
+        assert!(!Record::merge(&[record])?.is_complete());
+
+        Ok(())
+    }
+
+    #[test]
+    fn streaming_matches_record() -> Result<()> {
+        let (handle, results) = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .test_num(20)
+            .run_streaming();
+
+        let results: Vec<PuzzleResult> = results.iter().collect();
+        let record = handle.join()?;
+
+        assert_eq!(results.len(), 40);
+        for result in results {
+            let perf = &record[result.strategy_index];
+            assert_eq!(perf.to_string(), result.strategy_name);
+            assert!(perf
+                .tries()
+                .iter()
+                .any(|(word, attempts)| *word == result.word && *attempts == result.attempts));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn interleaved_schedule_matches_by_word() -> Result<()> {
+        let run = |schedule| {
+            let (handle, results) = Harness::new()
+                .verbose(false)
+                .parallel(true)
+                .schedule(schedule)
+                .add_strategy(Box::new(Mock::new(None)), None)
+                .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+                .words(WordSelection::Range(0..30))
+                .run_streaming();
+            assert_eq!(results.iter().count(), 60);
+            let progress = handle.progress();
+            assert!(progress.iter().all(|p| p.done == 30 && p.total == 30));
+            assert!(progress.iter().all(|p| p.remaining == Some(Duration::ZERO)));
+            handle.join()
+        };
+
+        let by_word = run(Schedule::ByWord)?;
+        let interleaved = run(Schedule::Interleaved)?;
+        for (a, b) in by_word.iter().zip(interleaved.iter()) {
+            let mut a = a.tries().to_vec();
+            let mut b = b.tries().to_vec();
+            a.sort_by_key(|(word, _)| *word);
+            b.sort_by_key(|(word, _)| *word);
+            assert_eq!(a, b);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn streaming_results_as_json() -> Result<()> {
+        let (handle, results) = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .words(WordSelection::Explicit(vec![Word::from_str("point")?]))
+            .run_streaming();
+
+        let lines: Vec<String> = results
+            .iter()
+            .map(|result| result.to_json())
+            .collect::<Result<_>>()?;
+        handle.join()?;
+
+        assert_eq!(lines.len(), 1);
+        assert!(!lines[0].contains('\n'));
+        let line: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(line["strategy_index"], 0);
+        assert_eq!(line["word"], "point");
+        assert_eq!(
+            line["guesses"],
+            serde_json::json!(["nerds", "tithe", "doubt", "point"])
         );
+        assert_eq!(line["num_guesses"], 4);
+        assert_eq!(line["solved"], true);
 
         Ok(())
     }
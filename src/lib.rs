@@ -6,27 +6,83 @@
 #[cfg(feature = "serde")]
 extern crate serde_crate as serde;
 
+// Required to rename tracing, like serde
+#[cfg(feature = "tracing")]
+extern crate tracing_crate as tracing;
+
+// Lets `word!` expand to paths starting with `::wordle_rs` inside this crate
+#[cfg(feature = "macros")]
+extern crate self as wordle_rs;
+
 use std::error::Error as StdError;
 
 use thiserror::Error;
 
 pub mod strategy;
 #[doc(inline)]
-pub use strategy::{Attempts, AttemptsKey, Grade, Puzzle, Strategy, Word};
+pub use strategy::{
+    Attempts, AttemptsKey, Grade, Puzzle, RunContext, SolveContext, Strategy, StrategyMetadata,
+    Word,
+};
 
 pub mod words;
 
+#[cfg(feature = "macros")]
+pub use wordle_rs_macro::word;
+
 pub mod harness;
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use harness::VersionMismatch;
 #[doc(inline)]
-pub use harness::{Harness, Record};
+pub use harness::{
+    CancelToken, Harness, HarnessBuilder, Record, ReportLevel, ReportOptions, RunInfo, RunPlan,
+    Schedule, StrategyProgress, WordSelection,
+};
 
 pub mod perf;
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use perf::Format;
+#[cfg(feature = "stats")]
 #[doc(inline)]
-pub use perf::{Comparison, Perf, PrintOptions, Summary};
+pub use perf::TestKind;
+#[doc(inline)]
+pub use perf::{
+    ColorChoice, Comparison, Disqualification, Metric, Perf, PrintOptions, RegressionReport,
+    RegressionThresholds, Summary,
+};
+
+pub mod prelude;
+
+pub mod bench;
+
+pub mod experiments;
+
+pub mod analysis;
+
+pub mod testkit;
+
+pub mod snapshots;
+
+pub mod scaffold;
+
+pub mod sink;
+
+#[cfg(any(test, feature = "fuzz"))]
+pub mod fuzz;
+
+#[cfg(feature = "storage")]
+pub mod storage;
 
 #[cfg(feature = "stats")]
 mod stats;
 
+#[cfg(feature = "tui")]
+mod browse;
+
+mod report;
+
 #[cfg(test)]
 mod mock;
 
@@ -92,6 +148,17 @@ pub enum PuzzleError {
     #[error("the string \"{0}\" is not in the Wordle wordlist")]
     NotInWordlist(String),
 
+    /// A word given to a [`Wordlist`](words::Wordlist) is not made of as
+    /// many lowercase ASCII letters as the words in the wordlist.
+    #[error("the string \"{word}\" is not a word of {len} lowercase letters")]
+    InvalidWord {
+        /// The string that was given.
+        word: String,
+
+        /// The number of letters in every word of the wordlist.
+        len: usize,
+    },
+
     /// The puzzle has already evaluated six guesses.
     #[error("the puzzle has already evaluated six guesses")]
     OutOfGuesses,
@@ -100,6 +167,32 @@ pub enum PuzzleError {
     /// does not follow Wordle hardmode rules.
     #[error("that guess does not follow hardmode rules")]
     InvalidHardmodeGuess,
+
+    /// [`Puzzle::grade_hypothetical()`](strategy::Puzzle::grade_hypothetical())
+    /// was called without a cheating [`Attempts`](strategy::Attempts).
+    #[error("hypothetical guesses can only be graded outside of the harness")]
+    HypotheticalNotAllowed,
+
+    /// A grader disagreed with
+    /// [`reference_grade()`](strategy::reference_grade()) in
+    /// [`verify_grader()`](strategy::verify_grader()).
+    #[error("{grader} graded {guess} against {answer} incorrectly")]
+    GraderMismatch {
+        /// The name of the grader that was wrong.
+        grader: &'static str,
+
+        /// The guess that was graded.
+        guess: Word,
+
+        /// The answer it was graded against.
+        answer: Word,
+    },
+
+    /// A row of grades passed to
+    /// [`Grade::parse_row()`](strategy::Grade::parse_row()) had the wrong
+    /// length or a character that is not a grade.
+    #[error("\"{0}\" is not a row of grades")]
+    InvalidGrades(String),
 }
 
 /// The errors that the "harness" side of this crate can produce.
@@ -116,8 +209,8 @@ pub enum PuzzleError {
 /// ```
 #[derive(Debug, Error)]
 pub enum HarnessError {
-    /// The test harness already has a baseline.
-    #[error("test harness already has a baseline")]
+    /// The same baseline was set twice on the test harness.
+    #[error("test harness already has that baseline")]
     BaselineAlreadySet,
 
     /// The test harness could not find and deserialize a baseline file
@@ -130,6 +223,39 @@ pub enum HarnessError {
     #[error("could not write summary to disk")]
     SummaryWrite(#[source] Box<dyn StdError + Send>),
 
+    /// A saved [`Record`] could not be found or deserialized.
+    #[cfg(feature = "serde")]
+    #[error("could not read record file")]
+    RecordRead(#[source] Box<dyn StdError + Send>),
+
+    /// A [`Record`] could not be written to disk.
+    #[cfg(feature = "serde")]
+    #[error("could not write record to disk")]
+    RecordWrite(#[source] Box<dyn StdError + Send>),
+
+    /// A [`RunStore`](storage::RunStore) could not read or write its
+    /// database.
+    #[cfg(feature = "storage")]
+    #[error("could not access the run store")]
+    Storage(#[source] Box<dyn StdError + Send>),
+
+    /// A plot could not be drawn or written to disk.
+    #[cfg(feature = "plot")]
+    #[error("could not draw plot")]
+    Plot(#[source] Box<dyn StdError + Send>),
+
+    /// The test harness needs a baseline to compare against, but none was set.
+    #[error("no baseline has been set")]
+    NoBaseline,
+
+    /// The threshold p-value given to
+    /// [`Harness::until_significant()`](harness::Harness::until_significant()),
+    /// [`PrintOptions::alpha()`](perf::PrintOptions::alpha()) or
+    /// [`Comparison::compare_with()`](perf::Comparison::compare_with()) is
+    /// not in (0, 1).
+    #[error("threshold p-value {0} is not between 0 and 1")]
+    InvalidAlpha(f64),
+
     /// The test harness cannot run without adding at least one strategy.
     #[error("no strategies have been added to the harness")]
     NoStrategiesAdded,
@@ -138,8 +264,173 @@ pub enum HarnessError {
     #[error("test harness configured to run on 0 words")]
     NoWordsSelected,
 
+    /// The test harness was asked to run on more words than there are
+    /// possible answers.
+    #[error("test harness configured to run on {0} words, more than there are answers")]
+    TooManyWords(usize),
+
+    /// The test harness could not read the file listing the words to run on.
+    #[error("could not read word list file")]
+    WordsRead(#[source] Box<dyn StdError + Send>),
+
+    /// A date could not be parsed or does not exist.
+    #[error("invalid date \"{0}\"")]
+    InvalidDate(String),
+
+    /// The answer to the daily puzzle on a date is not known.
+    #[error("the answer to the daily puzzle on {0} is not known")]
+    NoDailyAnswer(words::daily::Date),
+
+    /// The shard index is not less than the number of shards.
+    #[error("cannot run shard {index} of {total}")]
+    InvalidShard {
+        /// The index of the shard.
+        index: usize,
+
+        /// The number of shards.
+        total: usize,
+    },
+
+    /// The words cannot be split into the number of folds asked for with
+    /// [`Harness::kfold()`](harness::Harness::kfold()).
+    #[error("cannot split {words} words into {folds} folds")]
+    InvalidFolds {
+        /// The number of folds.
+        folds: usize,
+
+        /// The number of words selected.
+        words: usize,
+    },
+
+    /// [`Harness::check_determinism()`](harness::Harness::check_determinism())
+    /// needs at least two trials to compare.
+    #[error("cannot check determinism with {0} trial(s), at least 2 are needed")]
+    InvalidTrials(usize),
+
+    /// The endpoint of a [`RemoteStrategy`](strategy::remote::RemoteStrategy)
+    /// is not a valid `http://` URL.
+    #[cfg(feature = "serde")]
+    #[error("invalid endpoint \"{0}\"")]
+    InvalidEndpoint(String),
+
+    /// A [`DylibWrapper`](strategy::plugin::DylibWrapper) could not load its
+    /// plugin.
+    #[cfg(feature = "plugin")]
+    #[error("could not load plugin")]
+    PluginLoad(#[source] Box<dyn StdError + Send>),
+
+    /// A plugin was written for a different version of the plugin ABI.
+    #[cfg(feature = "plugin")]
+    #[error(
+        "plugin uses ABI version {0}, but version {} is supported",
+        strategy::plugin::ABI_VERSION
+    )]
+    PluginAbiMismatch(u32),
+
+    /// A saved baseline was produced by a different version of a strategy
+    /// being tested, and the harness was told to treat that as an error.
+    #[cfg(feature = "serde")]
+    #[error("the baseline was produced by {strategy} v{baseline}, but v{current} is being tested")]
+    BaselineVersionMismatch {
+        /// The name of the strategy.
+        strategy: String,
+
+        /// The version that produced the baseline.
+        baseline: String,
+
+        /// The version being tested.
+        current: String,
+    },
+
+    /// The baseline index does not refer to a strategy added to the harness.
+    #[error("the baseline index {0} does not refer to an added strategy")]
+    BaselineOutOfRange(usize),
+
     /// A strategy created an unauthorized instance of [`Attempts`] and used it
     /// to gain more information about its puzzle.
-    #[error("the strategy {0} cheated")]
-    StrategyCheated(String),
+    #[error("the strategy {strategy} cheated on the puzzle {word}: it {poisoning}")]
+    StrategyCheated {
+        /// The name of the strategy that cheated.
+        strategy: String,
+
+        /// The answer to the puzzle it cheated on.
+        word: Word,
+
+        /// How it cheated.
+        poisoning: strategy::Poisoning,
+    },
+
+    /// A strategy panicked while an
+    /// [`Assistant`](strategy::assist::Assistant) was asking it for a guess.
+    #[error("the strategy {0} panicked")]
+    StrategyPanicked(String),
+
+    /// A strategy made different guesses when an
+    /// [`Assistant`](strategy::assist::Assistant) replayed the same grades
+    /// to it, or when [`Harness::check_determinism()`](harness::Harness::check_determinism())
+    /// ran it on the same puzzles again.
+    #[error("the strategy {0} did not repeat its earlier guesses")]
+    Nondeterministic(String),
+
+    /// There were no performance records or summaries to merge.
+    #[error("nothing to merge")]
+    NothingToMerge,
+
+    /// Performance records or summaries from different strategies cannot be
+    /// merged.
+    #[error("cannot merge results from {expected} with results from {found}")]
+    MismatchedStrategies {
+        /// The full name of the first strategy.
+        expected: String,
+
+        /// The full name of the strategy that did not match.
+        found: String,
+    },
+
+    /// The run was aborted before it finished.
+    #[error("the run was aborted")]
+    Aborted,
+
+    /// A run that writes or resumes from a checkpoint is on a random sample
+    /// of words without a seed, so resuming would draw different words.
+    #[cfg(feature = "serde")]
+    #[error("cannot checkpoint or resume a run on random words without a seed")]
+    UnseededCheckpoint,
+
+    /// A name given to a [`Scaffold`](scaffold::Scaffold) is not a valid
+    /// Rust identifier.
+    #[error("\"{0}\" is not a valid crate or type name")]
+    InvalidName(String),
+
+    /// A [`Scaffold`](scaffold::Scaffold) would overwrite an existing
+    /// directory.
+    #[error("{} already exists", .0.display())]
+    ScaffoldExists(std::path::PathBuf),
+
+    /// A [`Scaffold`](scaffold::Scaffold) could not write its files.
+    #[error("could not write the generated crate")]
+    ScaffoldWrite(#[source] Box<dyn StdError + Send>),
+
+    /// A [`Snapshot`](snapshots::Snapshot) file could not be read, written
+    /// or parsed.
+    #[error("could not read or write snapshot file")]
+    SnapshotIo(#[source] Box<dyn StdError + Send>),
+
+    /// A strategy did not make the guesses recorded in its
+    /// [`Snapshot`](snapshots::Snapshot).
+    #[error(
+        "{strategy} no longer plays like the snapshot {}: {} word(s) changed",
+        path.display(),
+        changes.len()
+    )]
+    SnapshotMismatch {
+        /// The name of the strategy.
+        strategy: String,
+
+        /// The path of the snapshot file.
+        path: std::path::PathBuf,
+
+        /// Every word that was played differently.
+        changes: Vec<snapshots::SnapshotChange>,
+    },
 }
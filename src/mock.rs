@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::{Attempts, AttemptsKey, Puzzle, Strategy, Word};
+use crate::{Attempts, AttemptsKey, Puzzle, Strategy, StrategyMetadata, Word, WordSelection};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Mock {
@@ -43,6 +43,19 @@ impl Strategy for Mock {
     fn hardmode(&self) -> bool {
         false
     }
+
+    fn metadata(&self) -> StrategyMetadata {
+        StrategyMetadata {
+            author: Some("wordle_rs".to_string()),
+            description: Some("Guesses a fixed list of words".to_string()),
+            tags: vec!["mock".to_string()],
+            ..StrategyMetadata::new()
+        }
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 impl Display for Mock {
@@ -50,3 +63,40 @@ impl Display for Mock {
         write!(f, "Mock {:?}", self.guesses)
     }
 }
+
+/// The words a [`Mock`] without a list of guesses guesses, all of which it
+/// solves.
+pub(crate) fn mock_words() -> WordSelection {
+    let words = ["nerds", "tithe", "doubt", "point", "parka", "sword"];
+    WordSelection::Explicit(words.iter().map(|w| w.parse().unwrap()).collect())
+}
+
+/// Guesses "tithe" and then cheats by grading it again with
+/// [`Puzzle::grade_hypothetical()`], which poisons the puzzle on its second
+/// guess.
+#[derive(Debug, Clone)]
+pub(crate) struct Peeker;
+
+impl Strategy for Peeker {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        let mut attempts = key.unlock();
+        let tithe = Word::from_str("tithe").unwrap();
+        let _ = puzzle.check(&tithe, &mut attempts).unwrap();
+        let _ = puzzle.grade_hypothetical(&tithe, &Attempts::cheat(false));
+        attempts
+    }
+
+    fn version(&self) -> &'static str {
+        "1.0.0"
+    }
+
+    fn hardmode(&self) -> bool {
+        false
+    }
+}
+
+impl Display for Peeker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Peeker")
+    }
+}
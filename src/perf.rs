@@ -6,25 +6,27 @@ use std::{
     io::Write,
     ops::Deref,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 #[cfg(feature = "fancy")]
 use comfy_table::{Cell, Color, ColumnConstraint, Row, Table, Width};
 #[cfg(feature = "stats")]
 use fishers_exact::FishersExactPvalues;
+use itertools::Itertools;
 #[cfg(feature = "fancy")]
-use owo_colors::{AnsiColors, OwoColorize, Stream};
+use owo_colors::Style;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
     harness::BaselineOpt,
-    strategy::{Attempts, Strategy, Word},
+    strategy::{grade, Attempts, CheckEvent, Grade, Poisoning, Strategy, StrategyMetadata, Word},
     {HarnessError, Result, WordleError},
 };
 
 #[cfg(feature = "stats")]
-use crate::stats::{Tails, WelchsT};
+use crate::stats::{TTest, Tails};
 
 /// A record of one strategy's guesses after run by the
 /// [test harness](crate::Harness).
@@ -32,30 +34,135 @@ use crate::stats::{Tails, WelchsT};
 /// This struct can provide statistics about the attempts on its own, but it
 /// is recommended to produce [`Summary`] first to cache the computations.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct Perf {
     pub(crate) tries: Vec<(Word, Attempts)>,
     strategy_name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    strategy_version: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    save_name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) elapsed: Duration,
+    #[cfg_attr(feature = "serde", serde(default))]
+    metadata: StrategyMetadata,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) checks: Vec<(Word, Vec<CheckEvent>)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) disqualification: Option<Disqualification>,
 }
 
 impl Perf {
     /// Creates a new empty performance record.
-    pub(crate) fn new(strat: &dyn Strategy) -> Self {
+    pub(crate) fn new(strat: &dyn Strategy, save_name: Option<&str>) -> Self {
+        Perf {
+            tries: Vec::new(),
+            strategy_name: strat.to_string(),
+            strategy_version: strat.version().to_string(),
+            save_name: save_name.map(str::to_string),
+            elapsed: Duration::ZERO,
+            metadata: strat.metadata(),
+            checks: Vec::new(),
+            disqualification: None,
+        }
+    }
+
+    /// Creates a new empty performance record for a strategy with the
+    /// given name, version and time spent solving.
+    #[cfg(feature = "storage")]
+    pub(crate) fn from_parts(
+        strategy_name: String,
+        strategy_version: String,
+        elapsed: Duration,
+    ) -> Self {
         Perf {
             tries: Vec::new(),
-            strategy_name: format!("{} v{}", strat, strat.version()),
+            strategy_name,
+            strategy_version,
+            save_name: None,
+            elapsed,
+            metadata: StrategyMetadata::default(),
+            checks: Vec::new(),
+            disqualification: None,
         }
     }
 
     /// Gets the name of the strategy that produced this performance record.
+    ///
+    /// This does not include the version; use the [`Display`] implementation
+    /// for the full name, like "Basic v0.1.0".
     pub fn strategy_name(&self) -> &str {
         &self.strategy_name
     }
 
+    /// Gets the version of the strategy that produced this performance record.
+    pub fn strategy_version(&self) -> &str {
+        &self.strategy_version
+    }
+
+    /// Gets the name the strategy was added to the harness with, which its
+    /// summary is saved under after a run.
+    pub fn save_name(&self) -> Option<&str> {
+        self.save_name.as_deref()
+    }
+
+    /// Splits a full name from a record saved before names and versions
+    /// were stored separately.
+    #[cfg(feature = "serde")]
+    pub(crate) fn split_legacy_name(&mut self) {
+        split_legacy_name(&mut self.strategy_name, &mut self.strategy_version);
+    }
+
+    /// Gets the metadata of the strategy that produced this performance record.
+    pub fn metadata(&self) -> &StrategyMetadata {
+        &self.metadata
+    }
+
+    /// Gets the total time the strategy spent solving puzzles.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
     /// Gets the record of attempts made by the strategy and the corresponding words.
     pub fn tries(&self) -> &[(Word, Attempts)] {
         &self.tries
     }
 
+    /// Gets every call the strategy made to [`Puzzle::check()`] on each
+    /// word, including the guesses that were rejected.
+    ///
+    /// This is empty unless the harness was told to
+    /// [record checks](crate::Harness::record_checks()).
+    ///
+    /// [`Puzzle::check()`]: crate::Puzzle::check()
+    pub fn checks(&self) -> &[(Word, Vec<CheckEvent>)] {
+        &self.checks
+    }
+
+    /// Gets the number of guesses the strategy passed to
+    /// [`Puzzle::check()`](crate::Puzzle::check()) that were rejected,
+    /// if checks were recorded.
+    pub fn num_rejected_checks(&self) -> usize {
+        self.checks
+            .iter()
+            .flat_map(|(_, checks)| checks)
+            .filter(|check| check.outcome.is_rejected())
+            .count()
+    }
+
+    /// Gets why the harness disqualified the strategy, if it did.
+    ///
+    /// A strategy is disqualified the first time it cheats on a puzzle. The
+    /// puzzle it cheated on is not included in [`tries()`](Self::tries()),
+    /// and it is not run on any puzzles after that.
+    pub fn disqualification(&self) -> Option<&Disqualification> {
+        self.disqualification.as_ref()
+    }
+
     /// Gets the number of puzzles attempted by the strategy.
     pub fn num_tried(&self) -> u32 {
         self.tries.len() as u32
@@ -118,17 +225,29 @@ impl Perf {
     /// Prints a table showing the guesses the strategy made on puzzles.
     #[cfg(feature = "fancy")]
     pub fn print(&self) {
+        println!("{}", self.table());
+    }
+
+    /// Builds the table printed by [`print()`](Self::print()).
+    #[cfg(feature = "fancy")]
+    pub(crate) fn table(&self) -> Table {
         let mut table = Table::new();
         if !table.is_tty() {
             table.set_table_width(80);
         } else {
             table.load_preset(comfy_table::presets::UTF8_FULL);
         }
-        let columns = (table.get_table_width().unwrap() / 9) as usize;
+        let columns = (table.get_table_width().unwrap() / 20) as usize;
         for chunk in self.tries.chunks(columns) {
             let mut row = Row::new();
             for (word, attempts) in chunk {
-                let mut cell = Cell::new(format!("{}\n-----\n{}", word, attempts));
+                let guesses = attempts
+                    .inner()
+                    .iter()
+                    .zip(attempts.share_grid().lines())
+                    .map(|(guess, grid)| format!("{} {}", guess, grid))
+                    .join("\n");
+                let mut cell = Cell::new(format!("{}\n-----\n{}", word, guesses));
                 if !attempts.solved(word) {
                     cell = cell.bg(Color::Red).fg(Color::Black);
                 }
@@ -137,10 +256,10 @@ impl Perf {
             table.add_row(row);
         }
         table.set_constraints(vec![
-            ColumnConstraint::LowerBoundary(Width::Fixed(5));
+            ColumnConstraint::LowerBoundary(Width::Fixed(16));
             columns
         ]);
-        println!("{}", table);
+        table
     }
 
     /// Converts this performance record to a pre-calculated summary.
@@ -155,14 +274,161 @@ impl Perf {
 
         assert_eq!(bins.iter().sum::<u32>(), self.num_solved());
 
+        let mut first_greens = [0; 6];
+        let mut first_yellows = [0; 6];
+        let mut guessed = [0; 6];
+        let mut candidates = [0; 6];
+        let mut reported = [0; 6];
+        let mut reported_candidates = [0; 6];
+
+        for (word, attempts) in &self.tries {
+            if let Some(grades) = attempts.grades().first() {
+                first_greens[grades.iter().filter(|&&g| g == Grade::Correct).count()] += 1;
+                first_yellows[grades.iter().filter(|&&g| g == Grade::Almost).count()] += 1;
+            }
+
+            let mut remaining: Vec<&Word> = Word::ANSWERS.iter().collect();
+            for (i, (guess, grades)) in attempts
+                .inner()
+                .iter()
+                .zip(attempts.grades())
+                .enumerate()
+                .take(6)
+            {
+                if guess == word {
+                    remaining = vec![word];
+                } else {
+                    remaining.retain(|answer| grade(guess, answer) == *grades);
+                }
+
+                guessed[i] += 1;
+                candidates[i] += remaining.len() as u64;
+            }
+
+            for (i, count) in attempts.reported_candidates().iter().enumerate().take(6) {
+                if let Some(count) = count {
+                    reported[i] += 1;
+                    reported_candidates[i] += *count as u64;
+                }
+            }
+        }
+
         Summary {
             strategy_name: self.strategy_name.clone(),
+            strategy_version: self.strategy_version.clone(),
+            save_name: self.save_name.clone(),
             num_tried: self.num_tried(),
             num_solved: self.num_solved(),
             cumulative_guesses: self.cumulative_guesses(),
             histogram: bins.into(),
+            first_greens,
+            first_yellows,
+            guessed,
+            candidates,
+            reported,
+            reported_candidates,
         }
     }
+
+    /// Combines the performance records from several runs of the same
+    /// strategy, such as the shards of a run split across machines, into one.
+    ///
+    /// The tries are concatenated in order and the time spent solving is
+    /// summed. Returns an error if `perfs` is empty or the records come from
+    /// strategies with different names or versions.
+    pub fn merge(perfs: &[Perf]) -> Result<Perf> {
+        let first = perfs.first().ok_or(HarnessError::NothingToMerge)?;
+        check_same_strategy(perfs)?;
+
+        Ok(Perf {
+            tries: perfs.iter().flat_map(|p| p.tries.iter().cloned()).collect(),
+            strategy_name: first.strategy_name.clone(),
+            strategy_version: first.strategy_version.clone(),
+            save_name: first.save_name.clone(),
+            elapsed: perfs.iter().map(|p| p.elapsed).sum(),
+            metadata: first.metadata.clone(),
+            checks: perfs
+                .iter()
+                .flat_map(|p| p.checks.iter().cloned())
+                .collect(),
+            disqualification: perfs.iter().find_map(|p| p.disqualification),
+        })
+    }
+}
+
+impl Display for Perf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_strategy(f, &self.strategy_name, &self.strategy_version)
+    }
+}
+
+/// Why the harness stopped running a strategy.
+///
+/// See [`Perf::disqualification()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct Disqualification {
+    word: Word,
+    poisoning: Poisoning,
+}
+
+impl Disqualification {
+    pub(crate) fn new(word: Word, poisoning: Poisoning) -> Self {
+        Disqualification { word, poisoning }
+    }
+
+    /// Gets the answer to the puzzle the strategy cheated on.
+    pub fn word(&self) -> Word {
+        self.word
+    }
+
+    /// Gets how the strategy cheated.
+    pub fn poisoning(&self) -> Poisoning {
+        self.poisoning
+    }
+}
+
+impl Display for Disqualification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cheated on the puzzle {}: it {}",
+            self.word, self.poisoning
+        )
+    }
+}
+
+/// Returns an error if any of `records` comes from a different strategy or
+/// version than the first.
+fn check_same_strategy<T: Display>(records: &[T]) -> Result<()> {
+    let expected = records[0].to_string();
+    match records
+        .iter()
+        .map(T::to_string)
+        .find(|found| *found != expected)
+    {
+        Some(found) => Err(HarnessError::MismatchedStrategies { expected, found }.into()),
+        None => Ok(()),
+    }
+}
+
+/// Returns an error if `alpha` is not a threshold p-value in (0, 1).
+#[cfg(feature = "stats")]
+pub(crate) fn check_alpha(alpha: f64) -> Result<()> {
+    if alpha > 0. && alpha < 1. {
+        Ok(())
+    } else {
+        Err(HarnessError::InvalidAlpha(alpha).into())
+    }
+}
+
+/// Adds each element of `other` to the matching element of `sum`.
+fn add_counts<T: Copy + std::ops::AddAssign>(sum: &mut [T], other: &[T]) {
+    sum.iter_mut().zip(other).for_each(|(s, &o)| *s += o);
 }
 
 /// A summary of a strategy's performance generated by the
@@ -178,21 +444,59 @@ impl Perf {
     serde(crate = "serde_crate")
 )]
 pub struct Summary {
-    strategy_name: String,
+    pub(crate) strategy_name: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) strategy_version: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    save_name: Option<String>,
     num_tried: u32,
     num_solved: u32,
     cumulative_guesses: u32,
 
     /// A histogram of the number of guesses used in each solved puzzle.
     pub histogram: Histogram,
+
+    // Index `n` counts the puzzles whose first guess got `n` greens/yellows.
+    #[cfg_attr(feature = "serde", serde(default))]
+    first_greens: [u32; 6],
+    #[cfg_attr(feature = "serde", serde(default))]
+    first_yellows: [u32; 6],
+
+    // Index `k` holds the number of puzzles with at least `k + 1` guesses and
+    // the total number of possible answers left after guess `k + 1`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    guessed: [u32; 6],
+    #[cfg_attr(feature = "serde", serde(default))]
+    candidates: [u64; 6],
+
+    // The same, counting the answers strategies reported as still possible
+    // with `Attempts::report_candidates()`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    reported: [u32; 6],
+    #[cfg_attr(feature = "serde", serde(default))]
+    reported_candidates: [u64; 6],
 }
 
 impl Summary {
     /// Gets the name of the strategy that produced this performance record.
+    ///
+    /// This does not include the version; use the [`Display`] implementation
+    /// for the full name, like "Basic v0.1.0".
     pub fn strategy_name(&self) -> &str {
         &self.strategy_name
     }
 
+    /// Gets the version of the strategy that produced this performance record.
+    pub fn strategy_version(&self) -> &str {
+        &self.strategy_version
+    }
+
+    /// Gets the name the strategy was added to the harness with, which this
+    /// summary is saved under after a run.
+    pub fn save_name(&self) -> Option<&str> {
+        self.save_name.as_deref()
+    }
+
     /// Gets the number of puzzles attempted by the strategy.
     pub fn num_tried(&self) -> u32 {
         self.num_tried
@@ -249,6 +553,175 @@ impl Summary {
         (self.num_missed() as f32) / (self.num_tried as f32)
     }
 
+    /// Gets the distribution of greens (correct letters) in the first guess.
+    ///
+    /// Index `n` of the returned array holds the number of puzzles whose first
+    /// guess had exactly `n` letters in the correct position.
+    pub fn first_guess_greens(&self) -> &[u32; 6] {
+        &self.first_greens
+    }
+
+    /// Gets the distribution of yellows (misplaced letters) in the first guess.
+    ///
+    /// Index `n` of the returned array holds the number of puzzles whose first
+    /// guess had exactly `n` letters in the word but in the wrong position.
+    pub fn first_guess_yellows(&self) -> &[u32; 6] {
+        &self.first_yellows
+    }
+
+    /// Gets the average number of possible answers still consistent with the
+    /// grades seen after guess number `k` (starting from 1).
+    ///
+    /// Only puzzles where the strategy made at least `k` guesses are counted.
+    /// Returns `None` if there are no such puzzles.
+    pub fn mean_candidates_after(&self, k: usize) -> Option<f32> {
+        match k.checked_sub(1).and_then(|i| self.guessed.get(i)) {
+            Some(&n) if n > 0 => Some(self.candidates[k - 1] as f32 / n as f32),
+            _ => None,
+        }
+    }
+
+    /// Gets the average number of possible answers the strategy reported
+    /// after guess number `k` (starting from 1).
+    ///
+    /// Unlike [`mean_candidates_after()`](Self::mean_candidates_after), this
+    /// uses the counts strategies report with
+    /// [`Attempts::report_candidates()`], so it shows how quickly a strategy
+    /// believes it is narrowing down the answer. Only puzzles where the
+    /// strategy reported a count after guess `k` are included. Returns `None`
+    /// if there are no such puzzles.
+    pub fn mean_reported_candidates_after(&self, k: usize) -> Option<f32> {
+        match k.checked_sub(1).and_then(|i| self.reported.get(i)) {
+            Some(&n) if n > 0 => Some(self.reported_candidates[k - 1] as f32 / n as f32),
+            _ => None,
+        }
+    }
+
+    /// Gets the fraction of attempted puzzles solved in `k` or fewer guesses.
+    pub fn solve_rate_by_guess(&self, k: usize) -> f32 {
+        let solved = self.histogram.iter().take(k).sum::<u32>();
+        solved as f32 / self.num_tried as f32
+    }
+
+    /// Gets the cumulative distribution of the number of guesses used.
+    ///
+    /// Index `k` of the returned array holds the number of puzzles solved in
+    /// `k + 1` or fewer guesses, so the last element is
+    /// [`num_solved()`](Self::num_solved()).
+    pub fn cumulative_histogram(&self) -> [u32; 6] {
+        let mut cumulative = [0; 6];
+        let mut total = 0;
+        for (c, &n) in cumulative.iter_mut().zip(self.histogram.iter()) {
+            total += n;
+            *c = total;
+        }
+        cumulative
+    }
+
+    /// Gets the `p`th percentile of the number of guesses needed to solve a
+    /// puzzle, using the nearest-rank method.
+    ///
+    /// This is the fewest guesses `g` such that at least `p` percent of the
+    /// solved puzzles took `g` or fewer guesses. Like
+    /// [`mean_guesses()`](Self::mean_guesses()), it does not include puzzles
+    /// that the strategy was unable to solve, and it returns `None` if there
+    /// are none.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in [0, 100].
+    pub fn percentile_guesses(&self, p: f32) -> Option<u32> {
+        assert!((0. ..=100.).contains(&p), "percentile must be in [0, 100]");
+
+        if self.num_solved == 0 {
+            return None;
+        }
+
+        let rank = ((p / 100. * self.num_solved as f32).ceil() as u32).max(1);
+        self.cumulative_histogram()
+            .iter()
+            .position(|&c| c >= rank)
+            .map(|i| i as u32 + 1)
+    }
+
+    /// Gets the median number of guesses needed to solve a puzzle.
+    ///
+    /// This is the same as [`percentile_guesses(50.)`](Self::percentile_guesses()).
+    pub fn median_guesses(&self) -> Option<u32> {
+        self.percentile_guesses(50.)
+    }
+
+    /// Combines the summaries from several runs of the same strategy, such as
+    /// the shards of a run split across machines, into one.
+    ///
+    /// All counts are summed, so the result is the same as summarizing a
+    /// single run over every puzzle. Returns an error if `summaries` is empty
+    /// or the summaries come from strategies with different names or
+    /// versions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::{strategy::stupid::Stupid, Harness, Summary};
+    /// let mut shard = Harness::new()
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .test_num(5);
+    /// let first = shard.run()?[0].to_summary();
+    /// let second = shard.run()?[0].to_summary();
+    ///
+    /// let merged = Summary::merge(&[first, second])?;
+    /// assert_eq!(merged.num_tried(), 10);
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn merge(summaries: &[Summary]) -> Result<Summary> {
+        let first = summaries.first().ok_or(HarnessError::NothingToMerge)?;
+        check_same_strategy(summaries)?;
+
+        let mut merged = first.clone();
+        for summary in &summaries[1..] {
+            merged.num_tried += summary.num_tried;
+            merged.num_solved += summary.num_solved;
+            merged.cumulative_guesses += summary.cumulative_guesses;
+
+            let mut bins = merged.histogram.bins;
+            add_counts(&mut bins, &summary.histogram.bins);
+            merged.histogram = bins.into();
+
+            add_counts(&mut merged.first_greens, &summary.first_greens);
+            add_counts(&mut merged.first_yellows, &summary.first_yellows);
+            add_counts(&mut merged.guessed, &summary.guessed);
+            add_counts(&mut merged.candidates, &summary.candidates);
+            add_counts(&mut merged.reported, &summary.reported);
+            add_counts(
+                &mut merged.reported_candidates,
+                &summary.reported_candidates,
+            );
+        }
+
+        Ok(merged)
+    }
+
+    /// Returns the number of solved puzzles and the sample mean and variance of
+    /// the number of guesses used to solve them, if at least two were solved.
+    fn guesses_moments(&self) -> Option<(f64, f64, f64)> {
+        let n = self.num_solved as f64;
+        if n < 2. {
+            return None;
+        }
+
+        let mean = self.cumulative_guesses_solved() as f64 / n;
+        let var = self
+            .histogram
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| v as f64 * (i as f64 + 1. - mean).powi(2))
+            .sum::<f64>()
+            / (n - 1.);
+
+        Some((n, mean, var))
+    }
+
     /// Compares this summary against another provided in `baseline`.
     ///
     /// See [`Comparison`] to see what this function provides.
@@ -257,7 +730,8 @@ impl Summary {
     /// in the [crate-level documentation](`crate#build-features`)) then this
     /// function will perform hypothesis tests on the two summaries and return
     /// the results in `Comparison`. In this case, it will use a threshold
-    /// p-value of `0.05`.
+    /// p-value of `0.05` and the default [`TestKind`]. To configure these,
+    /// use [`compare_with()`](Self::compare_with()).
     pub fn compare<'a, 'b>(&'a self, baseline: &'b Summary) -> Result<Comparison<'a, 'b>> {
         if self == baseline {
             return Err(WordleError::SelfComparison);
@@ -271,171 +745,226 @@ impl Summary {
         )
     }
 
+    /// Compares this summary against another provided in `baseline` using
+    /// a specific threshold p-value and test for the number of guesses.
+    ///
+    /// This is the same as [`compare()`](Self::compare()) but does not
+    /// hard-code the hypothesis testing configuration.
+    ///
+    /// # Panics
+    ///
+    /// `alpha` must be in (0, 1).
+    #[cfg(feature = "stats")]
+    pub fn compare_with<'a, 'b>(
+        &'a self,
+        baseline: &'b Summary,
+        alpha: f64,
+        test: TestKind,
+    ) -> Result<Comparison<'a, 'b>> {
+        if self == baseline {
+            return Err(WordleError::SelfComparison);
+        }
+
+        Comparison::compare_with(self, baseline, alpha, test)
+    }
+
     /// Prints the [`Summary`] in a configurable way.
     ///
     /// To configure the print, use [`PrintOptions`]. You can create a new
-    /// [`PrintOptions`] with [`Summary::print_options()`].
+    /// [`PrintOptions`] with [`Summary::print_options()`]. This prints to
+    /// stdout; use [`print_to()`](Self::print_to()) to write anywhere else.
     pub fn print(&self, options: PrintOptions) -> Result<()> {
-        let mut stdout = std::io::stdout();
-        match options.compare {
+        options.check()?;
+        let colored = options.color.enabled(true);
+        self.write_report(&mut std::io::stdout(), &options, colored)
+    }
+
+    /// Prints the [`Summary`] to `writer` instead of stdout, e.g. to capture
+    /// the report in a string or a log file.
+    ///
+    /// See [`print()`](Self::print()) and the examples on [`PrintOptions`].
+    pub fn print_to(&self, writer: &mut dyn Write, options: PrintOptions) -> Result<()> {
+        options.check()?;
+        let colored = options.color.enabled(false);
+        self.write_report(writer, &options, colored)
+    }
+
+    /// Writes what [`print()`](Self::print()) prints to `stdout`, using
+    /// colors if `colored` is true.
+    pub(crate) fn write_report(
+        &self,
+        stdout: &mut dyn Write,
+        options: &PrintOptions,
+        colored: bool,
+    ) -> Result<()> {
+        #[cfg(not(feature = "fancy"))]
+        let _ = colored;
+        let width = options.width.unwrap_or(80);
+
+        match &options.compare {
             Some(baseline) => {
-                let comparison = self.compare(&baseline)?;
+                #[cfg(feature = "stats")]
+                let comparison =
+                    self.compare_with(baseline, options.alpha.unwrap_or(0.05), options.test)?;
+                #[cfg(not(feature = "stats"))]
+                let comparison = self.compare(baseline)?;
 
-                writeln!(stdout, "{:-^80}", self.strategy_name)?;
+                writeln!(stdout, "{:-^width$}", self, width = width)?;
                 writeln!(
                     stdout,
                     "Ran {} words against {} on {} words",
                     self.num_tried(),
-                    baseline.strategy_name(),
+                    baseline,
                     baseline.num_tried()
                 )?;
 
-                #[cfg(feature = "stats")]
-                if comparison.is_sig_solved() {
-                    #[cfg(feature = "fancy")]
+                if options.criterion {
+                    self.print_criterion(stdout, &comparison)?;
+                } else {
+                    #[cfg(feature = "stats")]
+                    if comparison.is_sig_solved() {
+                        #[cfg(feature = "fancy")]
+                        writeln!(
+                            stdout,
+                            "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly, {}",
+                            self.num_solved(),
+                            self.frac_solved() * 100.,
+                            paint(
+                                comparison.frac_solved_diff() * 100.,
+                                change_style(comparison.frac_solved_diff().is_sign_positive()),
+                                colored,
+                            ),
+                            self.num_missed(),
+                            paint("a sig. diff.", Style::new().bold(), colored)
+                        )?;
+
+                        #[cfg(not(feature = "fancy"))]
                     writeln!(
                         stdout,
-                        "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly, {}",
+                        "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly, a sig. diff.",
                         self.num_solved(),
                         self.frac_solved() * 100.,
-                        (comparison.frac_solved_diff() * 100.).if_supports_color(
-                            Stream::Stdout,
-                            |text| {
-                                if comparison.frac_solved_diff().is_sign_positive() {
-                                    text.color(AnsiColors::Green)
-                                } else {
-                                    text.color(AnsiColors::Red)
-                                }
-                            },
-                        ),
+                        comparison.frac_solved_diff() * 100.,
                         self.num_missed(),
-                        "a sig. diff.".if_supports_color(Stream::Stdout, |text| text.bold())
                     )?;
-
-                    #[cfg(not(feature = "fancy"))]
-                    writeln!(
+                    } else {
+                        writeln!(
                         stdout,
-                        "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly, a sig. diff.",
+                        "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly, not a sig. diff.",
                         self.num_solved(),
                         self.frac_solved() * 100.,
                         comparison.frac_solved_diff() * 100.,
-                        self.num_missed(),
+                        self.num_missed()
                     )?;
-                } else {
+                    }
+
+                    #[cfg(all(not(feature = "stats"), feature = "fancy"))]
                     writeln!(
                         stdout,
-                        "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly, not a sig. diff.",
+                        "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly",
                         self.num_solved(),
                         self.frac_solved() * 100.,
-                        comparison.frac_solved_diff() * 100.,
+                        paint(
+                            comparison.frac_solved_diff() * 100.,
+                            change_style(comparison.frac_solved_diff().is_sign_positive()),
+                            colored,
+                        ),
                         self.num_missed()
                     )?;
-                }
-
-                #[cfg(all(not(feature = "stats"), feature = "fancy"))]
-                writeln!(
-                    stdout,
-                    "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly",
-                    self.num_solved(),
-                    self.frac_solved() * 100.,
-                    (comparison.frac_solved_diff() * 100.).if_supports_color(
-                        Stream::Stdout,
-                        |text| {
-                            if comparison.frac_solved_diff().is_sign_positive() {
-                                text.color(AnsiColors::Green)
-                            } else {
-                                text.color(AnsiColors::Red)
-                            }
-                        },
-                    ),
-                    self.num_missed()
-                )?;
-
-                #[cfg(all(not(feature = "stats"), not(feature = "fancy")))]
-                writeln!(
-                    stdout,
-                    "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly",
-                    self.num_solved(),
-                    self.frac_solved() * 100.,
-                    (comparison.frac_solved_diff() * 100.),
-                    self.num_missed()
-                )?;
 
-                #[cfg(feature = "stats")]
-                if comparison.is_sig_guesses() {
-                    #[cfg(feature = "fancy")]
+                    #[cfg(all(not(feature = "stats"), not(feature = "fancy")))]
                     writeln!(
                         stdout,
-                        "Correct guesses took {:.2} ({:.2}) attempts on average, {}",
+                        "Guessed {} correctly, or {:.1}% ({:+.1}%), and {} incorrectly",
+                        self.num_solved(),
+                        self.frac_solved() * 100.,
+                        (comparison.frac_solved_diff() * 100.),
+                        self.num_missed()
+                    )?;
+
+                    #[cfg(feature = "stats")]
+                    if comparison.is_sig_guesses() {
+                        #[cfg(feature = "fancy")]
+                        writeln!(
+                            stdout,
+                            "Correct guesses took {:.2} ({:.2}) attempts on average, {}",
+                            self.mean_guesses().unwrap_or(f32::NAN),
+                            paint(
+                                comparison.mean_guesses_diff().unwrap_or(f32::NAN),
+                                comparison
+                                    .mean_guesses_diff()
+                                    .map_or(Style::new().black(), |mean| change_style(
+                                        mean.is_sign_negative()
+                                    )),
+                                colored
+                            ),
+                            paint("a sig. diff.", Style::new().bold(), colored)
+                        )?;
+
+                        #[cfg(not(feature = "fancy"))]
+                        writeln!(
+                            stdout,
+                            "Correct guesses took {:.2} ({:.2}) attempts on average, a sig. diff.",
+                            self.mean_guesses().unwrap_or(f32::NAN),
+                            comparison.mean_guesses_diff().unwrap_or(f32::NAN),
+                        )?;
+                    } else {
+                        writeln!(
+                        stdout,
+                        "Correct guesses took {:.2} ({:+.2}) attempts on average, not a sig. diff.",
                         self.mean_guesses().unwrap_or(f32::NAN),
-                        comparison
-                            .mean_guesses_diff()
-                            .unwrap_or(f32::NAN)
-                            .if_supports_color(Stream::Stdout, |text| {
-                                if let Some(mean) = comparison.mean_guesses_diff() {
-                                    if mean.is_sign_negative() {
-                                        text.color(AnsiColors::Green)
-                                    } else {
-                                        text.color(AnsiColors::Red)
-                                    }
-                                } else {
-                                    text.color(AnsiColors::Black)
-                                }
-                            }),
-                        "a sig. diff.".if_supports_color(Stream::Stdout, |text| text.bold())
+                        comparison.mean_guesses_diff().unwrap_or(f32::NAN),
                     )?;
+                    }
 
-                    #[cfg(not(feature = "fancy"))]
+                    #[cfg(all(not(feature = "stats"), feature = "fancy"))]
                     writeln!(
                         stdout,
-                        "Correct guesses took {:.2} ({:.2}) attempts on average, a sig. diff.",
+                        "Correct guesses took {:.2} ({:.2}) attempts on average",
                         self.mean_guesses().unwrap_or(f32::NAN),
-                        comparison.mean_guesses_diff().unwrap_or(f32::NAN),
+                        paint(
+                            comparison.mean_guesses_diff().unwrap_or(f32::NAN),
+                            comparison
+                                .mean_guesses_diff()
+                                .map_or(Style::new().black(), |mean| change_style(
+                                    mean.is_sign_negative()
+                                )),
+                            colored
+                        ),
                     )?;
-                } else {
+
+                    #[cfg(all(not(feature = "stats"), not(feature = "fancy")))]
                     writeln!(
                         stdout,
-                        "Correct guesses took {:.2} ({:+.2}) attempts on average, not a sig. diff.",
+                        "Correct guesses took {:.2} ({:.2}) attempts on average",
                         self.mean_guesses().unwrap_or(f32::NAN),
                         comparison.mean_guesses_diff().unwrap_or(f32::NAN),
                     )?;
-                }
 
-                #[cfg(all(not(feature = "stats"), feature = "fancy"))]
-                writeln!(
+                    writeln!(
                     stdout,
-                    "Correct guesses took {:.2} ({:.2}) attempts on average",
-                    self.mean_guesses().unwrap_or(f32::NAN),
+                    "Effect sizes: h = {:+.2} ({}, odds ratio {:.2}) for solve rate, d = {:+.2} ({}) for guesses",
+                    comparison.cohens_h(),
+                    effect_magnitude(comparison.cohens_h()),
+                    comparison.odds_ratio(),
+                    comparison.cohens_d().unwrap_or(f32::NAN),
                     comparison
-                        .mean_guesses_diff()
-                        .unwrap_or(f32::NAN)
-                        .if_supports_color(Stream::Stdout, |text| {
-                            if let Some(mean) = comparison.mean_guesses_diff() {
-                                if mean.is_sign_negative() {
-                                    text.color(AnsiColors::Green)
-                                } else {
-                                    text.color(AnsiColors::Red)
-                                }
-                            } else {
-                                text.color(AnsiColors::Black)
-                            }
-                        }),
-                )?;
-
-                #[cfg(all(not(feature = "stats"), not(feature = "fancy")))]
-                writeln!(
-                    stdout,
-                    "Correct guesses took {:.2} ({:.2}) attempts on average",
-                    self.mean_guesses().unwrap_or(f32::NAN),
-                    comparison.mean_guesses_diff().unwrap_or(f32::NAN),
+                        .cohens_d()
+                        .map_or("unknown", effect_magnitude),
                 )?;
+                }
             }
             None => {
-                if let Some(s) = options.baseline {
-                    writeln!(stdout, "Baseline{:-^72}", self.strategy_name)?;
+                if let Some(s) = &options.baseline {
+                    writeln!(
+                        stdout,
+                        "Baseline{:-^width$}",
+                        self,
+                        width = width.saturating_sub(8)
+                    )?;
                     writeln!(stdout, "{}", s)?;
                 } else {
-                    writeln!(stdout, "{:-^80}", self.strategy_name)?;
+                    writeln!(stdout, "{:-^width$}", self, width = width)?;
                 }
                 writeln!(stdout, "Ran {} words", self.num_tried(),)?;
 
@@ -455,8 +984,92 @@ impl Summary {
             }
         }
 
+        if options.diagnostics {
+            writeln!(
+                stdout,
+                "First guess greens (0-5): {}",
+                self.first_greens.iter().join(" ")
+            )?;
+            writeln!(
+                stdout,
+                "First guess yellows (0-5): {}",
+                self.first_yellows.iter().join(" ")
+            )?;
+            writeln!(
+                stdout,
+                "Answers left after each guess: {}",
+                (1..=6)
+                    .map_while(|k| self.mean_candidates_after(k))
+                    .map(|c| format!("{:.1}", c))
+                    .join(" -> ")
+            )?;
+            if self.mean_reported_candidates_after(1).is_some() {
+                writeln!(
+                    stdout,
+                    "Answers left after each guess (reported): {}",
+                    (1..=6)
+                        .map_while(|k| self.mean_reported_candidates_after(k))
+                        .map(|c| format!("{:.1}", c))
+                        .join(" -> ")
+                )?;
+            }
+            writeln!(
+                stdout,
+                "Solved by each guess: {}",
+                (1..=6)
+                    .map(|k| format!("{:.1}%", self.solve_rate_by_guess(k) * 100.))
+                    .join(" ")
+            )?;
+        }
+
         if options.histogram {
-            write!(stdout, "{}", self.histogram)?;
+            match &options.compare {
+                Some(baseline) => write!(
+                    stdout,
+                    "{:width$}",
+                    self.histogram.compare(&baseline.histogram),
+                    width = width
+                )?,
+                None => write!(stdout, "{:width$}", self.histogram, width = width)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prints a comparison in the style of criterion's benchmark output.
+    fn print_criterion(&self, stdout: &mut dyn Write, comparison: &Comparison) -> Result<()> {
+        let baseline = comparison.baseline;
+
+        writeln!(
+            stdout,
+            "{:<16}{:.1}%",
+            "solve rate:",
+            self.frac_solved() * 100.
+        )?;
+        write_change(
+            stdout,
+            comparison.frac_solved_diff() / baseline.frac_solved(),
+            comparison.frac_solved_diff() > 0.,
+            #[cfg(feature = "stats")]
+            (comparison.solved_p_value(), comparison.alpha),
+        )?;
+
+        writeln!(
+            stdout,
+            "{:<16}{:.2}",
+            "mean guesses:",
+            self.mean_guesses().unwrap_or(f32::NAN)
+        )?;
+        if let (Some(diff), Some(mean)) = (comparison.mean_guesses_diff(), baseline.mean_guesses())
+        {
+            write_change(
+                stdout,
+                diff / mean,
+                diff < 0.,
+                #[cfg(feature = "stats")]
+                (comparison.guesses_p_value(), comparison.alpha),
+            )?;
         }
 
         Ok(())
@@ -473,27 +1086,30 @@ impl Summary {
     /// the name it was saved with (NOT the name of the strategy that produced
     /// it.)
     ///
+    /// The format is detected from the extension of the file, trying each
+    /// enabled [`Format`] in turn, so summaries saved with
+    /// [`save_as()`](Self::save_as()) load the same way as those saved with
+    /// [`save()`](Self::save()).
+    ///
     /// To get the `dir` the same way that the test harness does, use
     /// [`get_save_dir()`](crate::harness:get_save_dir<'a>()).
     #[cfg(feature = "serde")]
     pub fn from_saved(name: &str, dir: impl AsRef<Path>) -> Result<Summary> {
-        let dir = dir.as_ref();
-        let mut path = dir.to_path_buf();
-        path.push(name);
-        path.set_extension("json");
+        let (path, format) = saved_path(name, dir.as_ref());
 
         let file = File::options()
             .read(true)
             .open(path)
             .map_err(|e| HarnessError::BaselineRead(Box::new(e)))?;
 
-        let summary =
-            serde_json::from_reader(file).map_err(|e| HarnessError::BaselineRead(Box::new(e)))?;
+        let mut summary: Summary = format.read(file).map_err(HarnessError::BaselineRead)?;
+        split_legacy_name(&mut summary.strategy_name, &mut summary.strategy_version);
 
         Ok(summary)
     }
 
-    /// Saves the summary with a particular name and in a particular directory.
+    /// Saves the summary as JSON with a particular name and in a particular
+    /// directory.
     ///
     /// When `force` is true, this will overwrite any "\[name\].json" file
     /// in the passed directory.
@@ -502,11 +1118,41 @@ impl Summary {
     /// [`get_save_dir()`](crate::harness:get_save_dir<'a>()).
     #[cfg(feature = "serde")]
     pub fn save(&self, name: &str, dir: impl AsRef<Path>, force: bool) -> Result<PathBuf> {
+        self.save_as(name, dir, force, Format::Json)
+    }
+
+    /// Saves the summary in a particular format, with a particular name and
+    /// in a particular directory.
+    ///
+    /// The file is named after `name` with the [extension](Format::extension())
+    /// of `format`. When `force` is true, this will overwrite an existing file
+    /// with that name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use wordle_rs::{harness::get_save_dir, perf::Format, strategy::stupid::Stupid, Harness, Summary};
+    /// let record = Harness::new()
+    ///     .add_strategy(Box::new(Stupid), None)
+    ///     .run()?;
+    ///
+    /// let dir = get_save_dir(None)?;
+    /// record[0].to_summary().save_as("stupid", &dir, true, Format::Json)?;
+    /// let summary = Summary::from_saved("stupid", &dir)?;
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn save_as(
+        &self,
+        name: &str,
+        dir: impl AsRef<Path>,
+        force: bool,
+        format: Format,
+    ) -> Result<PathBuf> {
         let dir = dir.as_ref();
         std::fs::create_dir_all(dir).map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?;
 
-        let mut path = dir.join(name);
-        path.set_extension("json");
+        let path = dir.join(name).with_extension(format.extension());
 
         let mut file = File::options()
             .write(true)
@@ -516,31 +1162,258 @@ impl Summary {
             .open(&path)
             .map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?;
 
-        serde_json::to_writer(&mut file, self)
-            .map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?;
+        format
+            .write(self, &mut file)
+            .map_err(HarnessError::SummaryWrite)?;
 
         Ok(path)
     }
-}
 
-/// Configurable options that control printing performance records.
-#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PrintOptions {
-    compare: Option<Summary>,
-    histogram: bool,
+    /// Replaces the summary saved at `path` with this one, writing to a
+    /// temporary file first so that a crash cannot leave it half-written.
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    pub(crate) fn replace_saved(&self, path: &Path, format: Format) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?;
+        }
+
+        let mut tmp = path.to_path_buf().into_os_string();
+        tmp.push(".tmp");
+        let mut file = File::create(&tmp).map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?;
+        format
+            .write(self, &mut file)
+            .map_err(HarnessError::SummaryWrite)?;
+        std::fs::rename(&tmp, path).map_err(|e| HarnessError::SummaryWrite(Box::new(e)))?;
+
+        Ok(())
+    }
+}
+
+/// Finds the file that the summary `name` was saved to in `dir` and its
+/// format, or where a JSON summary would go if there is none.
+#[cfg(feature = "serde")]
+pub(crate) fn saved_path(name: &str, dir: &Path) -> (PathBuf, Format) {
+    Format::ALL
+        .iter()
+        .map(|format| (dir.join(name).with_extension(format.extension()), *format))
+        .find(|(path, _)| path.exists())
+        .unwrap_or_else(|| (dir.join(name).with_extension("json"), Format::Json))
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_strategy(f, &self.strategy_name, &self.strategy_version)
+    }
+}
+
+/// The file formats summaries can be saved in.
+///
+/// JSON is always available. The binary formats are much smaller for large
+/// archives of runs; they and TOML need the `formats` build feature (see the
+/// feature description in the [crate-level documentation](`crate#build-features`)).
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Format {
+    /// [JSON](https://www.json.org), with the extension `json`.
+    Json,
+
+    /// [MessagePack](https://msgpack.org), with the extension `msgpack`.
+    #[cfg(feature = "formats")]
+    MessagePack,
+
+    /// [CBOR](https://cbor.io), with the extension `cbor`.
+    #[cfg(feature = "formats")]
+    Cbor,
+
+    /// [TOML](https://toml.io), with the extension `toml`.
+    #[cfg(feature = "formats")]
+    Toml,
+}
+
+#[cfg(feature = "serde")]
+type FormatError = Box<dyn std::error::Error + Send>;
+
+#[cfg(feature = "serde")]
+impl Format {
+    /// Every format enabled in this build.
+    pub const ALL: &'static [Format] = &[
+        Format::Json,
+        #[cfg(feature = "formats")]
+        Format::MessagePack,
+        #[cfg(feature = "formats")]
+        Format::Cbor,
+        #[cfg(feature = "formats")]
+        Format::Toml,
+    ];
+
+    /// Gets the file extension used for this format, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            #[cfg(feature = "formats")]
+            Format::MessagePack => "msgpack",
+            #[cfg(feature = "formats")]
+            Format::Cbor => "cbor",
+            #[cfg(feature = "formats")]
+            Format::Toml => "toml",
+        }
+    }
+
+    /// Gets the format that uses the file extension `extension`, if it is
+    /// enabled in this build.
+    pub fn from_extension(extension: &str) -> Option<Format> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|format| format.extension() == extension)
+    }
+
+    /// Serializes `value` in this format to `writer`.
+    fn write<T: Serialize>(
+        &self,
+        value: &T,
+        writer: impl Write,
+    ) -> std::result::Result<(), FormatError> {
+        match self {
+            Format::Json => serde_json::to_writer(writer, value).map_err(|e| Box::new(e) as _),
+            #[cfg(feature = "formats")]
+            Format::MessagePack => {
+                let mut writer = writer;
+                rmp_serde::encode::write_named(&mut writer, value).map_err(|e| Box::new(e) as _)
+            }
+            #[cfg(feature = "formats")]
+            Format::Cbor => ciborium::ser::into_writer(value, writer).map_err(|e| Box::new(e) as _),
+            #[cfg(feature = "formats")]
+            Format::Toml => {
+                // Going through `toml::Value` puts tables after plain values,
+                // as TOML requires.
+                let value = toml::Value::try_from(value).map_err(|e| Box::new(e) as FormatError)?;
+                let mut writer = writer;
+                writer
+                    .write_all(value.to_string().as_bytes())
+                    .map_err(|e| Box::new(e) as _)
+            }
+        }
+    }
+
+    /// Deserializes a value in this format from `reader`.
+    fn read<T: serde::de::DeserializeOwned>(
+        &self,
+        reader: impl std::io::Read,
+    ) -> std::result::Result<T, FormatError> {
+        match self {
+            Format::Json => serde_json::from_reader(reader).map_err(|e| Box::new(e) as _),
+            #[cfg(feature = "formats")]
+            Format::MessagePack => rmp_serde::from_read(reader).map_err(|e| Box::new(e) as _),
+            #[cfg(feature = "formats")]
+            Format::Cbor => ciborium::de::from_reader(reader).map_err(|e| Box::new(e) as _),
+            #[cfg(feature = "formats")]
+            Format::Toml => {
+                let mut reader = reader;
+                let mut text = String::new();
+                reader
+                    .read_to_string(&mut text)
+                    .map_err(|e| Box::new(e) as FormatError)?;
+                toml::from_str(&text).map_err(|e| Box::new(e) as _)
+            }
+        }
+    }
+}
+
+/// Configurable options that control printing performance records.
+///
+/// # Examples
+///
+/// Reports can be captured instead of printed:
+///
+/// ```rust
+/// # use wordle_rs::{perf::ColorChoice, strategy::stupid::Stupid, Harness};
+/// # let record = Harness::new()
+/// #     .verbose(false)
+/// #     .add_strategy(Box::new(Stupid), None)
+/// #     .test_num(10)
+/// #     .run()?;
+/// let mut report = Vec::new();
+/// record[0].to_summary().print_to(
+///     &mut report,
+///     wordle_rs::Summary::print_options()
+///         .histogram(true)
+///         .color(ColorChoice::Never)
+///         .width(60),
+/// )?;
+///
+/// let report = String::from_utf8(report).unwrap();
+/// assert!(report.lines().all(|line| line.chars().count() <= 60));
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+pub struct PrintOptions {
+    color: ColorChoice,
+    width: Option<usize>,
+    compare: Option<Summary>,
+    histogram: bool,
+    diagnostics: bool,
+    criterion: bool,
     baseline: Option<String>,
+    #[cfg(feature = "stats")]
+    alpha: Option<f64>,
+    #[cfg(feature = "stats")]
+    test: TestKind,
 }
 
-impl<'a> PrintOptions {
+impl PrintOptions {
     /// Creates a new instance with default configuration.
     ///
     /// Defaults:
+    /// - uses colors when printing to stdout, if it supports them
+    /// - prints lines up to 80 characters wide
     /// - does not compare against other summary
     /// - does not print histogram
+    /// - does not print diagnostics
+    /// - prints comparisons in the default style rather than criterion's
+    /// - uses a threshold p-value of `0.05` for comparisons
+    /// - uses the default [`TestKind`] for comparisons
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Sets the threshold p-value used when comparing against another summary.
+    ///
+    /// Printing returns [`HarnessError::InvalidAlpha`] before writing
+    /// anything if `alpha` is not in (0, 1).
+    #[cfg(feature = "stats")]
+    pub fn alpha(self, alpha: f64) -> Self {
+        Self {
+            alpha: Some(alpha),
+            ..self
+        }
+    }
+
+    /// Sets the hypothesis test used to compare the number of guesses against
+    /// another summary.
+    #[cfg(feature = "stats")]
+    pub fn stat_test(self, test: TestKind) -> Self {
+        Self { test, ..self }
+    }
+
+    /// Sets whether to color the output.
+    ///
+    /// Colors are only used with the `fancy` build feature.
+    pub fn color(self, color: ColorChoice) -> Self {
+        Self { color, ..self }
+    }
+
+    /// Sets the width of the output in characters.
+    ///
+    /// This sets the width of headers and histograms. Other lines are not
+    /// wrapped, so they may be longer.
+    pub fn width(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
     /// Sets the summary to compare against while printing.
     pub fn compare(self, baseline: &Summary) -> Self {
         Self {
@@ -551,15 +1424,45 @@ impl<'a> PrintOptions {
 
     /// Sets whether or not to display the histogram.
     ///
-    /// Passing `true` will display it and `false` will suppress it.
+    /// Passing `true` will display it and `false` will suppress it. When
+    /// comparing against another summary, both histograms are shown side by
+    /// side, as by [`Histogram::compare()`].
     pub fn histogram(self, histogram: bool) -> Self {
         Self { histogram, ..self }
     }
 
+    /// Sets whether or not to display guess-by-guess diagnostics, such as the
+    /// grades of the first guess and how quickly the possible answers narrow.
+    pub fn diagnostics(self, diagnostics: bool) -> Self {
+        Self {
+            diagnostics,
+            ..self
+        }
+    }
+
+    /// Sets whether or not to print comparisons in the style of criterion,
+    /// e.g. "change: +2.30% (p = 0.01 < 0.05)" followed by a verdict such as
+    /// "Performance has regressed."
+    ///
+    /// This only has an effect when comparing against another summary.
+    pub fn criterion(self, criterion: bool) -> Self {
+        Self { criterion, ..self }
+    }
+
+    /// Returns an error if the options cannot be printed, which is when the
+    /// threshold p-value is not in (0, 1).
+    fn check(&self) -> Result<()> {
+        #[cfg(feature = "stats")]
+        if let Some(alpha) = self.alpha {
+            check_alpha(alpha)?;
+        }
+
+        Ok(())
+    }
+
     /// Sets the baseline text.
     pub(crate) fn baseline(self, baseline: &BaselineOpt) -> Self {
         let baseline = match baseline {
-            BaselineOpt::None => None,
             BaselineOpt::Run(_, Some(name)) => {
                 Some(format!("Used as baseline and saved as {}", name))
             }
@@ -572,6 +1475,66 @@ impl<'a> PrintOptions {
     }
 }
 
+/// Whether to color printed output, set with [`PrintOptions::color()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorChoice {
+    /// Use colors when printing to stdout, if it supports them. This is the
+    /// default.
+    Auto,
+
+    /// Always use colors.
+    Always,
+
+    /// Never use colors.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns true if output should be colored, when printing to stdout if
+    /// `stdout` is true or to another writer otherwise.
+    pub(crate) fn enabled(self, stdout: bool) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                #[cfg(feature = "fancy")]
+                return stdout
+                    && supports_color::on_cached(supports_color::Stream::Stdout).is_some();
+                #[cfg(not(feature = "fancy"))]
+                return stdout;
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+impl Default for ColorChoice {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+/// The hypothesis test used to compare the number of guesses two strategies
+/// needed to solve puzzles.
+///
+/// The fraction of puzzles solved is always compared with Fisher's exact test.
+#[cfg(feature = "stats")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TestKind {
+    /// Welch's t-test, which does not assume that both strategies have the
+    /// same variance in their number of guesses. This is the default.
+    Welch,
+
+    /// Student's t-test, which pools the variances of both strategies.
+    Student,
+}
+
+#[cfg(feature = "stats")]
+impl Default for TestKind {
+    fn default() -> Self {
+        Self::Welch
+    }
+}
+
 /// A comparison between two [`Summary`]s.
 ///
 /// When the `stats` build feature is enabled (see the feature description
@@ -584,7 +1547,7 @@ pub struct Comparison<'a, 'b> {
     #[cfg(feature = "stats")]
     solved: FishersExactPvalues,
     #[cfg(feature = "stats")]
-    guesses: WelchsT<f64>,
+    guesses: TTest<f64>,
     #[cfg(feature = "stats")]
     alpha: f64,
 }
@@ -595,27 +1558,55 @@ impl<'a, 'b> Comparison<'a, 'b> {
     /// All of the "difference" methods on the resulting [`Comparison`]
     /// will return the equivalent of `this - baseline` in the corresponding
     /// measure.
+    ///
+    /// When the `stats` build feature is enabled, `alpha` is the threshold
+    /// p-value, and the number of guesses is compared with the default
+    /// [`TestKind`]. To choose the test, use
+    /// [`compare_with()`](Self::compare_with()).
     pub fn compare(
         this: &'a Summary,
         baseline: &'b Summary,
         #[cfg(feature = "stats")] alpha: f64,
     ) -> Result<Self> {
         #[cfg(feature = "stats")]
-        let guesses = WelchsT::two_sample(
-            this.histogram
+        return Self::compare_with(this, baseline, alpha, TestKind::default());
+
+        #[cfg(not(feature = "stats"))]
+        Ok(Self { this, baseline })
+    }
+
+    /// Produces a new [`Comparison`] from two [`Summary`]s, with `alpha` as
+    /// the threshold p-value and `test` selecting the hypothesis test run on
+    /// the number of guesses.
+    ///
+    /// Returns [`HarnessError::InvalidAlpha`] if `alpha` is not in (0, 1).
+    #[cfg(feature = "stats")]
+    pub fn compare_with(
+        this: &'a Summary,
+        baseline: &'b Summary,
+        alpha: f64,
+        test: TestKind,
+    ) -> Result<Self> {
+        check_alpha(alpha)?;
+
+        let guesses = {
+            let this = this
+                .histogram
                 .iter()
                 .enumerate()
-                .map(|(i, &v)| (i as f64 + 1.) * v as f64),
-            baseline
+                .map(|(i, &v)| (i as f64 + 1.) * v as f64);
+            let baseline = baseline
                 .histogram
                 .iter()
                 .enumerate()
-                .map(|(i, &v)| (i as f64 + 1.) * v as f64),
-            alpha,
-            Tails::Two,
-        )?;
+                .map(|(i, &v)| (i as f64 + 1.) * v as f64);
+
+            match test {
+                TestKind::Welch => TTest::welch(this, baseline, alpha, Tails::Two)?,
+                TestKind::Student => TTest::student(this, baseline, alpha, Tails::Two)?,
+            }
+        };
 
-        #[cfg(feature = "stats")]
         let solved = fishers_exact::fishers_exact(&[
             this.num_solved(),
             baseline.num_solved(),
@@ -627,11 +1618,8 @@ impl<'a, 'b> Comparison<'a, 'b> {
         Ok(Self {
             this,
             baseline,
-            #[cfg(feature = "stats")]
             solved,
-            #[cfg(feature = "stats")]
             guesses,
-            #[cfg(feature = "stats")]
             alpha,
         })
     }
@@ -711,17 +1699,74 @@ impl<'a, 'b> Comparison<'a, 'b> {
         }
     }
 
+    /// Returns Cohen's d for the number of guesses used in each solved puzzle.
+    ///
+    /// This is the difference between the mean number of guesses of the
+    /// two strategies divided by their pooled standard deviation, so a
+    /// negative value means that this strategy needed fewer guesses than the
+    /// baseline. As a rule of thumb, values around 0.2, 0.5, and 0.8 in
+    /// magnitude are considered small, medium, and large effects.
+    ///
+    /// Returns [`None`] when either strategy solved fewer than two puzzles
+    /// or when neither has any variation in its number of guesses.
+    pub fn cohens_d(&self) -> Option<f32> {
+        let (this_n, this_mean, this_var) = self.this.guesses_moments()?;
+        let (base_n, base_mean, base_var) = self.baseline.guesses_moments()?;
+
+        let pooled =
+            (((this_n - 1.) * this_var + (base_n - 1.) * base_var) / (this_n + base_n - 2.)).sqrt();
+
+        if pooled > 0. {
+            Some(((this_mean - base_mean) / pooled) as f32)
+        } else {
+            None
+        }
+    }
+
+    /// Returns Cohen's h for the fraction of puzzles solved.
+    ///
+    /// This is the difference between the arcsine-transformed solve rates of
+    /// the two strategies, so a positive value means that this strategy
+    /// solved more puzzles than the baseline. The same rule-of-thumb
+    /// thresholds as [`cohens_d()`](Self::cohens_d()) apply.
+    pub fn cohens_h(&self) -> f32 {
+        let phi = |p: f32| 2. * p.sqrt().asin();
+        phi(self.this.frac_solved()) - phi(self.baseline.frac_solved())
+    }
+
+    /// Returns the odds ratio of solving a puzzle with this strategy versus
+    /// the baseline.
+    ///
+    /// Values above 1 mean that this strategy was more likely to solve a
+    /// puzzle. When any of the four counts involved is zero, `0.5` is added
+    /// to each of them (the Haldane-Anscombe correction) so that the ratio
+    /// stays finite.
+    pub fn odds_ratio(&self) -> f32 {
+        let mut counts = [
+            self.this.num_solved() as f32,
+            self.this.num_missed() as f32,
+            self.baseline.num_solved() as f32,
+            self.baseline.num_missed() as f32,
+        ];
+
+        if counts.contains(&0.) {
+            counts.iter_mut().for_each(|n| *n += 0.5);
+        }
+
+        (counts[0] / counts[1]) / (counts[2] / counts[3])
+    }
+
     /// Indicates if the two summaries had a significantly different number
     /// of guesses per solved puzzle.
     ///
-    /// Internally, the comparison uses Welch's t-test with the p-value passed
+    /// Internally, the comparison uses the [`TestKind`] and p-value passed
     /// when creating this instance.
     #[cfg(feature = "stats")]
     pub fn is_sig_guesses(&self) -> bool {
         self.guesses.p < self.alpha
     }
 
-    /// Returns p-value from Welch's t-test run on the number of guesses each
+    /// Returns p-value from the t-test run on the number of guesses each
     /// strategy used to solve a puzzles, excluding those it did not solve.
     #[cfg(feature = "stats")]
     pub fn guesses_p_value(&self) -> f64 {
@@ -744,8 +1789,318 @@ impl<'a, 'b> Comparison<'a, 'b> {
     pub fn solved_p_value(&self) -> f64 {
         self.solved.two_tail_pvalue
     }
+
+    /// Indicates if this strategy was significantly better than the baseline
+    /// in `metric`: it solved a larger fraction of puzzles or used fewer
+    /// guesses, and the difference is significant.
+    #[cfg(feature = "stats")]
+    pub fn is_improvement(&self, metric: Metric) -> bool {
+        match metric {
+            Metric::SolveRate => self.is_sig_solved() && self.frac_solved_diff() > 0.,
+            Metric::MeanGuesses => {
+                self.is_sig_guesses() && self.mean_guesses_diff().map_or(false, |d| d < 0.)
+            }
+        }
+    }
+}
+
+/// Writes the "change" and verdict lines of criterion-style output.
+///
+/// `change` is the relative change from the baseline and `improved` is
+/// whether that change is for the better.
+fn write_change(
+    stdout: &mut dyn Write,
+    change: f32,
+    improved: bool,
+    #[cfg(feature = "stats")] (p, alpha): (f64, f64),
+) -> Result<()> {
+    #[cfg(feature = "stats")]
+    {
+        writeln!(
+            stdout,
+            "{:<16}change: {:+.2}% (p = {:.2} {} {:.2})",
+            "",
+            change * 100.,
+            p,
+            if p < alpha { "<" } else { ">" },
+            alpha
+        )?;
+
+        let verdict = if p >= alpha {
+            "No change in performance detected."
+        } else if improved {
+            "Performance has improved."
+        } else {
+            "Performance has regressed."
+        };
+        writeln!(stdout, "{:<16}{}", "", verdict)?;
+    }
+
+    #[cfg(not(feature = "stats"))]
+    {
+        let _ = improved;
+        writeln!(stdout, "{:<16}change: {:+.2}%", "", change * 100.)?;
+    }
+
+    Ok(())
+}
+
+/// Displays `value` with `style` if `enabled`, and plainly otherwise.
+#[cfg(feature = "fancy")]
+fn paint<T: Display>(value: T, style: Style, enabled: bool) -> impl Display {
+    struct Painted<T>(T, Option<Style>);
+
+    impl<T: Display> Display for Painted<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self.1 {
+                Some(style) => style.style(&self.0).fmt(f),
+                None => self.0.fmt(f),
+            }
+        }
+    }
+
+    Painted(value, enabled.then(|| style))
+}
+
+/// Gets the style of a change, green if it is an improvement and red
+/// otherwise.
+#[cfg(feature = "fancy")]
+fn change_style(improved: bool) -> Style {
+    if improved {
+        Style::new().green()
+    } else {
+        Style::new().red()
+    }
+}
+
+/// Splits a strategy name of the form "Name vX" into its name and version.
+#[cfg(feature = "serde")]
+fn split_strategy_name(full: &str) -> (&str, &str) {
+    match full.rfind(" v") {
+        Some(i) => (&full[..i], &full[i + 2..]),
+        None => (full, ""),
+    }
+}
+
+/// Splits a full name like "Name vX" saved before names and versions were
+/// stored separately, leaving names that already have a version alone.
+#[cfg(feature = "serde")]
+fn split_legacy_name(name: &mut String, version: &mut String) {
+    if version.is_empty() {
+        let (split_name, split_version) = split_strategy_name(name);
+        let (split_name, split_version) = (split_name.to_string(), split_version.to_string());
+        *name = split_name;
+        *version = split_version;
+    }
+}
+
+/// Writes the full name of a strategy, like "Basic v0.1.0".
+fn fmt_strategy(f: &mut std::fmt::Formatter<'_>, name: &str, version: &str) -> std::fmt::Result {
+    if version.is_empty() {
+        f.pad(name)
+    } else {
+        f.pad(&format!("{} v{}", name, version))
+    }
+}
+
+/// Describes the magnitude of a standardized effect size using Cohen's
+/// conventional thresholds.
+fn effect_magnitude(effect: f32) -> &'static str {
+    match effect.abs() {
+        e if e < 0.2 => "negligible",
+        e if e < 0.5 => "small",
+        e if e < 0.8 => "medium",
+        _ => "large",
+    }
+}
+
+/// How far a strategy's performance may slip from its baseline before
+/// [`Record::check_regressions()`](crate::Record::check_regressions()) treats
+/// it as a regression.
+///
+/// Defaults:
+/// - the solve rate may drop by at most 1 percentage point
+/// - the mean number of guesses may rise by at most `0.05`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct RegressionThresholds {
+    solve_rate_drop: f32,
+    mean_guesses_rise: f32,
+}
+
+impl Default for RegressionThresholds {
+    fn default() -> Self {
+        Self {
+            solve_rate_drop: 0.01,
+            mean_guesses_rise: 0.05,
+        }
+    }
+}
+
+impl RegressionThresholds {
+    /// Creates a new instance with the default thresholds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the largest allowed drop in the fraction of puzzles solved.
+    pub fn solve_rate_drop(self, solve_rate_drop: f32) -> Self {
+        Self {
+            solve_rate_drop,
+            ..self
+        }
+    }
+
+    /// Sets the largest allowed rise in the mean number of guesses needed to
+    /// solve a puzzle.
+    pub fn mean_guesses_rise(self, mean_guesses_rise: f32) -> Self {
+        Self {
+            mean_guesses_rise,
+            ..self
+        }
+    }
+
+    /// Checks `this` against `baseline`, returning any regressions found.
+    pub(crate) fn check(&self, this: &Summary, baseline: &Summary) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+
+        let drop = baseline.frac_solved() - this.frac_solved();
+        if drop > self.solve_rate_drop {
+            regressions.push(Regression {
+                strategy_name: this.to_string(),
+                kind: RegressionKind::SolveRate,
+                baseline: baseline.frac_solved(),
+                current: this.frac_solved(),
+            });
+        }
+
+        if let (Some(current), Some(old)) = (this.mean_guesses(), baseline.mean_guesses()) {
+            if current - old > self.mean_guesses_rise {
+                regressions.push(Regression {
+                    strategy_name: this.to_string(),
+                    kind: RegressionKind::MeanGuesses,
+                    baseline: old,
+                    current,
+                });
+            }
+        }
+
+        regressions
+    }
+}
+
+/// A measure by which one strategy can be better than another.
+///
+/// This picks the best strategy to keep with
+/// [`Harness::update_baseline_if_better()`](crate::Harness::update_baseline_if_better())
+/// and the best parameters in a
+/// [`GridReport`](crate::experiments::GridReport).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Metric {
+    /// A higher fraction of puzzles solved, with ties broken by the mean
+    /// number of guesses.
+    SolveRate,
+
+    /// A lower mean number of guesses needed to solve a puzzle, with ties
+    /// broken by the fraction of puzzles solved.
+    MeanGuesses,
+}
+
+impl Metric {
+    /// Every metric.
+    pub const ALL: [Metric; 2] = [Metric::SolveRate, Metric::MeanGuesses];
+
+    /// Orders summaries from best to worst by this metric.
+    pub(crate) fn compare(self, a: &Summary, b: &Summary) -> std::cmp::Ordering {
+        let by = |a: f32, b: f32| a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal);
+        let mean = |s: &Summary| s.mean_guesses().unwrap_or(f32::INFINITY);
+        let solve_rate = by(b.frac_solved(), a.frac_solved());
+        let mean_guesses = by(mean(a), mean(b));
+
+        match self {
+            Metric::SolveRate => solve_rate.then(mean_guesses),
+            Metric::MeanGuesses => mean_guesses.then(solve_rate),
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Metric::SolveRate => write!(f, "solve rate"),
+            Metric::MeanGuesses => write!(f, "mean guesses"),
+        }
+    }
+}
+
+/// The measure in which a [`Regression`] occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegressionKind {
+    /// The fraction of puzzles solved dropped.
+    SolveRate,
+
+    /// The mean number of guesses needed to solve a puzzle rose.
+    MeanGuesses,
+}
+
+/// A single measure in which a strategy performed worse than its baseline.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Regression {
+    /// The name of the strategy that regressed.
+    pub strategy_name: String,
+
+    /// The measure that regressed.
+    pub kind: RegressionKind,
+
+    /// The value of the measure in the baseline.
+    pub baseline: f32,
+
+    /// The value of the measure in the current run.
+    pub current: f32,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            RegressionKind::SolveRate => write!(
+                f,
+                "{}: solve rate dropped from {:.1}% to {:.1}%",
+                self.strategy_name,
+                self.baseline * 100.,
+                self.current * 100.
+            ),
+            RegressionKind::MeanGuesses => write!(
+                f,
+                "{}: mean guesses rose from {:.2} to {:.2}",
+                self.strategy_name, self.baseline, self.current
+            ),
+        }
+    }
+}
+
+/// The regressions found by
+/// [`Record::check_regressions()`](crate::Record::check_regressions()).
+///
+/// This implements [`std::error::Error`], so returning it from `main()` will
+/// make the process exit with a non-zero status.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct RegressionReport {
+    /// Every regression found, in the order the strategies were run.
+    pub regressions: Vec<Regression>,
+}
+
+impl Display for RegressionReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} regression(s) found:", self.regressions.len())?;
+        for regression in &self.regressions {
+            writeln!(f, "- {}", regression)?;
+        }
+
+        Ok(())
+    }
 }
 
+impl std::error::Error for RegressionReport {}
+
 /// A histogram of the number of guesses used by a strategy in each puzzle
 /// that it solved.
 ///
@@ -801,12 +2156,214 @@ impl Deref for Histogram {
     }
 }
 
+impl Histogram {
+    /// Shows this histogram next to a `baseline` histogram.
+    ///
+    /// The [`Display`] implementation of the result prints each bin of this
+    /// histogram with its change from the baseline, followed by the same bin
+    /// of the baseline, with both drawn to the same scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use wordle_rs::perf::Histogram;
+    /// let this: Histogram = [0, 3, 8, 41, 25, 18].into();
+    /// let baseline: Histogram = [0, 3, 10, 35, 30, 17].into();
+    ///
+    /// let shown = this.compare(&baseline).to_string();
+    /// let mut lines = shown.lines().skip(6);
+    /// assert_eq!(lines.next(), Some("4 |■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■■ 41 (+6)"));
+    /// assert_eq!(lines.next(), Some("  |□□□□□□□□□□□□□□□□□□□□□□□□□□□□□□□□□□□ 35"));
+    /// ```
+    pub fn compare<'a>(&'a self, baseline: &'a Histogram) -> HistogramComparison<'a> {
+        HistogramComparison {
+            this: self,
+            baseline,
+        }
+    }
+}
+
+/// Two histograms shown side by side, produced by [`Histogram::compare()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistogramComparison<'a> {
+    this: &'a Histogram,
+    baseline: &'a Histogram,
+}
+
+impl Display for HistogramComparison<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let max = self
+            .this
+            .iter()
+            .chain(self.baseline.iter())
+            .copied()
+            .max()
+            .unwrap();
+        let digits =
+            std::iter::successors(Some(max), |&n| (n >= 10).then(|| n / 10)).count() as u32;
+        // Leave room for the label, the count and a change of the same size.
+        let width = f.width().unwrap_or(80) as f32;
+        let count_per_mark = (max as f32 / (width - 2. * digits as f32 - 9.)).max(1.0);
+
+        for (i, (&bin, &base)) in self.this.iter().zip(self.baseline.iter()).enumerate() {
+            let marks = (bin as f32 / count_per_mark).floor() as usize;
+            writeln!(
+                f,
+                "{} |{:■>marks$} {} ({:+})",
+                i + 1,
+                "",
+                bin,
+                bin as i64 - base as i64
+            )?;
+            let marks = (base as f32 / count_per_mark).floor() as usize;
+            writeln!(f, "  |{:□>marks$} {}", "", base)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "plot")]
+impl Histogram {
+    /// Renders the histogram as an SVG bar chart of the number of puzzles
+    /// solved in each number of guesses.
+    pub fn render_svg(&self, title: &str) -> Result<String> {
+        use plotters::{prelude::*, series::Histogram as Bars};
+
+        let max = self.bins.iter().copied().max().unwrap_or(0).max(1);
+        let mut svg = String::new();
+
+        {
+            let root = SVGBackend::with_string(&mut svg, (640, 400)).into_drawing_area();
+            root.fill(&WHITE).map_err(plot_error)?;
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption(title, ("sans-serif", 20))
+                .margin(10)
+                .x_label_area_size(35)
+                .y_label_area_size(45)
+                .build_cartesian_2d((1_u32..6_u32).into_segmented(), 0_u32..max + max / 10 + 1)
+                .map_err(plot_error)?;
+
+            chart
+                .configure_mesh()
+                .disable_x_mesh()
+                .x_desc("Guesses")
+                .y_desc("Puzzles solved")
+                .draw()
+                .map_err(plot_error)?;
+
+            chart
+                .draw_series(
+                    Bars::vertical(&chart).style(BLUE.filled()).margin(10).data(
+                        self.bins
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &n)| (i as u32 + 1, n)),
+                    ),
+                )
+                .map_err(plot_error)?;
+
+            root.present().map_err(plot_error)?;
+        }
+
+        Ok(svg)
+    }
+}
+
+#[cfg(feature = "plot")]
+impl<'a, 'b> Comparison<'a, 'b> {
+    /// Renders the comparison as an SVG chart with paired bars for each
+    /// number of guesses, showing the fraction of puzzles each strategy
+    /// solved in that many guesses.
+    pub fn render_svg(&self) -> Result<String> {
+        use plotters::prelude::*;
+
+        let fractions = |summary: &Summary| -> Vec<f32> {
+            summary
+                .histogram
+                .iter()
+                .map(|&n| n as f32 / summary.num_tried() as f32)
+                .collect()
+        };
+        let this = fractions(self.this);
+        let baseline = fractions(self.baseline);
+        let max = this
+            .iter()
+            .chain(baseline.iter())
+            .copied()
+            .fold(0.01_f32, f32::max);
+
+        let mut svg = String::new();
+
+        {
+            let root = SVGBackend::with_string(&mut svg, (640, 400)).into_drawing_area();
+            root.fill(&WHITE).map_err(plot_error)?;
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption(
+                    format!("{} vs. {}", self.this, self.baseline),
+                    ("sans-serif", 20),
+                )
+                .margin(10)
+                .x_label_area_size(35)
+                .y_label_area_size(45)
+                .build_cartesian_2d(0.5_f32..6.5_f32, 0_f32..max * 1.1)
+                .map_err(plot_error)?;
+
+            chart
+                .configure_mesh()
+                .disable_x_mesh()
+                .x_labels(6)
+                .x_label_formatter(&|x| format!("{:.0}", x))
+                .y_label_formatter(&|y| format!("{:.0}%", y * 100.))
+                .x_desc("Guesses")
+                .y_desc("Puzzles solved")
+                .draw()
+                .map_err(plot_error)?;
+
+            for (fracs, offset, color, name) in [
+                (&this, -0.35, BLUE, self.this.to_string()),
+                (&baseline, 0., RED, self.baseline.to_string()),
+            ] {
+                chart
+                    .draw_series(fracs.iter().enumerate().map(|(i, &f)| {
+                        let x = i as f32 + 1. + offset;
+                        Rectangle::new([(x, 0.), (x + 0.35, f)], color.filled())
+                    }))
+                    .map_err(plot_error)?
+                    .label(name)
+                    .legend(move |(x, y)| {
+                        Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled())
+                    });
+            }
+
+            chart
+                .configure_series_labels()
+                .background_style(WHITE)
+                .border_style(BLACK)
+                .draw()
+                .map_err(plot_error)?;
+
+            root.present().map_err(plot_error)?;
+        }
+
+        Ok(svg)
+    }
+}
+
+#[cfg(feature = "plot")]
+pub(crate) fn plot_error<E: std::error::Error + Send + 'static>(e: E) -> WordleError {
+    HarnessError::Plot(Box::new(e)).into()
+}
+
 impl Display for Histogram {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let max = *self.iter().max().unwrap();
         let digits =
             std::iter::successors(Some(max), |&n| (n >= 10).then(|| n / 10)).count() as u32;
-        let count_per_mark = (max as f32 / (80. - digits as f32 - 6.)).max(1.0);
+        let width = f.width().unwrap_or(80) as f32;
+        let count_per_mark = (max as f32 / (width - digits as f32 - 6.)).max(1.0);
 
         for (i, &bin) in self.bins.iter().enumerate() {
             write!(f, "{} |", i + 1)?;
@@ -816,7 +2373,7 @@ impl Display for Histogram {
 
         Ok(())
 
-        // TODO: test this to make sure it never outputs a line longer than 80 characters
+        // TODO: test this to make sure it never outputs a line longer than the width
     }
 }
 
@@ -825,7 +2382,20 @@ mod test {
     use proptest::prelude::*;
 
     use super::*;
-    use crate::{mock::Mock, Harness, Result, WordleError};
+    use crate::{mock::Mock, Harness, Puzzle, Result, WordleError};
+
+    #[test]
+    fn histogram_comparison() {
+        let this: Histogram = [0, 1234, 98765, 100000, 5, 0].into();
+        let baseline: Histogram = [1, 1000, 99999, 3, 5, 0].into();
+
+        let shown = this.compare(&baseline).to_string();
+        assert_eq!(shown.lines().count(), 12);
+        assert!(shown.lines().all(|line| line.chars().count() <= 80));
+        assert!(shown.lines().any(|line| line.ends_with(" 1234 (+234)")));
+        assert!(shown.lines().any(|line| line.ends_with(" 0 (-1)")));
+        assert!(shown.lines().any(|line| line.ends_with(" 5 (+0)")));
+    }
 
     #[test]
     fn perf_correct_stats() -> Result<()> {
@@ -840,7 +2410,7 @@ mod test {
             Word::from_str("knife")?,
         ];
 
-        let harness = Harness::new()
+        let mut harness = Harness::new()
             .parallel(false)
             .add_strategy(Box::new(Mock::new(None)), None);
         let perfs = harness.debug_run(Some(&words))?;
@@ -870,7 +2440,10 @@ mod test {
             assert_eq!(expected.1, found.1.inner())
         }
 
-        assert_eq!(perfs[0].strategy_name(), "Mock None v1.2.4");
+        assert_eq!(perfs[0].strategy_name(), "Mock None");
+        assert_eq!(perfs[0].strategy_version(), "1.2.4");
+        assert_eq!(perfs[0].save_name(), None);
+        assert_eq!(perfs[0].to_string(), "Mock None v1.2.4");
         assert_eq!(perfs[0].num_tried(), 8);
         assert_eq!(perfs[0].num_solved(), 4);
         assert!(perfs[0].frac_solved() - 0.5 < f32::EPSILON);
@@ -882,7 +2455,10 @@ mod test {
 
         let summary = perfs[0].to_summary();
 
-        assert_eq!(summary.strategy_name(), "Mock None v1.2.4");
+        assert_eq!(summary.strategy_name(), "Mock None");
+        assert_eq!(summary.strategy_version(), "1.2.4");
+        assert_eq!(summary.to_string(), "Mock None v1.2.4");
+        assert_eq!(format!("{:-^20}", summary), "--Mock None v1.2.4--");
         assert_eq!(summary.histogram, [0, 1, 1, 0, 1, 1].into());
         assert_eq!(summary.num_tried(), 8);
         assert_eq!(summary.num_solved(), 4);
@@ -893,15 +2469,287 @@ mod test {
         assert_eq!(summary.num_missed(), 4);
         assert!(summary.frac_missed() - 0.5 < f32::EPSILON);
 
+        assert_eq!(summary.first_guess_greens().iter().sum::<u32>(), 8);
+        assert_eq!(summary.first_guess_yellows().iter().sum::<u32>(), 8);
+        assert!((summary.solve_rate_by_guess(1) - 0.).abs() < f32::EPSILON);
+        assert!((summary.solve_rate_by_guess(2) - 0.125).abs() < f32::EPSILON);
+        assert!((summary.solve_rate_by_guess(6) - 0.5).abs() < f32::EPSILON);
+        assert_eq!(summary.mean_candidates_after(0), None);
+        assert_eq!(summary.mean_candidates_after(7), None);
+        assert!(summary.mean_candidates_after(1).unwrap() >= 1.);
+        assert!(summary.mean_candidates_after(1) >= summary.mean_candidates_after(2));
+        assert_eq!(summary.mean_reported_candidates_after(1), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reported_candidates() -> Result<()> {
+        let mut perf = Perf::new(&Mock::new(None), None);
+        let (earth, ratio, heart) = (
+            Word::from_str("earth")?,
+            Word::from_str("ratio")?,
+            Word::from_str("heart")?,
+        );
+
+        for reports in [[Some(10), Some(1)], [Some(20), None], [None, Some(3)]] {
+            let mut puzzle = Puzzle::new(earth);
+            let mut attempts = Attempts::cheat(false);
+            for (guess, report) in [ratio, heart].iter().zip(reports) {
+                puzzle.check(guess, &mut attempts)?;
+                if let Some(report) = report {
+                    attempts.report_candidates(report);
+                }
+            }
+            perf.tries.push((earth, attempts));
+        }
+
+        let summary = perf.to_summary();
+        assert_eq!(summary.mean_reported_candidates_after(0), None);
+        assert_eq!(summary.mean_reported_candidates_after(1), Some(15.));
+        assert_eq!(summary.mean_reported_candidates_after(2), Some(2.));
+        assert_eq!(summary.mean_reported_candidates_after(3), None);
+
+        let merged = Summary::merge(&[summary.clone(), summary])?;
+        assert_eq!(merged.mean_reported_candidates_after(1), Some(15.));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn split_strategy_names() {
+        assert_eq!(split_strategy_name("Basic v0.1.0"), ("Basic", "0.1.0"));
+        assert_eq!(
+            split_strategy_name("Mock None v1.2.4"),
+            ("Mock None", "1.2.4")
+        );
+        assert_eq!(split_strategy_name("Unversioned"), ("Unversioned", ""));
+
+        let (mut name, mut version) = ("Mock None v1.2.4".to_string(), String::new());
+        split_legacy_name(&mut name, &mut version);
+        assert_eq!((name.as_str(), version.as_str()), ("Mock None", "1.2.4"));
+
+        let (mut name, mut version) = ("Tom v2".to_string(), "1.0".to_string());
+        split_legacy_name(&mut name, &mut version);
+        assert_eq!((name.as_str(), version.as_str()), ("Tom v2", "1.0"));
+    }
+
+    #[test]
+    fn guess_percentiles() {
+        let solved = summary("Mock v1.0", 100, 90, [0, 10, 30, 30, 10, 10]);
+
+        assert_eq!(solved.cumulative_histogram(), [0, 10, 40, 70, 80, 90]);
+        assert_eq!(solved.percentile_guesses(0.), Some(2));
+        assert_eq!(solved.percentile_guesses(10.), Some(2));
+        assert_eq!(solved.percentile_guesses(12.), Some(3));
+        assert_eq!(solved.median_guesses(), Some(4));
+        assert_eq!(solved.percentile_guesses(90.), Some(6));
+        assert_eq!(solved.percentile_guesses(100.), Some(6));
+        assert!((solved.solve_rate_by_guess(3) - 0.4).abs() < f32::EPSILON);
+
+        let unsolved = summary("Mock v1.0", 10, 0, [0; 6]);
+        assert_eq!(unsolved.median_guesses(), None);
+    }
+
+    #[test]
+    fn merge_summaries() -> Result<()> {
+        let first = summary("Mock v1.0", 100, 90, [0, 10, 30, 30, 10, 10]);
+        let second = summary("Mock v1.0", 50, 40, [1, 4, 10, 15, 5, 5]);
+
+        let merged = Summary::merge(&[first.clone(), second])?;
+        assert_eq!(merged.to_string(), "Mock v1.0");
+        assert_eq!(merged.num_tried(), 150);
+        assert_eq!(merged.num_solved(), 130);
+        assert_eq!(merged.histogram, [1, 14, 40, 45, 15, 15].into());
+
+        assert_eq!(Summary::merge(std::slice::from_ref(&first))?, first);
+        assert!(Summary::merge(&[]).is_err());
+
+        let other = summary("Mock v1.1", 10, 10, [0, 0, 10, 0, 0, 0]);
+        assert!(matches!(
+            Summary::merge(&[first, other]),
+            Err(WordleError::Harness {
+                kind: HarnessError::MismatchedStrategies { .. }
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_perfs() -> Result<()> {
+        let words = [Word::from_str("tithe")?, Word::from_str("parka")?];
+
+        let mut harness = Harness::new()
+            .parallel(false)
+            .add_strategy(Box::new(Mock::new(None)), None);
+        let first = harness.debug_run(Some(&words[..1]))?;
+        let second = harness.debug_run(Some(&words[1..]))?;
+        let whole = harness.debug_run(Some(&words))?;
+
+        let merged = Perf::merge(&[first[0].clone(), second[0].clone()])?;
+        assert_eq!(merged.tries(), whole[0].tries());
+        assert_eq!(merged.elapsed(), first[0].elapsed() + second[0].elapsed());
+        assert_eq!(merged.to_summary(), whole[0].to_summary());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_formats() -> Result<()> {
+        let summary = summary("Mock v1.0", 100, 90, [0, 10, 30, 30, 10, 10]);
+        let dir = std::env::temp_dir().join("wordle_rs_save_formats");
+
+        for format in Format::ALL {
+            let name = format!("mock_{}", format.extension());
+            let path = summary.save_as(&name, &dir, true, *format)?;
+            assert_eq!(path.extension().unwrap(), format.extension());
+            assert_eq!(Format::from_extension(format.extension()), Some(*format));
+            assert_eq!(Summary::from_saved(&name, &dir)?, summary);
+        }
+
+        assert_eq!(Format::from_extension("yaml"), None);
+        assert!(Summary::from_saved("missing", &dir).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+
         Ok(())
     }
 
+    #[test]
+    fn regression_thresholds() {
+        let baseline = summary("Mock v1.0", 100, 90, [0, 10, 30, 30, 10, 10]);
+        let same = summary("Mock v1.1", 100, 90, [0, 10, 30, 30, 10, 10]);
+        let worse = summary("Mock v1.1", 100, 80, [0, 0, 20, 30, 20, 10]);
+
+        let thresholds = RegressionThresholds::new();
+        assert!(thresholds.check(&same, &baseline).is_empty());
+
+        let regressions = thresholds.check(&worse, &baseline);
+        assert_eq!(regressions.len(), 2);
+        assert_eq!(regressions[0].kind, RegressionKind::SolveRate);
+        assert_eq!(regressions[1].kind, RegressionKind::MeanGuesses);
+
+        let lenient = thresholds.solve_rate_drop(0.2).mean_guesses_rise(1.);
+        assert!(lenient.check(&worse, &baseline).is_empty());
+    }
+
+    #[test]
+    fn effect_sizes() -> Result<()> {
+        let this = summary("this v1.0", 100, 90, [0, 10, 30, 30, 10, 10]);
+        let baseline = summary("baseline v1.0", 100, 80, [0, 0, 20, 30, 20, 10]);
+
+        let comparison = this.compare(&baseline)?;
+
+        assert!(comparison.cohens_d().unwrap() < 0.);
+        assert!(comparison.cohens_h() > 0.);
+        assert!((comparison.odds_ratio() - (90. / 10.) / (80. / 20.)).abs() < 1e-4);
+
+        let reversed = baseline.compare(&this)?;
+        assert!((reversed.cohens_h() + comparison.cohens_h()).abs() < f32::EPSILON);
+        assert!((reversed.cohens_d().unwrap() + comparison.cohens_d().unwrap()).abs() < 1e-4);
+
+        assert_eq!(effect_magnitude(0.1), "negligible");
+        assert_eq!(effect_magnitude(-0.3), "small");
+        assert_eq!(effect_magnitude(0.6), "medium");
+        assert_eq!(effect_magnitude(-1.2), "large");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn invalid_alpha() {
+        let this = summary("this v1.0", 100, 90, [0, 10, 30, 30, 10, 10]);
+        let baseline = summary("baseline v1.0", 100, 80, [0, 0, 20, 30, 20, 10]);
+
+        for alpha in [0., 1., f64::NAN] {
+            let mut report = Vec::new();
+            let printed = this.print_to(
+                &mut report,
+                Summary::print_options().compare(&baseline).alpha(alpha),
+            );
+            assert!(matches!(
+                printed,
+                Err(WordleError::Harness {
+                    kind: HarnessError::InvalidAlpha(_)
+                })
+            ));
+            assert!(report.is_empty());
+            assert!(this
+                .compare_with(&baseline, alpha, TestKind::Welch)
+                .is_err());
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn improvements() -> Result<()> {
+        let better = summary("better v1.0", 1000, 990, [0, 100, 500, 300, 90, 0]);
+        let worse = summary("worse v1.0", 1000, 800, [0, 0, 100, 300, 300, 100]);
+        let close = summary("close v1.0", 1000, 989, [0, 100, 500, 300, 89, 0]);
+
+        assert!(better.compare(&worse)?.is_improvement(Metric::SolveRate));
+        assert!(!worse.compare(&better)?.is_improvement(Metric::SolveRate));
+        assert!(!worse.compare(&better)?.is_improvement(Metric::MeanGuesses));
+        assert!(!close.compare(&better)?.is_improvement(Metric::SolveRate));
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "serde", feature = "stats"))]
+    #[test]
+    fn replace_saved() -> Result<()> {
+        let better = summary("better v1.0", 100, 99, [0, 10, 50, 30, 9, 0]);
+        let worse = summary("worse v1.0", 100, 80, [0, 0, 10, 30, 30, 10]);
+
+        let mut ranked = vec![worse.clone(), better.clone()];
+        ranked.sort_by(|a, b| Metric::SolveRate.compare(a, b));
+        assert_eq!(ranked, [better.clone(), worse.clone()]);
+        ranked.sort_by(|a, b| Metric::MeanGuesses.compare(a, b));
+        assert_eq!(ranked, [better.clone(), worse.clone()]);
+
+        let dir = std::env::temp_dir().join("wordle_rs_replace_saved");
+        let _ = std::fs::remove_dir_all(&dir);
+        worse.save("best", &dir, false)?;
+
+        let (path, format) = saved_path("best", &dir);
+        assert_eq!(format, Format::Json);
+        better.replace_saved(&path, format)?;
+        assert_eq!(Summary::from_saved("best", &dir)?, better);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        Ok(())
+    }
+
+    fn summary(name: &str, num_tried: u32, num_solved: u32, bins: [u32; 6]) -> Summary {
+        let (name, version) = name.split_once(" v").unwrap();
+        Summary {
+            strategy_name: name.to_string(),
+            strategy_version: version.to_string(),
+            save_name: None,
+            num_tried,
+            num_solved,
+            cumulative_guesses: 0,
+            histogram: bins.into(),
+            first_greens: [0; 6],
+            first_yellows: [0; 6],
+            guessed: [0; 6],
+            candidates: [0; 6],
+            reported: [0; 6],
+            reported_candidates: [0; 6],
+        }
+    }
+
     proptest! {
         #[test]
         fn perf_matches_summary(guesses in &proptest::sample::subsequence(&crate::words::GUESSES.as_ref()[0..50], 1..7), answers in &proptest::sample::subsequence(&crate::words::GUESSES.as_ref()[0..50], 1..20)) {
             let answers: Vec<Word> = answers.iter().map(|s| Word::from_str(s).unwrap()).collect();
 
-            let harness = Harness::new()
+            let mut harness = Harness::new()
                 .parallel(false)
                 .add_strategy(Box::new(Mock::new(Some(guesses))), None);
 
@@ -934,7 +2782,7 @@ mod test {
             assert!(1 <= pivot && pivot <= 6);
             let (guesses1, guesses2) = guesses.split_at(pivot);
 
-            let harness = Harness::new()
+            let mut harness = Harness::new()
                 .parallel(false)
                 .add_strategy(Box::new(Mock::new(Some(guesses1.to_vec()))), None)
                 .add_strategy(Box::new(Mock::new(Some(guesses2.to_vec()))), None);
@@ -0,0 +1,27 @@
+//! The most commonly used items in `wordle_rs`, for glob importing.
+//!
+//! Writing a strategy and running it in the test harness usually needs items
+//! from both [`strategy`](crate::strategy) and [`harness`](crate::harness).
+//! Instead of importing each of them, you can write:
+//!
+//! ```rust
+//! use wordle_rs::prelude::*;
+//!
+//! let mut puzzle = Puzzle::new(Word::from_str("earth")?);
+//! let mut attempts = Attempts::cheat(false);
+//! let (grades, _) = puzzle.check(&Word::from_str("ratio")?, &mut attempts)?;
+//! assert_eq!(grades, [Almost, Correct, Almost, Incorrect, Incorrect]);
+//! #
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+#[doc(no_inline)]
+pub use crate::{
+    harness::Harness,
+    perf::Summary,
+    strategy::{
+        Attempts, AttemptsKey, Grade,
+        Grade::{Almost, Correct, Incorrect},
+        Puzzle, Strategy, Word,
+    },
+};
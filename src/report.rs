@@ -0,0 +1,606 @@
+//! Rendering records as reports in other formats.
+//!
+//! A [`Record`] can be turned into a machine-readable JSON report with
+//! [`Record::to_json()`], a Markdown report for pull request comments with
+//! [`Record::to_markdown()`], a standalone HTML page with
+//! [`Record::to_html()`] and, with the `plot` feature, SVG charts with
+//! [`Record::write_plots()`].
+
+#[cfg(any(feature = "serde", feature = "plot"))]
+use std::path::Path;
+#[cfg(feature = "plot")]
+use std::path::PathBuf;
+
+#[cfg(feature = "plot")]
+use crate::perf::plot_error;
+#[cfg(feature = "serde")]
+use crate::{harness::BaselineOpt, HarnessError};
+use crate::{Record, Result, Summary};
+
+impl Record {
+    /// The version of the schema produced by [`to_json()`](Self::to_json()).
+    ///
+    /// This is bumped whenever a field is removed or changes meaning.
+    #[cfg(feature = "serde")]
+    pub const JSON_SCHEMA_VERSION: u32 = 2;
+
+    /// Produces a machine-readable JSON report of the run.
+    ///
+    /// Unlike [`save()`](Self::save()), which stores the record itself, this
+    /// follows a stable schema meant for dashboards and scripts. The report
+    /// contains the harness configuration, and for each strategy its summary,
+    /// histogram, comparison against each baseline (in `comparisons`, in the
+    /// order of `baselines` in the configuration) and (when it was
+    /// disqualified) why it was disqualified. Values that are not available,
+    /// such as p-values without the `stats` feature or a comparison that
+    /// cannot be computed because a strategy solved no puzzles, are `null`.
+    ///
+    /// The top-level `schema_version` field holds
+    /// [`JSON_SCHEMA_VERSION`](Self::JSON_SCHEMA_VERSION).
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        use serde_json::{json, Value};
+
+        let baselines = self.baseline_summaries();
+        let baseline_configs: Vec<Value> = baselines
+            .iter()
+            .map(|(baseline, _)| match baseline {
+                BaselineOpt::Run(index, name) => json!({
+                    "kind": "run",
+                    "index": index,
+                    "saved_as": name,
+                }),
+                BaselineOpt::Saved(_, name) => json!({
+                    "kind": "saved",
+                    "name": name,
+                }),
+            })
+            .collect();
+
+        let strategies: Vec<Value> = self
+            .iter()
+            .map(|perf| {
+                let summary = perf.to_summary();
+                let (name, version) = (summary.strategy_name(), summary.strategy_version());
+
+                let comparisons: Vec<Value> = baselines
+                    .iter()
+                    .map(|(_, baseline)| comparison_json(&summary, baseline))
+                    .collect();
+
+                json!({
+                    "name": name,
+                    "version": version,
+                    "hardmode": perf.tries().first().map(|(_, a)| a.hard),
+                    "elapsed_secs": perf.elapsed().as_secs_f64(),
+                    "metadata": perf.metadata(),
+                    "summary": {
+                        "num_tried": summary.num_tried(),
+                        "num_solved": summary.num_solved(),
+                        "frac_solved": summary.frac_solved(),
+                        "mean_guesses": summary.mean_guesses(),
+                        "cumulative_guesses": summary.cumulative_guesses(),
+                        "histogram": &summary.histogram[..],
+                    },
+                    "comparisons": comparisons,
+                    "disqualified": perf.disqualification().map(|d| d.to_string()),
+                })
+            })
+            .collect();
+
+        let report = json!({
+            "schema_version": Self::JSON_SCHEMA_VERSION,
+            "config": {
+                "baselines": baseline_configs,
+                "num_words": self.first().map(|p| p.num_tried()),
+                "complete": self.is_complete(),
+            },
+            "strategies": strategies,
+        });
+
+        serde_json::to_string_pretty(&report)
+            .map_err(|e| HarnessError::RecordWrite(Box::new(e)).into())
+    }
+
+    /// Writes the report produced by [`to_json()`](Self::to_json()) to the
+    /// file at `path`, overwriting it if it exists.
+    #[cfg(feature = "serde")]
+    pub fn write_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = self.to_json()?;
+        std::fs::write(path, json).map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+
+        Ok(())
+    }
+
+    /// Produces a GitHub-flavored Markdown report of the run, suitable for
+    /// posting as a pull request comment.
+    ///
+    /// The report is a table with one row per strategy, listing the fraction
+    /// of puzzles solved, the mean number of guesses, the difference in each
+    /// from the baseline and which differences are significant (with the
+    /// `stats` feature.) Comparisons that cannot be computed, such as for a
+    /// strategy that solved no puzzles, are "n/a". With several baselines,
+    /// there is a table for each. Each strategy's histogram follows in a
+    /// collapsible section.
+    pub fn to_markdown(&self) -> Result<String> {
+        use std::fmt::Write;
+
+        let tables = self.report_tables();
+        let mut out = String::new();
+
+        for (name, rows) in &tables.comparisons {
+            if let Some(name) = name {
+                writeln!(out, "#### Compared with {}\n", name).unwrap();
+            }
+
+            writeln!(out, "{}", markdown_row(&COMPARISON_HEADER)).unwrap();
+            writeln!(out, "|---|---:|---:|---:|---:|---|").unwrap();
+            for row in rows {
+                writeln!(out, "{}", markdown_row(row)).unwrap();
+            }
+
+            writeln!(out).unwrap();
+        }
+
+        if let Some(rows) = &tables.strategies {
+            writeln!(out, "<details>\n<summary>Strategies</summary>\n").unwrap();
+            writeln!(out, "{}", markdown_row(&STRATEGY_HEADER)).unwrap();
+            writeln!(out, "|---|---|---|---|---|---|").unwrap();
+            for row in rows {
+                writeln!(out, "{}", markdown_row(row)).unwrap();
+            }
+            writeln!(out, "\n</details>\n").unwrap();
+        }
+
+        writeln!(out, "<details>\n<summary>Histograms</summary>\n").unwrap();
+        for (name, histogram) in &tables.histograms {
+            writeln!(out, "**{}**\n\n```text\n{}```\n", name, histogram).unwrap();
+        }
+        writeln!(out, "</details>").unwrap();
+
+        Ok(out)
+    }
+
+    /// Produces a standalone HTML page with the same report as
+    /// [`to_markdown()`](Self::to_markdown()), for viewing in a browser or
+    /// publishing as a build artifact.
+    pub fn to_html(&self) -> Result<String> {
+        use std::fmt::Write;
+
+        /// Writes an HTML table, right-aligning the columns in `right`.
+        fn table(out: &mut String, header: &[&str; 6], rows: &[[String; 6]], right: [bool; 6]) {
+            let align = |i: usize| if right[i] { " align=\"right\"" } else { "" };
+
+            writeln!(out, "<table>\n<thead>\n<tr>").unwrap();
+            for (i, cell) in header.iter().enumerate() {
+                writeln!(out, "<th{}>{}</th>", align(i), escape_html(cell)).unwrap();
+            }
+            writeln!(out, "</tr>\n</thead>\n<tbody>").unwrap();
+            for row in rows {
+                writeln!(out, "<tr>").unwrap();
+                for (i, cell) in row.iter().enumerate() {
+                    writeln!(out, "<td{}>{}</td>", align(i), escape_html(cell)).unwrap();
+                }
+                writeln!(out, "</tr>").unwrap();
+            }
+            writeln!(out, "</tbody>\n</table>").unwrap();
+        }
+
+        let tables = self.report_tables();
+        let mut out = String::new();
+
+        writeln!(
+            out,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>wordle_rs report</title>\n</head>\n<body>"
+        )
+        .unwrap();
+
+        for (name, rows) in &tables.comparisons {
+            if let Some(name) = name {
+                writeln!(out, "<h4>Compared with {}</h4>", escape_html(name)).unwrap();
+            }
+            table(
+                &mut out,
+                &COMPARISON_HEADER,
+                rows,
+                [false, true, true, true, true, false],
+            );
+        }
+
+        if let Some(rows) = &tables.strategies {
+            writeln!(out, "<details>\n<summary>Strategies</summary>").unwrap();
+            table(&mut out, &STRATEGY_HEADER, rows, [false; 6]);
+            writeln!(out, "</details>").unwrap();
+        }
+
+        writeln!(out, "<details>\n<summary>Histograms</summary>").unwrap();
+        for (name, histogram) in &tables.histograms {
+            writeln!(
+                out,
+                "<p><strong>{}</strong></p>\n<pre>{}</pre>",
+                escape_html(name),
+                escape_html(histogram)
+            )
+            .unwrap();
+        }
+        writeln!(out, "</details>\n</body>\n</html>").unwrap();
+
+        Ok(out)
+    }
+
+    /// Gets the cells of the tables shared by
+    /// [`to_markdown()`](Self::to_markdown()) and [`to_html()`](Self::to_html()).
+    fn report_tables(&self) -> ReportTables {
+        let baselines = self.baselines();
+
+        // Each baseline gets its own table, and without any there is one
+        // table with no comparisons.
+        let sections: Vec<Option<&(String, Summary)>> = if baselines.is_empty() {
+            vec![None]
+        } else {
+            baselines.iter().map(Some).collect()
+        };
+        let comparisons = sections
+            .into_iter()
+            .map(|section| {
+                let name = section
+                    .filter(|_| baselines.len() > 1)
+                    .map(|(name, _)| name.clone());
+                let rows = self
+                    .iter()
+                    .map(|perf| comparison_row(&perf.to_summary(), section.map(|(_, b)| b)))
+                    .collect();
+                (name, rows)
+            })
+            .collect();
+
+        let has_metadata = self.iter().any(|perf| {
+            let metadata = perf.metadata();
+            metadata.author.is_some() || metadata.description.is_some() || !metadata.tags.is_empty()
+        });
+        let strategies = has_metadata.then(|| {
+            self.iter()
+                .map(|perf| {
+                    let metadata = perf.metadata();
+                    [
+                        perf.to_string(),
+                        metadata.author.as_deref().unwrap_or("-").to_string(),
+                        metadata.description.as_deref().unwrap_or("-").to_string(),
+                        metadata.tags.join(", "),
+                        if metadata.hardmode { "hard" } else { "easy" }.to_string(),
+                        if metadata.deterministic { "yes" } else { "no" }.to_string(),
+                    ]
+                })
+                .collect()
+        });
+
+        let histograms = self
+            .iter()
+            .map(|perf| {
+                let summary = perf.to_summary();
+                (summary.to_string(), summary.histogram.to_string())
+            })
+            .collect();
+
+        ReportTables {
+            comparisons,
+            strategies,
+            histograms,
+        }
+    }
+
+    /// Writes SVG plots of the run into `dir`, returning the paths written.
+    ///
+    /// Each strategy gets a histogram of its guesses, and when baselines are
+    /// set, a chart comparing it against each baseline other than itself.
+    /// Comparisons that cannot be computed, such as when a strategy solved no
+    /// puzzles, are skipped. Files are named after the position of the
+    /// strategy in the harness, and of the baseline after the first, e.g.
+    /// "0-histogram.svg", "1-comparison.svg" and "1-comparison-2.svg".
+    #[cfg(feature = "plot")]
+    pub fn write_plots(&self, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(plot_error)?;
+
+        let baselines = self.baselines();
+        let mut paths = Vec::new();
+
+        for (i, perf) in self.iter().enumerate() {
+            let summary = perf.to_summary();
+
+            let path = dir.join(format!("{}-histogram.svg", i));
+            let svg = summary.histogram.render_svg(&summary.to_string())?;
+            std::fs::write(&path, svg).map_err(plot_error)?;
+            paths.push(path);
+
+            for (j, (_, baseline)) in baselines.iter().enumerate() {
+                if *baseline == summary {
+                    continue;
+                }
+                // Without any solved puzzles, there is nothing to plot.
+                let comparison = match summary.compare(baseline) {
+                    Ok(comparison) => comparison,
+                    Err(_) => continue,
+                };
+                let path = match j {
+                    0 => dir.join(format!("{}-comparison.svg", i)),
+                    j => dir.join(format!("{}-comparison-{}.svg", i, j + 1)),
+                };
+                let svg = comparison.render_svg()?;
+                std::fs::write(&path, svg).map_err(plot_error)?;
+                paths.push(path);
+            }
+        }
+
+        Ok(paths)
+    }
+}
+
+/// Describes the comparison of `summary` against `baseline` in the report
+/// produced by [`Record::to_json()`], which is `null` for the baseline
+/// itself and when the comparison cannot be computed, such as when a
+/// strategy solved no puzzles.
+#[cfg(feature = "serde")]
+fn comparison_json(summary: &Summary, baseline: &Summary) -> serde_json::Value {
+    use serde_json::{json, Value};
+
+    let comparison = match summary.compare(baseline) {
+        Ok(comparison) => comparison,
+        Err(_) => return Value::Null,
+    };
+
+    #[cfg(feature = "stats")]
+    let (solved_p, guesses_p, sig_solved, sig_guesses) = (
+        Some(comparison.solved_p_value()),
+        Some(comparison.guesses_p_value()),
+        Some(comparison.is_sig_solved()),
+        Some(comparison.is_sig_guesses()),
+    );
+    #[cfg(not(feature = "stats"))]
+    let (solved_p, guesses_p, sig_solved, sig_guesses): (
+        Option<f64>,
+        Option<f64>,
+        Option<bool>,
+        Option<bool>,
+    ) = (None, None, None, None);
+
+    json!({
+        "baseline": baseline.to_string(),
+        "frac_solved_diff": comparison.frac_solved_diff(),
+        "mean_guesses_diff": comparison.mean_guesses_diff(),
+        "cohens_h": comparison.cohens_h(),
+        "cohens_d": comparison.cohens_d(),
+        "odds_ratio": comparison.odds_ratio(),
+        "solved_p_value": solved_p,
+        "guesses_p_value": guesses_p,
+        "sig_solved": sig_solved,
+        "sig_guesses": sig_guesses,
+    })
+}
+
+/// The columns of the comparison tables in [`Record::to_markdown()`] and
+/// [`Record::to_html()`].
+const COMPARISON_HEADER: [&str; 6] = [
+    "Strategy",
+    "Solved",
+    "Δ solved",
+    "Mean guesses",
+    "Δ mean guesses",
+    "Significant",
+];
+
+/// The columns of the table describing the strategies in
+/// [`Record::to_markdown()`] and [`Record::to_html()`].
+const STRATEGY_HEADER: [&str; 6] = [
+    "Strategy",
+    "Author",
+    "Description",
+    "Tags",
+    "Mode",
+    "Deterministic",
+];
+
+/// The contents of the reports produced by [`Record::to_markdown()`] and
+/// [`Record::to_html()`].
+struct ReportTables {
+    // A table for each baseline, named after it when there are several.
+    comparisons: Vec<(Option<String>, Vec<[String; 6]>)>,
+    // Only present if any strategy describes itself.
+    strategies: Option<Vec<[String; 6]>>,
+    histograms: Vec<(String, String)>,
+}
+
+/// Describes `summary` in a row of a comparison table, compared against
+/// `baseline` if there is one.
+fn comparison_row(summary: &Summary, baseline: Option<&Summary>) -> [String; 6] {
+    let mean = summary
+        .mean_guesses()
+        .map_or_else(|| "-".to_string(), |m| format!("{:.2}", m));
+
+    let comparison = baseline.map(|baseline| (baseline == summary, summary.compare(baseline)));
+    let (solved_diff, mean_diff, significant) = match comparison {
+        Some((true, _)) => ("-".to_string(), "-".to_string(), "baseline".to_string()),
+        // Without any solved puzzles, there is nothing to compare.
+        Some((false, Err(_))) => ("n/a".to_string(), "n/a".to_string(), "n/a".to_string()),
+        Some((false, Ok(comparison))) => {
+            #[cfg(feature = "stats")]
+            let significant = match (comparison.is_sig_solved(), comparison.is_sig_guesses()) {
+                (true, true) => "solved, guesses",
+                (true, false) => "solved",
+                (false, true) => "guesses",
+                (false, false) => "no",
+            };
+            #[cfg(not(feature = "stats"))]
+            let significant = "n/a";
+
+            (
+                format!("{:+.1}%", comparison.frac_solved_diff() * 100.),
+                comparison
+                    .mean_guesses_diff()
+                    .map_or_else(|| "-".to_string(), |d| format!("{:+.2}", d)),
+                significant.to_string(),
+            )
+        }
+        None => ("-".to_string(), "-".to_string(), "-".to_string()),
+    };
+
+    [
+        summary.to_string(),
+        format!("{:.1}%", summary.frac_solved() * 100.),
+        solved_diff,
+        mean,
+        mean_diff,
+        significant,
+    ]
+}
+
+/// Formats `cells` as a row of a Markdown table, escaping any `|` in them so
+/// they don't split the cell.
+fn markdown_row(cells: &[impl AsRef<str>]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| cell.as_ref().replace('|', "\\|"))
+        .collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Escapes the characters of `text` that have a meaning in HTML.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        mock::{mock_words, Mock},
+        Harness,
+    };
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_report_schema() -> Result<()> {
+        let record = Harness::new()
+            .verbose(false)
+            .add_baseline(Box::new(Mock::new(None)), None)?
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .add_strategy(Box::new(Mock::new(vec!["crane"])), None)
+            .words(mock_words())
+            .run()?;
+
+        let report: serde_json::Value = serde_json::from_str(&record.to_json()?).unwrap();
+
+        assert_eq!(report["schema_version"], Record::JSON_SCHEMA_VERSION);
+        assert_eq!(report["config"]["baselines"][0]["kind"], "run");
+        assert_eq!(report["config"]["num_words"], 6);
+        assert_eq!(report["config"]["complete"], true);
+
+        let strategies = report["strategies"].as_array().unwrap();
+        assert_eq!(strategies.len(), 3);
+        assert_eq!(strategies[0]["name"], "Mock None");
+        assert_eq!(strategies[0]["version"], "1.2.4");
+        assert_eq!(strategies[0]["summary"]["num_tried"], 6);
+        assert_eq!(strategies[0]["summary"]["num_solved"], 6);
+        assert_eq!(strategies[0]["metadata"]["author"], "wordle_rs");
+        assert_eq!(strategies[0]["metadata"]["deterministic"], true);
+        assert_eq!(
+            strategies[0]["summary"]["histogram"]
+                .as_array()
+                .unwrap()
+                .len(),
+            6
+        );
+        assert!(strategies[0]["comparisons"][0].is_null());
+        assert!(strategies[1]["comparisons"][0].is_object());
+        assert_eq!(strategies[1]["summary"]["num_solved"], 2);
+
+        // A strategy that solves nothing cannot be compared under the
+        // `stats` feature, but is still in the report.
+        assert_eq!(strategies[2]["summary"]["num_solved"], 0);
+        #[cfg(feature = "stats")]
+        assert!(strategies[2]["comparisons"][0].is_null());
+
+        Ok(())
+    }
+
+    #[test]
+    fn markdown_report() -> Result<()> {
+        let record = Harness::new()
+            .verbose(false)
+            .add_baseline(Box::new(Mock::new(None)), None)?
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .add_strategy(Box::new(Mock::new(vec!["crane"])), None)
+            .words(mock_words())
+            .run()?;
+
+        let markdown = record.to_markdown()?;
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        assert!(lines[0].starts_with("| Strategy |"));
+        assert_eq!(
+            lines[2],
+            "| Mock None v1.2.4 | 100.0% | - | 3.50 | - | baseline |"
+        );
+        assert!(
+            lines[3].starts_with("| Mock Some([\"tithe\", \"doubt\"]) v1.2.4 | 33.3% | -66.7% |")
+        );
+        #[cfg(feature = "stats")]
+        assert!(lines[4].ends_with("| 0.0% | n/a | - | n/a | n/a |"));
+        assert!(markdown.contains("<summary>Strategies</summary>"));
+        assert!(markdown.contains(
+            "| Mock None v1.2.4 | wordle_rs | Guesses a fixed list of words | mock | easy | yes |"
+        ));
+        assert!(markdown.contains("<details>"));
+        assert!(markdown.trim_end().ends_with("</details>"));
+
+        let html = record.to_html()?;
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.trim_end().ends_with("</html>"));
+        assert_eq!(html.matches("<table>").count(), 2);
+        assert_eq!(html.matches("<tr>").count(), 8);
+        assert!(html.contains("<td>Mock Some([&quot;tithe&quot;, &quot;doubt&quot;]) v1.2.4</td>"));
+        assert!(html.contains("<td align=\"right\">100.0%</td>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn markdown_cells_escape_pipes() {
+        assert_eq!(markdown_row(&["a|b", "c"]), "| a\\|b | c |");
+    }
+
+    #[cfg(all(feature = "plot", feature = "stats"))]
+    #[test]
+    fn plots_skip_missing_comparisons() -> Result<()> {
+        let record = Harness::new()
+            .verbose(false)
+            .add_baseline(Box::new(Mock::new(None)), None)?
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .add_strategy(Box::new(Mock::new(vec!["crane"])), None)
+            .words(mock_words())
+            .run()?;
+
+        let dir = std::env::temp_dir().join("wordle_rs_plots");
+        let paths = record.write_plots(&dir)?;
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<_> = paths
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "0-histogram.svg",
+                "1-histogram.svg",
+                "1-comparison.svg",
+                "2-histogram.svg"
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,343 @@
+//! Generating crates for new strategies.
+//!
+//! A [`Scaffold`] describes a new crate holding a single [`Strategy`] that
+//! compiles and runs in the [test harness](crate::Harness) as soon as it is
+//! written, so writing a strategy can start with its logic instead of
+//! boilerplate. The crate has:
+//!
+//! - a `Cargo.toml` depending on this version of `wordle_rs`,
+//! - a `src/lib.rs` with a stub [`Strategy`] implementation to fill in,
+//! - a `src/main.rs` that [serves](crate::strategy::subprocess::serve()) the
+//!   strategy to a [`SubprocessWrapper`](crate::strategy::subprocess::SubprocessWrapper),
+//!   which `wordle_runner watch` uses to rerun it as it changes, and
+//! - an `examples/harness.rs` that runs the strategy against
+//!   [`Stupid`](crate::strategy::stupid::Stupid) and prints a report.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use wordle_rs::scaffold::Scaffold;
+//! let dir = Scaffold::new("my_strategy").hardmode(true).write(".")?;
+//! assert!(dir.join("Cargo.toml").exists());
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+//!
+//! [`Strategy`]: crate::Strategy
+
+use std::path::{Path, PathBuf};
+
+use crate::{HarnessError, Result};
+
+/// A new strategy crate to generate.
+///
+/// See the [module-level documentation](self) for what the crate contains.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scaffold {
+    name: String,
+    strategy: Option<String>,
+    hardmode: bool,
+}
+
+impl Scaffold {
+    /// Creates a scaffold for a crate named `name`.
+    ///
+    /// The strategy is named after the crate in `UpperCamelCase`, so the
+    /// crate `my_strategy` holds the strategy `MyStrategy`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Scaffold {
+            name: name.into(),
+            strategy: None,
+            hardmode: false,
+        }
+    }
+
+    /// Sets the name of the strategy's type.
+    pub fn strategy_name(self, strategy: impl Into<String>) -> Self {
+        Scaffold {
+            strategy: Some(strategy.into()),
+            ..self
+        }
+    }
+
+    /// Tells the strategy to play hardmode.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        Scaffold { hardmode, ..self }
+    }
+
+    /// Gets the name of the crate.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the name of the strategy's type.
+    pub fn strategy(&self) -> String {
+        match &self.strategy {
+            Some(strategy) => strategy.clone(),
+            None => camel_case(&self.name),
+        }
+    }
+
+    /// Generates the crate's files, as paths relative to the crate's root
+    /// and their contents.
+    ///
+    /// Returns an error if the crate or strategy name is not a valid Rust
+    /// identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::scaffold::Scaffold;
+    /// let files = Scaffold::new("my_strategy").files()?;
+    /// let (_, lib) = files.iter().find(|(path, _)| path.ends_with("lib.rs")).unwrap();
+    /// assert!(lib.contains("impl Strategy for MyStrategy"));
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn files(&self) -> Result<Vec<(PathBuf, String)>> {
+        if !is_crate_name(&self.name) {
+            return Err(HarnessError::InvalidName(self.name.clone()).into());
+        }
+        let strategy = self.strategy();
+        if !is_identifier(&strategy) {
+            return Err(HarnessError::InvalidName(strategy).into());
+        }
+
+        let lib = self.name.replace('-', "_");
+        let version = env!("CARGO_PKG_VERSION")
+            .rsplitn(2, '.')
+            .nth(1)
+            .unwrap_or(env!("CARGO_PKG_VERSION"));
+
+        Ok(vec![
+            (
+                PathBuf::from("Cargo.toml"),
+                format!(
+                    "[package]\n\
+                     name = \"{name}\"\n\
+                     version = \"0.1.0\"\n\
+                     edition = \"2021\"\n\
+                     \n\
+                     [dependencies]\n\
+                     wordle_rs = \"{version}\"\n",
+                    name = self.name,
+                    version = version,
+                ),
+            ),
+            (PathBuf::from(".gitignore"), "/target\n".to_string()),
+            (
+                Path::new("src").join("lib.rs"),
+                LIB_TEMPLATE
+                    .replace("{strategy}", &strategy)
+                    .replace("{lib}", &lib)
+                    .replace("{hardmode}", &self.hardmode.to_string()),
+            ),
+            (
+                Path::new("src").join("main.rs"),
+                MAIN_TEMPLATE
+                    .replace("{strategy}", &strategy)
+                    .replace("{lib}", &lib),
+            ),
+            (
+                Path::new("examples").join("harness.rs"),
+                EXAMPLE_TEMPLATE
+                    .replace("{strategy}", &strategy)
+                    .replace("{lib}", &lib),
+            ),
+        ])
+    }
+
+    /// Writes the crate to a new directory named after it inside `dir`, and
+    /// returns the path to that directory.
+    ///
+    /// Returns an error if the names are invalid, if the directory already
+    /// exists, or if the files cannot be written.
+    pub fn write(&self, dir: impl AsRef<Path>) -> Result<PathBuf> {
+        let files = self.files()?;
+        let root = dir.as_ref().join(&self.name);
+        if root.exists() {
+            return Err(HarnessError::ScaffoldExists(root).into());
+        }
+
+        for (path, contents) in files {
+            let path = root.join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| HarnessError::ScaffoldWrite(Box::new(e)))?;
+            }
+            std::fs::write(&path, contents)
+                .map_err(|e| HarnessError::ScaffoldWrite(Box::new(e)))?;
+        }
+
+        Ok(root)
+    }
+}
+
+const LIB_TEMPLATE: &str = r#"//! The {strategy} Wordle strategy.
+
+use std::fmt::Display;
+
+use wordle_rs::{Attempts, AttemptsKey, Puzzle, Strategy, Word};
+
+/// A Wordle strategy.
+#[derive(Debug, Clone, Default)]
+pub struct {strategy};
+
+impl Strategy for {strategy} {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        let mut attempts = key.unlock();
+
+        // Replace this with your strategy: check guesses until one is
+        // correct, using the grades of each to pick the next.
+        for guess in Word::ANSWERS.iter().take(6) {
+            match puzzle.check(guess, &mut attempts) {
+                Ok((_, false)) => continue,
+                Ok((_, true)) | Err(_) => break,
+            }
+        }
+
+        attempts
+    }
+
+    fn version(&self) -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    fn hardmode(&self) -> bool {
+        {hardmode}
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl Display for {strategy} {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{lib}::{strategy}")
+    }
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"//! Serves {strategy} to a parent process over stdin and stdout.
+//!
+//! See `wordle_rs::strategy::subprocess` for the protocol.
+
+use {lib}::{strategy};
+
+fn main() -> wordle_rs::Result<()> {
+    wordle_rs::strategy::subprocess::serve(&{strategy})
+}
+"#;
+
+const EXAMPLE_TEMPLATE: &str = r#"//! Runs {strategy} in the test harness and prints a report.
+//!
+//! Run this with `cargo run --release --example harness`.
+
+use wordle_rs::{strategy::stupid::Stupid, Harness, Result};
+use {lib}::{strategy};
+
+fn main() -> Result<()> {
+    let record = Harness::new()
+        .add_strategy(Box::new(Stupid), None)
+        .and_baseline()?
+        .add_strategy(Box::new({strategy}), None)
+        .test_num(100)
+        .run()?;
+
+    record.print_report()
+}
+"#;
+
+/// Converts a crate name like `my-strategy` to `MyStrategy`.
+fn camel_case(name: &str) -> String {
+    name.split(['_', '-'])
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// Checks that `name` can name a crate: an identifier, except that it may
+/// contain dashes.
+fn is_crate_name(name: &str) -> bool {
+    is_identifier(&name.replace('-', "_"))
+}
+
+/// Checks that `name` is an ASCII identifier that is not a keyword.
+fn is_identifier(name: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "_", "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait",
+        "true", "try", "type", "unsafe", "use", "where", "while",
+    ];
+
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WordleError;
+
+    #[test]
+    fn generates_crate() -> Result<()> {
+        let scaffold = Scaffold::new("my-strategy").hardmode(true);
+        assert_eq!(scaffold.strategy(), "MyStrategy");
+
+        let files = scaffold.files()?;
+        let get = |name: &str| {
+            &files
+                .iter()
+                .find(|(path, _)| path.ends_with(name))
+                .unwrap()
+                .1
+        };
+        assert!(get("Cargo.toml").contains("name = \"my-strategy\""));
+        assert!(get("lib.rs").contains("pub struct MyStrategy;"));
+        assert!(get("lib.rs").contains("write!(f, \"my_strategy::MyStrategy\")"));
+        assert!(get("lib.rs").contains("        true\n"));
+        assert!(get("main.rs").contains("serve(&MyStrategy)"));
+        assert!(get("harness.rs").contains("use my_strategy::MyStrategy;"));
+        assert!(!files
+            .iter()
+            .any(|(_, contents)| contents.contains("{strategy}")));
+
+        let dir = std::env::temp_dir().join("wordle_rs_scaffold");
+        let _ = std::fs::remove_dir_all(&dir);
+        let root = scaffold.write(&dir)?;
+        assert!(root.join("src").join("lib.rs").exists());
+        assert!(root.join("src").join("main.rs").exists());
+        assert!(root.join("examples").join("harness.rs").exists());
+        assert!(matches!(
+            scaffold.write(&dir),
+            Err(WordleError::Harness {
+                kind: HarnessError::ScaffoldExists(_)
+            })
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_names() {
+        assert!(Scaffold::new("my_strategy").files().is_ok());
+        assert!(Scaffold::new("2fast").files().is_err());
+        assert!(Scaffold::new("my strategy").files().is_err());
+        assert!(Scaffold::new("self").files().is_err());
+        assert!(Scaffold::new("fine")
+            .strategy_name("Not-Fine")
+            .files()
+            .is_err());
+    }
+}
@@ -0,0 +1,356 @@
+//! Sending results somewhere as the harness produces them.
+//!
+//! A [`ResultSink`] added with [`Harness::add_sink()`] is told about each
+//! puzzle a strategy finishes, how far along each strategy is as the run
+//! goes, and about the [`Record`] once the run is complete. Writing results
+//! out this way works the same whether the run happens in parallel, is
+//! streamed, or is checkpointed, so new destinations do not need changes to
+//! the harness.
+//!
+//! These sinks are built in:
+//!
+//! - [`JsonlSink`] writes each result to a file as a line of JSON, and
+//! - [`StoreSink`] writes each completed run into a
+//!   [`RunStore`](crate::storage::RunStore).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use wordle_rs::{sink::{ResultSink, StrategyId}, strategy::stupid::Stupid, Attempts, Harness, Word};
+//! #[derive(Debug, Default)]
+//! struct CountSolved(usize);
+//!
+//! impl ResultSink for CountSolved {
+//!     fn on_result(
+//!         &mut self,
+//!         _strategy: StrategyId<'_>,
+//!         word: Word,
+//!         attempts: &Attempts,
+//!     ) -> wordle_rs::Result<()> {
+//!         if attempts.solved(&word) {
+//!             self.0 += 1;
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let record = Harness::new()
+//!     .verbose(false)
+//!     .add_strategy(Box::new(Stupid), None)
+//!     .add_sink(Box::new(CountSolved::default()))
+//!     .test_num(10)
+//!     .run()?;
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+//!
+//! [`Harness::add_sink()`]: crate::Harness::add_sink()
+
+use std::fmt::Debug;
+#[cfg(feature = "storage")]
+use std::path::PathBuf;
+#[cfg(feature = "serde")]
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+#[cfg(feature = "serde")]
+use crate::HarnessError;
+use crate::{
+    harness::{Record, StrategyProgress},
+    strategy::{Attempts, Word},
+    Result,
+};
+
+/// Identifies the strategy that produced a result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StrategyId<'a> {
+    /// The index of the strategy in the order it was added to the harness,
+    /// which is also its index in the final [`Record`].
+    pub index: usize,
+
+    /// The full name of the strategy, including its version, as displayed by
+    /// [`Perf`](crate::Perf).
+    pub name: &'a str,
+}
+
+/// A destination for the results of a harness run.
+///
+/// Every method does nothing by default, so a sink only needs to implement
+/// the ones it uses. An error from any of them stops the run and is returned
+/// from it.
+///
+/// See the [module-level documentation](self) for more.
+pub trait ResultSink: Debug + Send {
+    /// Called each time a strategy finishes a puzzle.
+    ///
+    /// In a parallel run this may be called from different threads, but
+    /// never from two at once.
+    ///
+    /// Puzzles a strategy was disqualified on are not reported.
+    fn on_result(
+        &mut self,
+        strategy: StrategyId<'_>,
+        word: Word,
+        attempts: &Attempts,
+    ) -> Result<()> {
+        let _ = (strategy, word, attempts);
+        Ok(())
+    }
+
+    /// Called each time a strategy finishes or skips a puzzle, with how far
+    /// along each strategy is, in the order they were added to the harness.
+    ///
+    /// The throughput and estimated time remaining are the same ones shown
+    /// by the progress bar in verbose mode.
+    fn on_progress(&mut self, progress: &[StrategyProgress]) -> Result<()> {
+        let _ = progress;
+        Ok(())
+    }
+
+    /// Called once the run is complete, with its record.
+    ///
+    /// This is called before any named strategies are saved or the best
+    /// baseline is replaced, so an error from a sink leaves nothing saved,
+    /// and the record's [`RunInfo`](crate::RunInfo) does not yet say whether
+    /// the baseline was replaced. Every sink is called even if an earlier one
+    /// fails, and the first error is returned.
+    ///
+    /// This is not called for runs that stop early, like cancelled ones, or
+    /// by [`Harness::kfold()`](crate::Harness::kfold()).
+    fn on_complete(&mut self, record: &Record) -> Result<()> {
+        let _ = record;
+        Ok(())
+    }
+}
+
+/// Describes a result as a JSON object.
+#[cfg(feature = "serde")]
+pub(crate) fn result_json(
+    strategy: StrategyId<'_>,
+    word: Word,
+    attempts: &Attempts,
+) -> serde_json::Value {
+    let guesses: Vec<String> = attempts.inner().iter().map(Word::to_string).collect();
+    serde_json::json!({
+        "strategy_index": strategy.index,
+        "strategy": strategy.name,
+        "word": word.to_string(),
+        "hardmode": attempts.hard,
+        "num_guesses": guesses.len(),
+        "guesses": guesses,
+        "solved": attempts.solved(&word),
+    })
+}
+
+/// Writes each result to a file as a line of JSON, in the format of
+/// [`PuzzleResult::to_json()`](crate::harness::PuzzleResult::to_json()).
+///
+/// Each line is flushed as soon as it is written, so the file can be followed
+/// while the harness runs.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct JsonlSink {
+    file: BufWriter<File>,
+}
+
+#[cfg(feature = "serde")]
+impl JsonlSink {
+    /// Creates the file at `path`, replacing it if it exists.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::create(path).map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+        Ok(JsonlSink {
+            file: BufWriter::new(file),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ResultSink for JsonlSink {
+    fn on_result(
+        &mut self,
+        strategy: StrategyId<'_>,
+        word: Word,
+        attempts: &Attempts,
+    ) -> Result<()> {
+        serde_json::to_writer(&mut self.file, &result_json(strategy, word, attempts))
+            .map_err(|e| HarnessError::RecordWrite(Box::new(e)))?;
+        writeln!(self.file)
+            .and_then(|_| self.file.flush())
+            .map_err(|e| HarnessError::RecordWrite(Box::new(e)).into())
+    }
+}
+
+/// Writes each completed run into the [`RunStore`](crate::storage::RunStore)
+/// at a path, tagged with a label.
+///
+/// This is the sink behind [`Harness::archive()`](crate::Harness::archive()).
+#[cfg(feature = "storage")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreSink {
+    path: PathBuf,
+    label: Option<String>,
+}
+
+#[cfg(feature = "storage")]
+impl StoreSink {
+    /// Creates a sink for the store at `path`, which is created if it does
+    /// not exist when the run completes.
+    pub fn new(path: impl Into<PathBuf>, label: Option<&str>) -> Self {
+        StoreSink {
+            path: path.into(),
+            label: label.map(str::to_string),
+        }
+    }
+}
+
+#[cfg(feature = "storage")]
+impl ResultSink for StoreSink {
+    fn on_complete(&mut self, record: &Record) -> Result<()> {
+        crate::storage::RunStore::open(&self.path)?.insert(record, self.label.as_deref())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::{mock::Mock, Harness};
+
+    /// The results a [`Collect`] was told about, and how many runs it saw
+    /// complete.
+    type Collected = Arc<Mutex<(Vec<(usize, Word)>, usize)>>;
+
+    /// Shares what it is told with the test, since the harness keeps the
+    /// sink.
+    #[derive(Debug, Clone, Default)]
+    struct Collect(Collected);
+
+    impl ResultSink for Collect {
+        fn on_result(&mut self, strategy: StrategyId<'_>, word: Word, _: &Attempts) -> Result<()> {
+            self.0.lock().unwrap().0.push((strategy.index, word));
+            Ok(())
+        }
+
+        fn on_complete(&mut self, _: &Record) -> Result<()> {
+            self.0.lock().unwrap().1 += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn sinks_see_every_result() -> Result<()> {
+        let sink = Collect::default();
+        let record = Harness::new()
+            .verbose(false)
+            .parallel(true)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .add_sink(Box::new(sink.clone()))
+            .test_num(20)
+            .run()?;
+
+        let (mut results, completed) = sink.0.lock().unwrap().clone();
+        assert_eq!(completed, 1);
+        results.sort();
+        let mut expected: Vec<(usize, Word)> = record
+            .iter()
+            .enumerate()
+            .flat_map(|(i, perf)| perf.tries().iter().map(move |(word, _)| (i, *word)))
+            .collect();
+        expected.sort();
+        assert_eq!(results, expected);
+
+        Ok(())
+    }
+
+    /// Fails once the run is complete.
+    #[derive(Debug)]
+    struct FailOnComplete;
+
+    impl ResultSink for FailOnComplete {
+        fn on_complete(&mut self, _: &Record) -> Result<()> {
+            Err(crate::HarnessError::NoBaseline.into())
+        }
+    }
+
+    #[test]
+    fn failing_sink_still_tells_the_others() {
+        let sink = Collect::default();
+        let res = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_sink(Box::new(FailOnComplete))
+            .add_sink(Box::new(sink.clone()))
+            .test_num(5)
+            .run();
+
+        assert!(res.is_err());
+        assert_eq!(sink.0.lock().unwrap().1, 1);
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct Progress(Arc<Mutex<Vec<Vec<StrategyProgress>>>>);
+
+    impl ResultSink for Progress {
+        fn on_progress(&mut self, progress: &[StrategyProgress]) -> Result<()> {
+            self.0.lock().unwrap().push(progress.to_vec());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn sinks_see_progress() -> Result<()> {
+        let sink = Progress::default();
+        Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .add_sink(Box::new(sink.clone()))
+            .test_num(10)
+            .run()?;
+
+        let reports = sink.0.lock().unwrap().clone();
+        assert_eq!(reports.len(), 20);
+        for (n, report) in reports.iter().enumerate() {
+            let done: usize = report.iter().map(|p| p.done).sum();
+            assert_eq!(done, n + 1);
+            assert!(report.iter().all(|p| p.total == 10));
+        }
+        let last = reports.last().unwrap();
+        assert!(last
+            .iter()
+            .all(|p| p.done == 10 && p.remaining == Some(Duration::ZERO)));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn writes_jsonl() -> Result<()> {
+        let path = std::env::temp_dir().join("wordle_rs_sink.jsonl");
+        Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_sink(Box::new(JsonlSink::create(&path)?))
+            .test_num(5)
+            .run()?;
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(contents.lines().count(), 5);
+        for line in contents.lines() {
+            let line: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(line["strategy_index"], 0);
+            assert!(line["solved"].is_boolean());
+        }
+
+        Ok(())
+    }
+}
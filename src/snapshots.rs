@@ -0,0 +1,356 @@
+//! Snapshot tests that check a strategy still plays the same way.
+//!
+//! Refactoring the internals of a strategy, e.g. to make it faster, should
+//! not change a single guess it makes. A [`Snapshot`] plays a deterministic
+//! strategy on a fixed list of words and writes every guess to a text file
+//! the first time it runs. Each later run plays the words again and fails if
+//! any guess differs from the file, listing the words that changed.
+//!
+//! When the strategy is meant to play differently, bless the new behavior by
+//! running the tests with the [`BLESS_VAR`](Snapshot::BLESS_VAR) environment
+//! variable set, like `WORDLE_BLESS=1 cargo test`, or with
+//! [`Snapshot::bless()`]. The file is meant to be committed next to the
+//! strategy, so that the change in behavior shows up in code review.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use wordle_rs::{snapshots::Snapshot, strategy::stupid::Stupid};
+//! // In a test of the strategy
+//! Snapshot::new("tests/snapshots/stupid.txt").assert_matches(&Stupid);
+//! ```
+
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::{testkit::ScriptedPuzzle, HarnessError, Result, Strategy, Word};
+
+/// Each word played and every guess made on it.
+type Played = Vec<(Word, Vec<Word>)>;
+
+/// A file of the guesses a strategy made on a fixed list of words.
+///
+/// Each line of the file holds a word followed by every guess made on it,
+/// like `cigar: crane cigar`. Lines starting with `#` are comments.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Snapshot {
+    path: PathBuf,
+    words: Vec<Word>,
+    bless: bool,
+}
+
+impl Snapshot {
+    /// The environment variable that turns on bless mode when it is set to
+    /// anything but `0`.
+    pub const BLESS_VAR: &'static str = "WORDLE_BLESS";
+
+    /// The number of answers a snapshot covers by default.
+    pub const DEFAULT_WORDS: usize = 100;
+
+    /// Creates a snapshot kept in the file at `path`.
+    ///
+    /// # Defaults
+    ///
+    /// 1. covers the first [`DEFAULT_WORDS`](Self::DEFAULT_WORDS) answers
+    /// 2. blesses changes if [`BLESS_VAR`](Self::BLESS_VAR) is set
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let bless = std::env::var_os(Self::BLESS_VAR).map_or(false, |var| var != "0");
+        Snapshot {
+            path: path.into(),
+            words: Word::ANSWERS[..Self::DEFAULT_WORDS].to_vec(),
+            bless,
+        }
+    }
+
+    /// Sets the words the strategy plays.
+    ///
+    /// These should not change between runs, so use a fixed list rather
+    /// than a random sample.
+    pub fn words(self, words: impl IntoIterator<Item = Word>) -> Self {
+        Snapshot {
+            words: words.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Sets whether a snapshot that does not match is replaced by the new
+    /// guesses instead of failing.
+    pub fn bless(self, bless: bool) -> Self {
+        Snapshot { bless, ..self }
+    }
+
+    /// Gets the path of the snapshot file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Plays `strategy` on each word, returning every guess it made.
+    ///
+    /// Returns an error if the strategy cheated.
+    pub fn play(&self, strategy: &dyn Strategy) -> Result<Played> {
+        self.words
+            .iter()
+            .map(|word| {
+                let mut puzzle = ScriptedPuzzle::new(*word);
+                puzzle.play(strategy)?;
+                Ok((*word, puzzle.guesses()))
+            })
+            .collect()
+    }
+
+    /// Checks that `strategy` makes the same guesses as the snapshot.
+    ///
+    /// The snapshot is written if its file does not exist yet, and replaced
+    /// if it does not match and bless mode is on. Otherwise, a mismatch is
+    /// returned as [`HarnessError::SnapshotMismatch`], with the words that
+    /// changed.
+    pub fn check(&self, strategy: &dyn Strategy) -> Result<SnapshotStatus> {
+        let played = self.play(strategy)?;
+
+        let expected = match self.read()? {
+            Some(expected) => expected,
+            None => {
+                self.write(strategy, &played)?;
+                return Ok(SnapshotStatus::Created);
+            }
+        };
+
+        let changes = changes(&expected, &played);
+        if changes.is_empty() {
+            Ok(SnapshotStatus::Matched)
+        } else if self.bless {
+            self.write(strategy, &played)?;
+            Ok(SnapshotStatus::Updated)
+        } else {
+            Err(HarnessError::SnapshotMismatch {
+                strategy: strategy.to_string(),
+                path: self.path.clone(),
+                changes,
+            }
+            .into())
+        }
+    }
+
+    /// Asserts that `strategy` makes the same guesses as the snapshot.
+    ///
+    /// This is [`check()`](Self::check()) for use in tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics with every word that changed if the guesses differ, or if the
+    /// snapshot could not be played, read or written.
+    pub fn assert_matches(&self, strategy: &dyn Strategy) {
+        match self.check(strategy) {
+            Ok(_) => {}
+            Err(crate::WordleError::Harness {
+                kind: HarnessError::SnapshotMismatch { changes, .. },
+            }) => {
+                let changes: Vec<String> = changes.iter().map(ToString::to_string).collect();
+                panic!(
+                    "{} no longer plays like the snapshot {}:\n{}\nset {}=1 to update the snapshot",
+                    strategy,
+                    self.path.display(),
+                    changes.join("\n"),
+                    Self::BLESS_VAR
+                );
+            }
+            Err(e) => panic!(
+                "could not check the snapshot {}: {}",
+                self.path.display(),
+                e
+            ),
+        }
+    }
+
+    /// Reads the guesses in the snapshot file, or `None` if there is none.
+    fn read(&self) -> Result<Option<Played>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(HarnessError::SnapshotIo(Box::new(e)).into()),
+        };
+
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let invalid = || {
+                    let message = format!("invalid snapshot line \"{}\"", line);
+                    HarnessError::SnapshotIo(Box::new(io::Error::new(
+                        ErrorKind::InvalidData,
+                        message,
+                    )))
+                };
+                let (word, guesses) = line.split_once(':').ok_or_else(invalid)?;
+                let word = word.trim().parse().map_err(|_| invalid())?;
+                let guesses = guesses
+                    .split_whitespace()
+                    .map(|guess| guess.parse().map_err(|_| invalid()))
+                    .collect::<std::result::Result<_, _>>()?;
+                Ok((word, guesses))
+            })
+            .collect::<Result<_>>()
+            .map(Some)
+    }
+
+    /// Writes `played` to the snapshot file, replacing it.
+    fn write(&self, strategy: &dyn Strategy, played: &[(Word, Vec<Word>)]) -> Result<()> {
+        let mut contents = format!(
+            "# Guesses of {} v{}, updated with {}=1\n",
+            strategy,
+            strategy.version(),
+            Self::BLESS_VAR
+        );
+        for (word, guesses) in played {
+            let guesses: Vec<String> = guesses.iter().map(Word::to_string).collect();
+            contents.push_str(&format!("{}: {}\n", word, guesses.join(" ")));
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| HarnessError::SnapshotIo(Box::new(e)))?;
+        }
+        fs::write(&self.path, contents).map_err(|e| HarnessError::SnapshotIo(Box::new(e)))?;
+        Ok(())
+    }
+}
+
+/// What [`Snapshot::check()`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapshotStatus {
+    /// There was no snapshot, so one was written.
+    Created,
+
+    /// The strategy made the same guesses as the snapshot.
+    Matched,
+
+    /// The strategy made different guesses and bless mode was on, so the
+    /// snapshot was replaced.
+    Updated,
+}
+
+/// A word that a strategy played differently from its [`Snapshot`].
+///
+/// The [`Display`] implementation prints both sequences of guesses on one
+/// line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SnapshotChange {
+    /// The word.
+    pub word: Word,
+
+    /// The guesses in the snapshot, or `None` if the word is not in it.
+    pub expected: Option<Vec<Word>>,
+
+    /// The guesses the strategy made, or `None` if the word was not played.
+    pub actual: Option<Vec<Word>>,
+}
+
+impl Display for SnapshotChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let guesses = |guesses: &Option<Vec<Word>>| match guesses {
+            Some(guesses) => guesses
+                .iter()
+                .map(Word::to_string)
+                .collect::<Vec<_>>()
+                .join(" "),
+            None => "(not played)".to_string(),
+        };
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.word,
+            guesses(&self.expected),
+            guesses(&self.actual)
+        )
+    }
+}
+
+/// Finds every word that was played differently in `expected` and `actual`,
+/// in the order of `expected` and then of `actual`.
+fn changes(expected: &[(Word, Vec<Word>)], actual: &[(Word, Vec<Word>)]) -> Vec<SnapshotChange> {
+    let find = |played: &[(Word, Vec<Word>)], word: Word| {
+        played
+            .iter()
+            .find(|(other, _)| *other == word)
+            .map(|(_, guesses)| guesses.clone())
+    };
+
+    let mut changes: Vec<SnapshotChange> = expected
+        .iter()
+        .map(|(word, guesses)| SnapshotChange {
+            word: *word,
+            expected: Some(guesses.clone()),
+            actual: find(actual, *word),
+        })
+        .filter(|change| change.expected != change.actual)
+        .collect();
+    changes.extend(
+        actual
+            .iter()
+            .filter(|(word, _)| find(expected, *word).is_none())
+            .map(|(word, guesses)| SnapshotChange {
+                word: *word,
+                expected: None,
+                actual: Some(guesses.clone()),
+            }),
+    );
+    changes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, WordleError};
+
+    #[test]
+    fn snapshots() -> Result<()> {
+        let path = std::env::temp_dir().join("wordle_rs_snapshots/mock.txt");
+        let _ = fs::remove_file(&path);
+        let words = ["tithe", "doubt", "parka"].map(|w| Word::from_str(w).unwrap());
+        let snapshot = Snapshot::new(&path).words(words).bless(false);
+
+        assert_eq!(snapshot.check(&Mock::new(None))?, SnapshotStatus::Created);
+        assert_eq!(snapshot.check(&Mock::new(None))?, SnapshotStatus::Matched);
+        snapshot.assert_matches(&Mock::new(None));
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with('#'));
+        assert!(contents.contains("\ntithe: nerds tithe\n"));
+
+        let changed = Mock::new(vec!["nerds", "tithe", "parka", "doubt"]);
+        match snapshot.check(&changed) {
+            Err(WordleError::Harness {
+                kind: HarnessError::SnapshotMismatch { changes, .. },
+            }) => {
+                let changed: Vec<String> = changes.iter().map(|c| c.word.to_string()).collect();
+                assert_eq!(changed, ["doubt", "parka"]);
+                assert_eq!(
+                    changes[0].to_string(),
+                    "doubt: expected nerds tithe doubt, got nerds tithe parka doubt"
+                );
+            }
+            other => panic!("expected a mismatch, got {:?}", other),
+        }
+
+        let fewer = snapshot.clone().words([words[0]]);
+        assert!(fewer.check(&Mock::new(None)).is_err());
+
+        let blessed = snapshot.clone().bless(true);
+        assert_eq!(blessed.check(&changed)?, SnapshotStatus::Updated);
+        snapshot.assert_matches(&changed);
+
+        fs::write(&path, "tithe nerds\n").unwrap();
+        assert!(matches!(
+            snapshot.check(&changed),
+            Err(WordleError::Harness {
+                kind: HarnessError::SnapshotIo(_)
+            })
+        ));
+
+        fs::remove_file(&path).unwrap();
+        Ok(())
+    }
+}
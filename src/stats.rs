@@ -62,7 +62,7 @@ impl<N: Float + Sum> Sample<N> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct WelchsT<N: Float> {
+pub(crate) struct TTest<N: Float> {
     /// The p-value of the test, which is the probability accepting the results
     /// of the test is an error because the null hypothesis is in fact true.
     pub(crate) p: N,
@@ -74,16 +74,14 @@ pub(crate) struct WelchsT<N: Float> {
     pub(crate) tails: Tails,
 }
 
-impl<N: Float + Sum + Into<f64>> WelchsT<N> {
-    /// Runs the test on two samples.
+impl<N: Float + Sum + Into<f64>> TTest<N> {
+    /// Runs Welch's t-test on two samples, which does not assume that the
+    /// samples have equal variances.
     ///
     /// # Panics
     ///
     /// `alpha` must be in (0, 1).
-    pub(crate) fn two_sample<
-        T: IntoIterator<Item = N> + Clone,
-        V: IntoIterator<Item = N> + Clone,
-    >(
+    pub(crate) fn welch<T: IntoIterator<Item = N> + Clone, V: IntoIterator<Item = N> + Clone>(
         a: T,
         b: V,
         alpha: N,
@@ -93,12 +91,7 @@ impl<N: Float + Sum + Into<f64>> WelchsT<N> {
         // To run the t-test, we need to calculate the test statistic and then
         // compare it against the T distribution.
 
-        let a = Sample::new(a);
-        let b = Sample::new(b);
-
-        if a.mean.into().abs() < f64::EPSILON || b.mean.into().abs() < f64::EPSILON {
-            return Err(WordleError::Stats);
-        }
+        let (a, b) = Self::samples(a, b)?;
 
         // Uses equations from https://statisticaloddsandends.wordpress.com/2020/07/03/welchs-t-test-and-the-welch-satterthwaite-equation/.
 
@@ -110,11 +103,56 @@ impl<N: Float + Sum + Into<f64>> WelchsT<N> {
             / ((a.var.powi(2) / (a.len.powi(2) * (a.len - N::from(1_u32).unwrap())))
                 + (b.var.powi(2) / (b.len.powi(2) * (b.len - N::from(1_u32).unwrap()))));
 
+        Ok(Self::finish(t, deg, alpha, tails))
+    }
+
+    /// Runs Student's t-test on two samples, which pools their variances
+    /// under the assumption that they are equal.
+    ///
+    /// # Panics
+    ///
+    /// `alpha` must be in (0, 1).
+    pub(crate) fn student<T: IntoIterator<Item = N> + Clone, V: IntoIterator<Item = N> + Clone>(
+        a: T,
+        b: V,
+        alpha: N,
+        tails: Tails,
+    ) -> Result<Self> {
+        assert!(alpha > N::from(0_f32).unwrap() && alpha < N::from(1_f32).unwrap());
+
+        let (a, b) = Self::samples(a, b)?;
+        let one = N::from(1_u32).unwrap();
+
+        // Calculate degrees of freedom and the pooled variance.
+        let deg = a.len + b.len - N::from(2_u32).unwrap();
+        let pooled = ((a.len - one) * a.var + (b.len - one) * b.var) / deg;
+
+        // Calculate t-statistic.
+        let t = (a.mean - b.mean).abs() / (pooled * (one / a.len + one / b.len)).sqrt();
+
+        Ok(Self::finish(t, deg, alpha, tails))
+    }
+
+    fn samples<T: IntoIterator<Item = N> + Clone, V: IntoIterator<Item = N> + Clone>(
+        a: T,
+        b: V,
+    ) -> Result<(Sample<N>, Sample<N>)> {
+        let a = Sample::new(a);
+        let b = Sample::new(b);
+
+        if a.mean.into().abs() < f64::EPSILON || b.mean.into().abs() < f64::EPSILON {
+            return Err(WordleError::Stats);
+        }
+
+        Ok((a, b))
+    }
+
+    fn finish(t: N, deg: N, alpha: N, tails: Tails) -> Self {
         let dist = StudentsT::new(0.0, 1.0, deg.into()).unwrap();
 
         let p = N::from(dist.cdf((-t).into())).unwrap() * tails.factor::<N>();
 
-        Ok(Self { p, alpha, tails })
+        Self { p, alpha, tails }
     }
 
     #[allow(dead_code)]
@@ -156,31 +194,37 @@ mod test {
             let f_samples: Vec<f64> = (0..samples).map(|_| f_dist.sample(&mut rng)).collect();
             let s_samples: Vec<f64> = (0..samples).map(|_| s_dist.sample(&mut rng)).collect();
 
-            let internal: WelchsT<f64> = WelchsT::two_sample(f_samples.iter().cloned(), s_samples.iter().cloned(), ALPHA, Tails::Two)?;
-
-            let external: StdResult<f64, PyErr> = Python::with_gil(|py| {
-                let locals = PyDict::new(py);
-                py.run("from scipy import stats", None, Some(locals))?;
-                let stats = locals.get_item("stats").unwrap();
+            let welch: TTest<f64> = TTest::welch(f_samples.iter().cloned(), s_samples.iter().cloned(), ALPHA, Tails::Two)?;
+            let student: TTest<f64> = TTest::student(f_samples.iter().cloned(), s_samples.iter().cloned(), ALPHA, Tails::Two)?;
 
-                // let scipy = py.import("scipy")?;
-                // let stats = scipy.import("stats")?;
-
-                let f_samples = f_samples.to_object(py);
-                let s_samples = s_samples.to_object(py);
-
-                let args = (f_samples, s_samples);
-                let kwargs = [("equal_var", false)];
-                let res = stats.getattr("ttest_ind")?.call(args, Some(kwargs.into_py_dict(py)))?;
-                res.getattr("pvalue")?.extract()
-            });
-
-            let external = match external {
-                Ok(f) => f,
-                Err(e) => panic!("Python encountered error: {}", e)
-            };
+            assert!((welch.p - scipy_ttest(&f_samples, &s_samples, false)).abs() < 0.000001_f64);
+            assert!((student.p - scipy_ttest(&f_samples, &s_samples, true)).abs() < 0.000001_f64);
+        }
+    }
 
-            assert!((internal.p - external).abs() < 0.000001_f64);
+    fn scipy_ttest(f_samples: &[f64], s_samples: &[f64], equal_var: bool) -> f64 {
+        let external: StdResult<f64, PyErr> = Python::with_gil(|py| {
+            let locals = PyDict::new(py);
+            py.run("from scipy import stats", None, Some(locals))?;
+            let stats = locals.get_item("stats").unwrap();
+
+            // let scipy = py.import("scipy")?;
+            // let stats = scipy.import("stats")?;
+
+            let f_samples = f_samples.to_object(py);
+            let s_samples = s_samples.to_object(py);
+
+            let args = (f_samples, s_samples);
+            let kwargs = [("equal_var", equal_var)];
+            let res = stats
+                .getattr("ttest_ind")?
+                .call(args, Some(kwargs.into_py_dict(py)))?;
+            res.getattr("pvalue")?.extract()
+        });
+
+        match external {
+            Ok(f) => f,
+            Err(e) => panic!("Python encountered error: {}", e),
         }
     }
 }
@@ -0,0 +1,373 @@
+//! Long-term storage of harness runs in a SQLite database.
+//!
+//! A [`RunStore`] keeps every run written into it, including each strategy's
+//! attempts on every word and the time it spent solving. This makes it
+//! possible to track a strategy's performance across many versions without
+//! juggling saved summaries by hand.
+//!
+//! The test harness can write into a store automatically with
+//! [`Harness::archive()`](crate::Harness::archive()).
+
+use std::{
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::{
+    harness::Record,
+    perf::Perf,
+    strategy::{grade, Attempts, Word},
+    HarnessError, Result,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY,
+    created INTEGER NOT NULL,
+    label TEXT
+);
+CREATE TABLE IF NOT EXISTS strategies (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    version TEXT NOT NULL,
+    UNIQUE (name, version)
+);
+CREATE TABLE IF NOT EXISTS perfs (
+    id INTEGER PRIMARY KEY,
+    run INTEGER NOT NULL REFERENCES runs (id),
+    strategy INTEGER NOT NULL REFERENCES strategies (id),
+    position INTEGER NOT NULL,
+    elapsed_us INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS attempts (
+    perf INTEGER NOT NULL REFERENCES perfs (id),
+    word TEXT NOT NULL,
+    guesses TEXT NOT NULL,
+    hard INTEGER NOT NULL,
+    solved INTEGER NOT NULL
+);
+";
+
+/// A single strategy's results within a run that has been written into a
+/// [`RunStore`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StoredRun {
+    /// The id of the run in the store, to be passed to [`RunStore::load()`].
+    pub id: i64,
+
+    /// When the run was stored, as seconds since the Unix epoch.
+    pub created: u64,
+
+    /// The label the run was stored with, if any.
+    pub label: Option<String>,
+
+    /// The name of the strategy.
+    pub strategy: String,
+
+    /// The version of the strategy.
+    pub version: String,
+
+    /// The number of puzzles the strategy attempted.
+    pub num_tried: u32,
+
+    /// The number of puzzles the strategy solved.
+    pub num_solved: u32,
+
+    /// The total time the strategy spent solving puzzles.
+    pub elapsed: Duration,
+}
+
+/// An archive of harness runs backed by a SQLite database.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use wordle_rs::{storage::RunStore, Harness};
+/// # use wordle_rs::strategy::stupid::Stupid;
+/// let record = Harness::new()
+///     .add_strategy(Box::new(Stupid), None)
+///     .run()?;
+///
+/// let mut store = RunStore::open("runs.sqlite")?;
+/// store.insert(&record, Some("nightly"))?;
+///
+/// for run in store.runs_of("Stupid", Some("0.1.0"))? {
+///     println!("{}: {}/{}", run.id, run.num_solved, run.num_tried);
+/// }
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+#[derive(Debug)]
+pub struct RunStore {
+    conn: Connection,
+}
+
+impl RunStore {
+    /// Opens the store at `path`, creating the database if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_connection(Connection::open(path).map_err(storage_error)?)
+    }
+
+    /// Opens a new store that lives only in memory.
+    pub fn in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory().map_err(storage_error)?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA).map_err(storage_error)?;
+        Ok(RunStore { conn })
+    }
+
+    /// Writes every performance record in `record` into the store as a
+    /// single run, optionally tagged with a `label`.
+    ///
+    /// Returns the id of the new run.
+    pub fn insert(&mut self, record: &Record, label: Option<&str>) -> Result<i64> {
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let tx = self.conn.transaction().map_err(storage_error)?;
+
+        tx.execute(
+            "INSERT INTO runs (created, label) VALUES (?1, ?2)",
+            params![created as i64, label],
+        )
+        .map_err(storage_error)?;
+        let run = tx.last_insert_rowid();
+
+        for (position, perf) in record.iter().enumerate() {
+            let (name, version) = (perf.strategy_name(), perf.strategy_version());
+
+            tx.execute(
+                "INSERT OR IGNORE INTO strategies (name, version) VALUES (?1, ?2)",
+                params![name, version],
+            )
+            .map_err(storage_error)?;
+            let strategy: i64 = tx
+                .query_row(
+                    "SELECT id FROM strategies WHERE name = ?1 AND version = ?2",
+                    params![name, version],
+                    |row| row.get(0),
+                )
+                .map_err(storage_error)?;
+
+            tx.execute(
+                "INSERT INTO perfs (run, strategy, position, elapsed_us) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    run,
+                    strategy,
+                    position as i64,
+                    perf.elapsed().as_micros() as i64
+                ],
+            )
+            .map_err(storage_error)?;
+            let perf_id = tx.last_insert_rowid();
+
+            let mut insert = tx
+                .prepare(
+                    "INSERT INTO attempts (perf, word, guesses, hard, solved)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                )
+                .map_err(storage_error)?;
+            for (word, attempts) in perf.tries() {
+                let guesses = attempts
+                    .inner()
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect::<Vec<_>>();
+                insert
+                    .execute(params![
+                        perf_id,
+                        word.to_string(),
+                        guesses.join(","),
+                        attempts.hard,
+                        attempts.solved(word)
+                    ])
+                    .map_err(storage_error)?;
+            }
+        }
+
+        tx.commit().map_err(storage_error)?;
+
+        Ok(run)
+    }
+
+    /// Lists every strategy result in the store, oldest first.
+    pub fn runs(&self) -> Result<Vec<StoredRun>> {
+        self.query_runs("", params![])
+    }
+
+    /// Lists every result produced by the strategy called `strategy`, oldest
+    /// first.
+    ///
+    /// When `version` is provided, only results from that version of the
+    /// strategy are returned.
+    pub fn runs_of(&self, strategy: &str, version: Option<&str>) -> Result<Vec<StoredRun>> {
+        match version {
+            Some(version) => self.query_runs(
+                "WHERE strategies.name = ?1 AND strategies.version = ?2",
+                params![strategy, version],
+            ),
+            None => self.query_runs("WHERE strategies.name = ?1", params![strategy]),
+        }
+    }
+
+    /// Lists every run tagged with `label`, oldest first.
+    pub fn runs_labeled(&self, label: &str) -> Result<Vec<StoredRun>> {
+        self.query_runs("WHERE runs.label = ?1", params![label])
+    }
+
+    fn query_runs(&self, filter: &str, params: &[&dyn rusqlite::ToSql]) -> Result<Vec<StoredRun>> {
+        let sql = format!(
+            "SELECT runs.id, runs.created, runs.label, strategies.name, strategies.version,
+                    perfs.elapsed_us,
+                    (SELECT COUNT(*) FROM attempts WHERE attempts.perf = perfs.id),
+                    (SELECT COUNT(*) FROM attempts WHERE attempts.perf = perfs.id AND attempts.solved)
+             FROM perfs
+             JOIN runs ON perfs.run = runs.id
+             JOIN strategies ON perfs.strategy = strategies.id
+             {}
+             ORDER BY runs.id, perfs.position",
+            filter
+        );
+
+        let mut stmt = self.conn.prepare(&sql).map_err(storage_error)?;
+        let rows = stmt
+            .query_map(params, |row| {
+                Ok(StoredRun {
+                    id: row.get(0)?,
+                    created: row.get::<_, i64>(1)? as u64,
+                    label: row.get(2)?,
+                    strategy: row.get(3)?,
+                    version: row.get(4)?,
+                    elapsed: Duration::from_micros(row.get::<_, i64>(5)? as u64),
+                    num_tried: row.get(6)?,
+                    num_solved: row.get(7)?,
+                })
+            })
+            .map_err(storage_error)?;
+
+        rows.collect::<std::result::Result<_, _>>()
+            .map_err(storage_error)
+    }
+
+    /// Loads the performance records of every strategy in the run with the
+    /// given `id`, in the order they were run.
+    ///
+    /// Returns `None` if there is no run with that id.
+    pub fn load(&self, id: i64) -> Result<Option<Vec<Perf>>> {
+        let exists = self
+            .conn
+            .query_row("SELECT id FROM runs WHERE id = ?1", params![id], |row| {
+                row.get::<_, i64>(0)
+            })
+            .optional()
+            .map_err(storage_error)?;
+        if exists.is_none() {
+            return Ok(None);
+        }
+
+        let mut perfs_stmt = self
+            .conn
+            .prepare(
+                "SELECT perfs.id, strategies.name, strategies.version, perfs.elapsed_us
+                 FROM perfs JOIN strategies ON perfs.strategy = strategies.id
+                 WHERE perfs.run = ?1 ORDER BY perfs.position",
+            )
+            .map_err(storage_error)?;
+        let mut attempts_stmt = self
+            .conn
+            .prepare("SELECT word, guesses, hard FROM attempts WHERE perf = ?1 ORDER BY rowid")
+            .map_err(storage_error)?;
+
+        let rows = perfs_stmt
+            .query_map(params![id], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    Duration::from_micros(row.get::<_, i64>(3)? as u64),
+                ))
+            })
+            .map_err(storage_error)?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+
+        let mut perfs = Vec::with_capacity(rows.len());
+        for (perf_id, name, version, elapsed) in rows {
+            let tries = attempts_stmt
+                .query_map(params![perf_id], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, bool>(2)?,
+                    ))
+                })
+                .map_err(storage_error)?
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(storage_error)?;
+
+            let mut perf = Perf::from_parts(name, version, elapsed);
+            for (word, guesses, hard) in tries {
+                let word = Word::from_str(&word)?;
+                let mut attempts = Attempts::new(hard, false);
+                for guess in guesses.split(',').filter(|g| !g.is_empty()) {
+                    let guess = Word::from_str(guess)?;
+                    attempts.push(guess, grade(&guess, &word))?;
+                }
+                perf.tries.push((word, attempts));
+            }
+            perfs.push(perf);
+        }
+
+        Ok(Some(perfs))
+    }
+}
+
+fn storage_error(e: rusqlite::Error) -> crate::WordleError {
+    HarnessError::Storage(Box::new(e)).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, Harness};
+
+    #[test]
+    fn store_round_trip() -> Result<()> {
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(Mock::new(None)), None)
+            .add_strategy(Box::new(Mock::new(vec!["tithe", "doubt"])), None)
+            .test_num(10)
+            .run()?;
+
+        let mut store = RunStore::in_memory()?;
+        let id = store.insert(&record, Some("test"))?;
+        store.insert(&record, None)?;
+
+        let runs = store.runs()?;
+        assert_eq!(runs.len(), 4);
+        assert_eq!(runs[0].num_tried, 10);
+        assert_eq!(runs[0].label.as_deref(), Some("test"));
+
+        assert_eq!(store.runs_of("Mock None", None)?.len(), 2);
+        assert_eq!(store.runs_of("Mock None", Some("1.2.4"))?.len(), 2);
+        assert_eq!(store.runs_of("Mock None", Some("0.0.0"))?.len(), 0);
+        assert_eq!(store.runs_labeled("test")?.len(), 2);
+
+        let perfs = store.load(id)?.unwrap();
+        assert_eq!(perfs.len(), 2);
+        for (loaded, original) in perfs.iter().zip(record.iter()) {
+            assert_eq!(loaded.tries(), original.tries());
+            assert_eq!(loaded.to_summary(), original.to_summary());
+        }
+        assert!(store.load(id + 100)?.is_none());
+
+        Ok(())
+    }
+}
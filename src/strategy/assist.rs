@@ -0,0 +1,150 @@
+//! Asking a strategy for guesses one at a time.
+//!
+//! A [`Strategy`] solves a whole [`Puzzle`] in one call, which needs the
+//! answer to grade its guesses. An [`Assistant`] instead gets the grades from
+//! somewhere else, like the official daily game, so a strategy can help
+//! solve a puzzle whose answer nobody knows yet.
+//!
+//! To get each suggestion, the assistant replays the game so far to the
+//! strategy, grading the guesses it already suggested with the grades it was
+//! given, and takes the first guess past them. This only works for
+//! strategies that always make the same guesses given the same grades (see
+//! [`StrategyMetadata::deterministic`](super::StrategyMetadata::deterministic)).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use wordle_rs::strategy::{assist::Assistant, stupid::Stupid, Grade, Word};
+//! let mut assistant = Assistant::new(&Stupid);
+//!
+//! let guess = assistant.suggest()?.unwrap();
+//! assert_eq!(guess, "aahed");
+//!
+//! // Type the grades from the real game back in.
+//! assistant.feed(Grade::parse_row("ibiib")?)?;
+//! assert_eq!(assistant.suggest()?.unwrap(), "aalii");
+//! #
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{
+    strategy::{AttemptsKey, Grade, Puzzle, SolveContext, Strategy, Word},
+    HarnessError, PuzzleError, Result,
+};
+
+/// Suggests guesses from a strategy for a puzzle graded elsewhere.
+///
+/// See the [module-level documentation](self) for how it works.
+#[derive(Debug)]
+pub struct Assistant<'a> {
+    strategy: &'a dyn Strategy,
+    hardmode: bool,
+    history: Vec<(Word, [Grade; 5])>,
+    pending: Option<Word>,
+}
+
+impl<'a> Assistant<'a> {
+    /// Creates an assistant for a new puzzle.
+    ///
+    /// The strategy plays hardmode if it [says it does](Strategy::hardmode()).
+    pub fn new(strategy: &'a dyn Strategy) -> Self {
+        Assistant {
+            strategy,
+            hardmode: strategy.hardmode(),
+            history: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// Sets whether the strategy must follow the hardmode rules.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        Assistant { hardmode, ..self }
+    }
+
+    /// Gets the guesses suggested so far and the grades they were given.
+    pub fn history(&self) -> &[(Word, [Grade; 5])] {
+        &self.history
+    }
+
+    /// Returns true if the last guess was graded as correct.
+    pub fn solved(&self) -> bool {
+        matches!(self.history.last(), Some((_, grades)) if *grades == [Grade::Correct; 5])
+    }
+
+    /// Gets the strategy's next guess.
+    ///
+    /// Returns `None` once the puzzle is solved, six guesses have been made
+    /// or the strategy stops guessing. Until the guess is graded with
+    /// [`feed()`](Self::feed()), this keeps returning the same guess.
+    ///
+    /// Returns an error if the strategy panics or does not repeat the guesses
+    /// it suggested before.
+    pub fn suggest(&mut self) -> Result<Option<Word>> {
+        if self.pending.is_some() || self.solved() || self.history.len() >= 6 {
+            return Ok(self.pending);
+        }
+
+        let mut puzzle = Puzzle::new_scripted(self.history.clone());
+        let key = AttemptsKey::new(self.hardmode);
+        let strategy = self.strategy;
+        panic::catch_unwind(AssertUnwindSafe(|| {
+            strategy.solve_with_context(&mut puzzle, key, &SolveContext::new(0, 1, None))
+        }))
+        .map_err(|_| HarnessError::StrategyPanicked(strategy.to_string()))?;
+
+        let mut guesses = puzzle
+            .take_checks()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|check| !check.outcome.is_rejected())
+            .map(|check| check.guess);
+        for (suggested, _) in &self.history {
+            if guesses.next() != Some(*suggested) {
+                return Err(HarnessError::Nondeterministic(strategy.to_string()).into());
+            }
+        }
+
+        self.pending = guesses.next();
+        Ok(self.pending)
+    }
+
+    /// Grades the guess from [`suggest()`](Self::suggest()).
+    ///
+    /// Returns an error if there is no guess to grade, because the puzzle is
+    /// over or the strategy stopped guessing.
+    pub fn feed(&mut self, grades: [Grade; 5]) -> Result<()> {
+        let guess = self.suggest()?.ok_or(PuzzleError::OutOfGuesses)?;
+        self.history.push((guess, grades));
+        self.pending = None;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, strategy::grade};
+
+    #[test]
+    fn replays_grades() -> Result<()> {
+        let answer = Word::from_str("point")?;
+        let strategy = Mock::new(None);
+        let mut assistant = Assistant::new(&strategy);
+
+        let mut guesses = Vec::new();
+        while let Some(guess) = assistant.suggest()? {
+            assert_eq!(assistant.suggest()?, Some(guess));
+            guesses.push(guess);
+            assistant.feed(grade(&guess, &answer))?;
+        }
+
+        assert!(assistant.solved());
+        assert_eq!(guesses, ["nerds", "tithe", "doubt", "point"]);
+        assert!(assistant.feed([Grade::Correct; 5]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+//! Checks for the rules of hardmode.
+//!
+//! On hardmode, every guess must use the information revealed by the guesses
+//! before it: letters graded [`Correct`](Grade::Correct) must stay where they
+//! are, and letters graded [`Almost`](Grade::Almost) must appear somewhere.
+//! [`Puzzle::check()`](super::Puzzle::check()) rejects guesses that break
+//! these rules, so strategies can use [`is_valid_guess()`] to filter their
+//! candidates first.
+
+use itertools::Itertools;
+
+use super::{Grade, Word};
+
+/// Returns true if `guess` follows the hardmode rules given the previous
+/// guesses and their grades in `history`.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordle_rs::strategy::{hardmode::is_valid_guess, Grade::*, Word};
+///
+/// let history = [(
+///     Word::from_str("ratio")?,
+///     [Incorrect, Incorrect, Almost, Correct, Incorrect],
+/// )];
+///
+/// assert!(is_valid_guess(&history, &Word::from_str("unlit")?));
+/// // The "i" has to stay in the fourth position.
+/// assert!(!is_valid_guess(&history, &Word::from_str("trick")?));
+/// // The "t" has to be used.
+/// assert!(!is_valid_guess(&history, &Word::from_str("unlid")?));
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn is_valid_guess<const N: usize>(history: &[(Word<N>, [Grade; N])], guess: &Word<N>) -> bool {
+    history
+        .iter()
+        .all(|(previous, grades)| follows(previous, grades, guess))
+}
+
+/// Returns true if `guess` uses all of the information revealed by grading
+/// `previous` with `grades`.
+pub(crate) fn follows<const N: usize>(
+    previous: &Word<N>,
+    grades: &[Grade],
+    guess: &Word<N>,
+) -> bool {
+    // We need to check that `guess` incorporates all _revealed_ guesses.
+    // That means that it uses the all of the almosts and correctly uses
+    // all of the corrects.
+    let mut almost_lookup = [0_u8; 26];
+    const A_ASCII: usize = 0x61;
+    let i = |c: char| c as usize - A_ASCII;
+
+    for (prev, grade, new) in previous
+        .chars()
+        .zip(grades.iter())
+        .zip(guess.chars())
+        .map(|c| (c.0 .0, c.0 .1, c.1))
+        .sorted_unstable_by_key(|c| c.1)
+    {
+        match grade {
+            Grade::Correct => {
+                // make sure prev == new since they know where this letter goes
+                if prev != new {
+                    return false;
+                }
+            }
+            Grade::Incorrect => {}
+            Grade::Almost => {
+                // make sure that enough of this letter are in the word
+                almost_lookup[i(prev)] += 1;
+                if guess.chars().filter(|&c| c == prev).count() < almost_lookup[i(prev)] as usize {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
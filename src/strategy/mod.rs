@@ -1,36 +1,62 @@
 //! Tools for defining Wordle strategies.
 
 use std::{
+    convert::TryFrom,
     fmt::{Debug, Display},
     ops::Deref,
+    str::FromStr,
 };
 
 use itertools::Itertools;
+use rand::Rng;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    words::GUESSES,
-    {PuzzleError, Result},
+    words::{ANSWERS, GUESSES},
+    {PuzzleError, Result, WordleError},
 };
 
+pub mod assist;
+pub mod hardmode;
+#[cfg(feature = "plugin")]
+pub mod plugin;
+#[cfg(feature = "serde")]
+pub mod remote;
 pub mod stupid;
+pub mod subprocess;
 
 /// A Wordle word.
 ///
 /// This struct represents a possible Wordle guess, and its construction
 /// is validated to ensure that every instance is a possible word.
+///
+/// Words have five letters unless `N` says otherwise. Five letter words come
+/// from the built-in wordlists, and words of any other length come from a
+/// [`Wordlist`](crate::words::Wordlist) for that length.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(
-    feature = "serde",
-    derive(Serialize, Deserialize),
-    serde(crate = "serde_crate")
-)]
-pub struct Word {
-    pub(crate) index: usize,
+pub struct Word<const N: usize = 5> {
+    letters: [u8; N],
 }
 
 impl Word {
+    /// Every word that Wordle accepts as a guess, in the same order as
+    /// [`GUESSES`](crate::words::GUESSES).
+    pub const ALL: &'static [Word] = &crate::words::all_guesses();
+
+    /// Every word that Wordle may choose as an answer, in the same order as
+    /// [`ANSWERS`](crate::words::ANSWERS).
+    pub const ANSWERS: &'static [Word] = &crate::words::all_answers();
+
+    /// Creates the word at `index` in [`GUESSES`](crate::words::GUESSES),
+    /// which must be in bounds.
+    pub(crate) const fn from_guess_index(index: usize) -> Self {
+        let bytes = GUESSES[index].as_bytes();
+        Word {
+            letters: [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4]],
+        }
+    }
+
     /// Creates a new [`Word`] from an index into [`GUESSES`](crate::words::GUESSES).
     ///
     /// Returns an error if the index provided is out of bounds.
@@ -50,7 +76,7 @@ impl Word {
     /// ```
     pub fn from_index(index: usize) -> Result<Self> {
         if index < GUESSES.len() {
-            Ok(Word { index })
+            Ok(Word::from_guess_index(index))
         } else {
             Err(PuzzleError::InvalidIndex(index).into())
         }
@@ -73,29 +99,181 @@ impl Word {
     /// #
     /// # Ok::<_, wordle_rs::WordleError>(())
     /// ```
+    ///
+    /// This is the same as the [`FromStr`] implementation, which also lets
+    /// you write `"pints".parse::<Word>()`.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(word: &str) -> Result<Self> {
+        <Self as FromStr>::from_str(word)
+    }
+}
+
+impl Word {
+    /// Creates a new [`Word`] from an index into [`ANSWERS`](crate::words::ANSWERS).
+    ///
+    /// Returns an error if the index provided is out of bounds. This is
+    /// useful for restricting candidates to the words that Wordle may choose
+    /// as answers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::ops::Deref;
+    /// # use wordle_rs::{strategy::Word, words::ANSWERS};
+    /// #
+    /// let aback = Word::from_answer_index(0)?;
+    /// assert_eq!(aback.deref(), "aback");
+    /// assert!(aback.is_possible_answer());
+    ///
+    /// assert!(Word::from_answer_index(ANSWERS.len()).is_err());
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn from_answer_index(index: usize) -> Result<Self> {
+        ANSWERS
+            .get(index)
+            .map(|&index| Word::from_guess_index(index))
+            .ok_or_else(|| PuzzleError::InvalidIndex(index).into())
+    }
+
+    /// Returns true if Wordle may choose this word as an answer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::Word;
+    /// assert!(Word::from_str("cigar")?.is_possible_answer());
+    /// assert!(!Word::from_str("aahed")?.is_possible_answer());
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn is_possible_answer(&self) -> bool {
+        ANSWERS.binary_search(&self.index()).is_ok()
+    }
+
+    /// Returns the index of this word into [`GUESSES`](crate::words::GUESSES).
+    pub fn index(&self) -> usize {
         GUESSES
-            .binary_search(&word)
-            .map(|index| Word { index })
-            .map_err(|_| PuzzleError::NotInWordlist(word.to_string()).into())
+            .binary_search(&self.deref())
+            .expect("every word is in the wordlist")
     }
 }
 
-impl Deref for Word {
+impl<const N: usize> Word<N> {
+    /// Creates a word from `letters`, which must be `N` lowercase ASCII
+    /// letters.
+    pub(crate) fn from_letters(letters: &str) -> Option<Self> {
+        let letters: [u8; N] = letters.as_bytes().try_into().ok()?;
+        letters
+            .iter()
+            .all(u8::is_ascii_lowercase)
+            .then(|| Word { letters })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "serde_crate", rename = "Word")]
+struct WordIndex {
+    index: usize,
+}
+
+// Words are saved as their index into the wordlist, which only exists for
+// the built-in five letter words.
+#[cfg(feature = "serde")]
+impl Serialize for Word {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        WordIndex {
+            index: self.index(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Word {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let WordIndex { index } = WordIndex::deserialize(deserializer)?;
+        Word::from_index(index).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<const N: usize> Deref for Word<N> {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        crate::words::GUESSES[self.index]
+        std::str::from_utf8(&self.letters).expect("words are ASCII")
     }
 }
 
-impl Display for Word {
+impl<const N: usize> Display for Word<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.deref())
     }
 }
 
+impl FromStr for Word {
+    type Err = WordleError;
+
+    fn from_str(word: &str) -> Result<Self> {
+        GUESSES
+            .binary_search(&word)
+            .map(Word::from_guess_index)
+            .map_err(|_| PuzzleError::NotInWordlist(word.to_string()).into())
+    }
+}
+
+impl TryFrom<&str> for Word {
+    type Error = WordleError;
+
+    fn try_from(word: &str) -> Result<Self> {
+        word.parse()
+    }
+}
+
+impl TryFrom<usize> for Word {
+    type Error = WordleError;
+
+    fn try_from(index: usize) -> Result<Self> {
+        Word::from_index(index)
+    }
+}
+
+impl<const N: usize> AsRef<str> for Word<N> {
+    fn as_ref(&self) -> &str {
+        self.deref()
+    }
+}
+
+impl<const N: usize> PartialEq<str> for Word<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.deref() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for Word<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.deref() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<Word<N>> for str {
+    fn eq(&self, other: &Word<N>) -> bool {
+        self == other.deref()
+    }
+}
+
+impl<const N: usize> PartialEq<Word<N>> for &str {
+    fn eq(&self, other: &Word<N>) -> bool {
+        *self == other.deref()
+    }
+}
+
 /// A specific Wordle puzzle to solve.
 ///
 /// Implementers of [`Strategy`] receive an instance of this struct
@@ -106,8 +284,10 @@ impl Display for Word {
 /// When an [`Attempts`] created with the [`cheat()`](Attempts::cheat())
 /// function is passed to [`check()`](Puzzle::check()), the puzzle will
 /// become "poisoned." The [test harness](crate::Harness) checks for this
-/// and will refuse to produce performance results for a strategy that has
-/// passed such an instance to its puzzle.
+/// and disqualifies a strategy that has passed such an instance to its
+/// puzzle, leaving out its results from then on. The puzzle remembers how it
+/// was first poisoned in a [`Poisoning`], which the harness reports in
+/// [`Perf::disqualification()`](crate::Perf::disqualification()).
 ///
 /// # Examples
 ///
@@ -125,25 +305,118 @@ impl Display for Word {
 /// #
 /// # Ok::<_, wordle_rs::WordleError>(())
 /// ```
+///
+/// Puzzles of other lengths are played the same way, with words from a
+/// [`Wordlist`](crate::words::Wordlist).
+///
+/// ```rust
+/// use wordle_rs::{strategy::{Attempts, Grade::*, Puzzle}, words::Wordlist};
+///
+/// let wordlist = Wordlist::<6>::new(["planet", "plenty"], ["planet"])?;
+/// let mut puzzle = Puzzle::new(wordlist.get("planet")?);
+/// let mut attempts = Attempts::cheat(false);
+///
+/// let (grades, correct) = puzzle.check(&wordlist.get("plenty")?, &mut attempts)?;
+/// assert_eq!(grades, [Correct, Correct, Almost, Correct, Almost, Incorrect]);
+/// assert!(!correct);
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Puzzle {
-    word: Word,
-    pub(crate) poisoned: bool,
+pub struct Puzzle<const N: usize = 5> {
+    word: Word<N>,
+    pub(crate) poisoned: Option<Poisoning>,
+    checked: usize,
+    checks: Option<Vec<CheckEvent<N>>>,
+    script: Option<Vec<(Word<N>, [Grade; N])>>,
 }
 
-impl Puzzle {
+impl<const N: usize> Puzzle<N> {
     /// Creates a new puzzle from a [`Word`].
-    pub fn new(word: Word) -> Self {
+    pub fn new(word: Word<N>) -> Self {
         Puzzle {
             word,
-            poisoned: false,
+            poisoned: None,
+            checked: 0,
+            checks: None,
+            script: None,
+        }
+    }
+
+    /// Creates a new puzzle from a [`Word`] that records every call to
+    /// [`check()`](Self::check()), including the ones that fail.
+    ///
+    /// This is for debugging strategies that waste calls on guesses that
+    /// break the hardmode rules or come after their last attempt. The
+    /// [test harness](crate::Harness) uses it when told to
+    /// [record checks](crate::Harness::record_checks()).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::{Attempts, CheckOutcome, Puzzle, Word};
+    /// let mut puzzle = Puzzle::new_recording(Word::from_str("earth")?);
+    /// let mut attempts = Attempts::cheat(true);
+    ///
+    /// puzzle.check(&Word::from_str("ratio")?, &mut attempts)?;
+    /// assert!(puzzle.check(&Word::from_str("trick")?, &mut attempts).is_err());
+    ///
+    /// let checks = puzzle.checks().unwrap();
+    /// assert_eq!(checks.len(), 2);
+    /// assert_eq!(checks[1].outcome, CheckOutcome::InvalidHardmodeGuess);
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn new_recording(word: Word<N>) -> Self {
+        Puzzle {
+            checks: Some(Vec::new()),
+            ..Self::new(word)
         }
     }
 
+    /// Gets every call to [`check()`](Self::check()) made so far, in order,
+    /// if the puzzle was created with
+    /// [`new_recording()`](Self::new_recording()).
+    pub fn checks(&self) -> Option<&[CheckEvent<N>]> {
+        self.checks.as_deref()
+    }
+
+    /// Takes the calls recorded so far, leaving the puzzle recording from
+    /// scratch.
+    pub(crate) fn take_checks(&mut self) -> Option<Vec<CheckEvent<N>>> {
+        self.checks.as_mut().map(std::mem::take)
+    }
+
+    /// Consumes the puzzle and reveals its answer.
+    ///
+    /// Strategies only ever receive a mutable reference to a puzzle, so they
+    /// cannot call this function while solving it. Once a strategy is done,
+    /// whoever owns the puzzle can use this to find out what the answer was,
+    /// even if the strategy failed to solve it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::{stupid::Stupid, AttemptsKey, Puzzle, Strategy, Word};
+    /// let earth = Word::from_str("earth")?;
+    /// let mut puzzle = Puzzle::new(earth);
+    ///
+    /// let attempts = Stupid.solve(&mut puzzle, AttemptsKey::new_cheat(false));
+    /// assert!(!attempts.inner().contains(&earth));
+    ///
+    /// assert_eq!(puzzle.reveal(), earth);
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn reveal(self) -> Word<N> {
+        self.word
+    }
+
     /// Checks if a guess is correct and returns partial information.
     ///
     /// This function checks the `guess` parameter against the puzzle word
-    /// and returns a tuple containing five [`Grade`]s and a [`bool`]. The
+    /// and returns a tuple containing a [`Grade`] for each letter and a
+    /// [`bool`]. The
     /// bool denotes whether or not the guess is correct, and the grades
     /// provide information about how correct each letter in the guess is.
     /// The array grades each letter of the guess in order, so the first element
@@ -198,121 +471,635 @@ impl Puzzle {
     /// #
     /// # Ok::<_, wordle_rs::WordleError>(())
     /// ```
-    pub fn check(&mut self, guess: &Word, attempts: &mut Attempts) -> Result<([Grade; 5], bool)> {
+    pub fn check(
+        &mut self,
+        guess: &Word<N>,
+        attempts: &mut Attempts<N>,
+    ) -> Result<([Grade; N], bool)> {
         if attempts.cheat {
-            self.poisoned = true;
+            self.poison(PoisonKind::CheatAttempts);
+        }
+
+        let res = self.check_guess(guess, attempts);
+        if let Some(checks) = &mut self.checks {
+            checks.push(CheckEvent {
+                guess: *guess,
+                outcome: match &res {
+                    Ok((grades, _)) => CheckOutcome::Graded(*grades),
+                    Err(WordleError::Puzzle {
+                        kind: PuzzleError::InvalidHardmodeGuess,
+                    }) => CheckOutcome::InvalidHardmodeGuess,
+                    Err(_) => CheckOutcome::OutOfGuesses,
+                },
+            });
         }
 
+        res
+    }
+
+    fn check_guess(
+        &mut self,
+        guess: &Word<N>,
+        attempts: &mut Attempts<N>,
+    ) -> Result<([Grade; N], bool)> {
         if attempts.hard {
             for previous in attempts.inner().iter().rev() {
                 let (previous_grades, _) = self.check_inner(previous);
-                self.hardmode_guard(previous, &previous_grades, guess)?;
+                if !hardmode::follows(previous, &previous_grades, guess) {
+                    return Err(PuzzleError::InvalidHardmodeGuess.into());
+                }
             }
         }
 
-        if attempts.push(*guess).is_err() {
+        let (grades, correct) = self.check_inner(guess);
+        if attempts.push(*guess, grades).is_err() {
             return Err(PuzzleError::OutOfGuesses.into());
         }
+        self.checked += 1;
+
+        Ok((grades, correct))
+    }
+
+    /// Grades a guess without using up an attempt.
+    ///
+    /// This is for analysis tools that need to know what feedback a guess
+    /// would get, such as how much information it reveals. It is only
+    /// allowed with an [`Attempts`] made by [`Attempts::cheat()`] or
+    /// [`AttemptsKey::new_cheat()`], and like [`check()`](Puzzle::check())
+    /// with such an instance, it poisons the puzzle. Hardmode rules are not
+    /// enforced, and `attempts` is left unchanged.
+    ///
+    /// Returns an error if `attempts` is not marked as cheating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::{Attempts, Grade, Puzzle, Word};
+    /// let mut puzzle = Puzzle::new(Word::from_str("earth")?);
+    /// let attempts = Attempts::cheat(false);
+    ///
+    /// let grades = puzzle.grade_hypothetical(&Word::from_str("heart")?, &attempts)?;
+    /// assert_eq!(grades, [Grade::Almost; 5]);
+    /// assert!(attempts.inner().is_empty());
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn grade_hypothetical(
+        &mut self,
+        guess: &Word<N>,
+        attempts: &Attempts<N>,
+    ) -> Result<[Grade; N]> {
+        if !attempts.cheat {
+            return Err(PuzzleError::HypotheticalNotAllowed.into());
+        }
 
-        Ok(self.check_inner(guess))
+        self.poison(PoisonKind::HypotheticalGrade);
+        Ok(grade(guess, &self.word))
     }
 
-    fn check_inner(&self, guess: &Word) -> ([Grade; 5], bool) {
-        use std::cmp::Ordering;
+    /// Gets how the puzzle was first poisoned, if it has been.
+    pub fn poisoning(&self) -> Option<Poisoning> {
+        self.poisoned
+    }
 
-        let mut used = String::new();
-        let mut res = [Grade::Incorrect; 5];
-        let mut correct = true;
+    /// Records the first way the puzzle was poisoned.
+    fn poison(&mut self, kind: PoisonKind) {
+        if self.poisoned.is_none() {
+            self.poisoned = Some(Poisoning {
+                kind,
+                guess: self.checked + 1,
+            });
+        }
+    }
 
-        // go through correct letters first, since those get priority
-        for (i, (guess, answer)) in guess
-            .chars()
-            .zip(self.word.chars())
-            .enumerate()
-            .sorted_unstable_by(|&(a_i, (a_guess, a_answer)), &(b_i, (b_guess, b_answer))| {
-                let a_correct = a_guess == a_answer;
-                let b_correct = b_guess == b_answer;
-                match a_correct.cmp(&b_correct).reverse() {
-                    Ordering::Equal => a_i.cmp(&b_i),
-                    other => other,
-                }
-            })
-        {
-            if guess == answer {
-                used.push(guess);
-                res[i] = Grade::Correct;
+    fn check_inner(&self, guess: &Word<N>) -> ([Grade; N], bool) {
+        let res = match &self.script {
+            Some(script) => script
+                .iter()
+                .find(|(word, _)| word == guess)
+                .map_or([Grade::Correct; N], |(_, grades)| *grades),
+            None => grade(guess, &self.word),
+        };
+        (res, res == [Grade::Correct; N])
+    }
+}
+
+impl Puzzle {
+    /// Creates a recording puzzle that grades guesses from `script` instead
+    /// of against an answer.
+    ///
+    /// Guesses in the script get the grades they are listed with. Any other
+    /// guess is graded as correct, which ends a strategy's game there.
+    pub(crate) fn new_scripted(script: Vec<(Word, [Grade; 5])>) -> Self {
+        let word = script.first().map_or(Word::ALL[0], |(word, _)| *word);
+        Puzzle {
+            script: Some(script),
+            ..Self::new_recording(word)
+        }
+    }
+}
+
+/// How a [`Puzzle`] was poisoned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct Poisoning {
+    kind: PoisonKind,
+    guess: usize,
+}
+
+impl Poisoning {
+    /// Gets what poisoned the puzzle.
+    pub fn kind(&self) -> PoisonKind {
+        self.kind
+    }
+
+    /// Gets the number of the guess, starting from 1, that was being made
+    /// when the puzzle was poisoned.
+    ///
+    /// This counts every guess checked by the puzzle, whichever [`Attempts`]
+    /// it was checked with.
+    pub fn guess(&self) -> usize {
+        self.guess
+    }
+}
+
+impl Display for Poisoning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            PoisonKind::CheatAttempts => {
+                write!(f, "checked guess {} with cheating attempts", self.guess)
+            }
+            PoisonKind::HypotheticalGrade => {
+                write!(f, "graded a hypothetical guess before guess {}", self.guess)
+            }
+        }
+    }
+}
+
+/// One call to [`Puzzle::check()`] on a puzzle created with
+/// [`Puzzle::new_recording()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        crate = "serde_crate",
+        bound(
+            serialize = "Word<N>: Serialize, [Grade; N]: Serialize",
+            deserialize = "Word<N>: Deserialize<'de>, [Grade; N]: Deserialize<'de>"
+        )
+    )
+)]
+pub struct CheckEvent<const N: usize = 5> {
+    /// The guess that was checked.
+    pub guess: Word<N>,
+
+    /// What happened to the guess.
+    pub outcome: CheckOutcome<N>,
+}
+
+impl<const N: usize> Display for CheckEvent<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.outcome {
+            CheckOutcome::Graded(grades) => {
+                write!(f, "{} {}", self.guess, Grade::row_emoji(&grades))
+            }
+            CheckOutcome::InvalidHardmodeGuess => {
+                write!(f, "{} rejected: breaks the hardmode rules", self.guess)
+            }
+            CheckOutcome::OutOfGuesses => write!(f, "{} rejected: out of guesses", self.guess),
+        }
+    }
+}
+
+/// What happened to a guess passed to [`Puzzle::check()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        crate = "serde_crate",
+        bound(
+            serialize = "[Grade; N]: Serialize",
+            deserialize = "[Grade; N]: Deserialize<'de>"
+        )
+    )
+)]
+pub enum CheckOutcome<const N: usize = 5> {
+    /// The guess was graded and used up an attempt.
+    Graded([Grade; N]),
+
+    /// The guess was rejected because it breaks the hardmode rules.
+    InvalidHardmodeGuess,
+
+    /// The guess was rejected because every attempt was already used.
+    OutOfGuesses,
+}
+
+impl<const N: usize> CheckOutcome<N> {
+    /// Returns true if the guess was rejected instead of graded.
+    pub fn is_rejected(&self) -> bool {
+        !matches!(self, CheckOutcome::Graded(_))
+    }
+}
+
+/// The actions that poison a [`Puzzle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum PoisonKind {
+    /// An [`Attempts`] made with [`Attempts::cheat()`] or
+    /// [`AttemptsKey::new_cheat()`] was passed to [`Puzzle::check()`].
+    CheatAttempts,
+
+    /// [`Puzzle::grade_hypothetical()`] was called.
+    HypotheticalGrade,
+}
+
+/// A Wordle "grade" that indicates the correctness of a letter in a guess.
+///
+/// The [`Puzzle::check()`] function returns an array of these, one
+/// corresponding to each letter in the guess, to indicate how correct
+/// each letter is. `Correct` means that the letter is in the correct position.
+/// `Almost` means that the letter is in the word, but not in that position.
+/// `Incorrect` means that the word does not contain that letter.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum Grade {
+    /// A grade that indicates the letter guessed is in the correct position.
+    Correct,
+
+    /// A grade that indicates the letter guessed is in the word, but not there.
+    Almost,
+
+    /// A grade that indicates the letter guesses is not in the word.
+    Incorrect,
+}
+
+impl Grade {
+    /// Returns the colored square Wordle uses to share this grade:
+    /// 🟩 for `Correct`, 🟨 for `Almost` and ⬛ for `Incorrect`.
+    pub fn emoji(&self) -> char {
+        match self {
+            Self::Correct => '🟩',
+            Self::Almost => '🟨',
+            Self::Incorrect => '⬛',
+        }
+    }
+
+    /// Returns the letter used to write this grade as text: `c` for
+    /// `Correct`, `a` for `Almost` and `i` for `Incorrect`.
+    ///
+    /// This is also how [`Display`] formats a grade.
+    pub fn letter(&self) -> char {
+        match self {
+            Self::Correct => 'c',
+            Self::Almost => 'a',
+            Self::Incorrect => 'i',
+        }
+    }
+
+    /// Parses a single grade from a character.
+    ///
+    /// Both the letters returned by [`letter()`](Self::letter()) and the
+    /// squares returned by [`emoji()`](Self::emoji()) are accepted, as well
+    /// as the green/yellow/black letters `g`, `y` and `b` and the white
+    /// square ⬜ that light-themed share grids use for `Incorrect`. Letters
+    /// may be upper or lower case.
+    pub fn from_char(c: char) -> Option<Grade> {
+        match c.to_ascii_lowercase() {
+            'c' | 'g' | '🟩' => Some(Self::Correct),
+            'a' | 'y' | '🟨' => Some(Self::Almost),
+            'i' | 'b' | '⬛' | '⬜' => Some(Self::Incorrect),
+            _ => None,
+        }
+    }
+
+    /// Parses the grades of a whole guess, written with one character per
+    /// letter as accepted by [`from_char()`](Self::from_char()).
+    ///
+    /// Surrounding whitespace is ignored. Returns an error if the row does
+    /// not have exactly five grades.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use wordle_rs::strategy::Grade::{self, *};
+    ///
+    /// let grades = [Correct, Almost, Incorrect, Incorrect, Correct];
+    /// assert_eq!(Grade::parse_row("🟩🟨⬛⬛🟩")?, grades);
+    /// assert_eq!(Grade::parse_row("caiic")?, grades);
+    ///
+    /// assert_eq!(Grade::row_letters(&grades), "caiic");
+    /// assert_eq!(Grade::row_emoji(&grades), "🟩🟨⬛⬛🟩");
+    /// assert!(Grade::parse_row("caii").is_err());
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn parse_row(row: &str) -> Result<[Grade; 5]> {
+        let invalid = || PuzzleError::InvalidGrades(row.to_string());
+
+        let mut grades = [Grade::Incorrect; 5];
+        let mut chars = row.trim().chars();
+        for grade in grades.iter_mut() {
+            *grade = chars.next().and_then(Self::from_char).ok_or_else(invalid)?;
+        }
+        if chars.next().is_some() {
+            return Err(invalid().into());
+        }
+
+        Ok(grades)
+    }
+
+    /// Writes a row of grades with their [letters](Self::letter()), like
+    /// "caiic".
+    pub fn row_letters(row: &[Grade]) -> String {
+        row.iter().map(Grade::letter).collect()
+    }
+
+    /// Writes a row of grades with their [squares](Self::emoji()), like
+    /// "🟩🟨⬛⬛🟩".
+    pub fn row_emoji(row: &[Grade]) -> String {
+        row.iter().map(Grade::emoji).collect()
+    }
+}
+
+impl Display for Grade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+/// Grades `guess` against `answer` exactly as [`Puzzle::check()`] would.
+///
+/// Correct letters are marked first, and any remaining occurrences of a
+/// letter in the answer are then handed out as `Almost` grades from left to
+/// right.
+pub(crate) fn grade<const N: usize>(guess: &Word<N>, answer: &Word<N>) -> [Grade; N] {
+    let guess = &guess.letters;
+    let answer = &answer.letters;
+
+    let mut res = [Grade::Incorrect; N];
+    let mut remaining = [0_u8; 26];
+
+    for i in 0..N {
+        if guess[i] == answer[i] {
+            res[i] = Grade::Correct;
+        } else {
+            remaining[(answer[i] - b'a') as usize] += 1;
+        }
+    }
+
+    for i in 0..N {
+        if res[i] != Grade::Correct {
+            let letter = (guess[i] - b'a') as usize;
+            if remaining[letter] > 0 {
+                remaining[letter] -= 1;
+                res[i] = Grade::Almost;
+            }
+        }
+    }
+
+    res
+}
+
+/// Grades `guess` against every word in `answers`, writing each result to
+/// the same position in `out` as a pattern produced by [`encode_grades()`].
+///
+/// This gives the same grades as [`Puzzle::check()`], but it is much faster
+/// than grading each answer separately, so strategies can use it to split
+/// thousands of answers by the feedback a guess would get.
+///
+/// # Panics
+///
+/// Panics if `answers` and `out` have different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordle_rs::strategy::{decode_grades, grade_batch, Grade::*, Word};
+///
+/// let answers = [Word::from_str("prick")?, Word::from_str("lints")?];
+/// let mut out = [0; 2];
+/// grade_batch(&Word::from_str("crimp")?, &answers, &mut out);
+///
+/// assert_eq!(decode_grades(out[0]), Some([Almost, Correct, Correct, Incorrect, Almost]));
+/// assert_eq!(decode_grades(out[1]), Some([Incorrect, Incorrect, Almost, Incorrect, Incorrect]));
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn grade_batch(guess: &Word, answers: &[Word], out: &mut [u8]) {
+    assert_eq!(
+        answers.len(),
+        out.len(),
+        "answers and out must have the same length"
+    );
+
+    let guess = letters(guess);
+    let guess_mask = letter_mask(&guess);
+
+    for (answer, out) in answers.iter().zip(out.iter_mut()) {
+        let answer = letters(answer);
+
+        // Most answers share few letters with a guess, and those that share
+        // none are all graded incorrect.
+        if guess_mask & letter_mask(&answer) == 0 {
+            *out = 0;
+            continue;
+        }
+
+        let mut correct = 0_u8;
+        let mut remaining = [0_u8; 26];
+        for i in 0..5 {
+            if guess[i] == answer[i] {
+                correct |= 1 << i;
             } else {
-                match self.word.chars().filter(|&c| c == guess).count() {
-                    0 => correct = false,
-                    n if n >= 1 && used.chars().filter(|&c| c == guess).count() < n => {
-                        used.push(guess);
-                        res[i] = Grade::Almost;
-                        correct = false;
-                    }
-                    _ => correct = false,
+                remaining[answer[i] as usize] += 1;
+            }
+        }
+
+        let mut pattern = 0;
+        let mut place = 1;
+        for (i, &letter) in guess.iter().enumerate() {
+            if correct & (1 << i) != 0 {
+                pattern += 2 * place;
+            } else if remaining[letter as usize] > 0 {
+                remaining[letter as usize] -= 1;
+                pattern += place;
+            }
+            place *= 3;
+        }
+
+        *out = pattern;
+    }
+}
+
+/// Packs the grades of a guess into a single byte.
+///
+/// Each grade is a base 3 digit, with `Incorrect` as 0, `Almost` as 1 and
+/// `Correct` as 2, and the grade of the first letter is the least
+/// significant digit. Every pattern is less than 243, and a guess is correct
+/// exactly when its pattern is 242.
+pub fn encode_grades(grades: &[Grade; 5]) -> u8 {
+    grades.iter().rev().fold(0, |pattern, grade| {
+        pattern * 3
+            + match grade {
+                Grade::Incorrect => 0,
+                Grade::Almost => 1,
+                Grade::Correct => 2,
+            }
+    })
+}
+
+/// Unpacks grades packed by [`encode_grades()`], or returns `None` if
+/// `pattern` is not a valid pattern.
+pub fn decode_grades(mut pattern: u8) -> Option<[Grade; 5]> {
+    if pattern >= 243 {
+        return None;
+    }
+
+    let mut grades = [Grade::Incorrect; 5];
+    for grade in grades.iter_mut() {
+        *grade = match pattern % 3 {
+            0 => Grade::Incorrect,
+            1 => Grade::Almost,
+            _ => Grade::Correct,
+        };
+        pattern /= 3;
+    }
+
+    Some(grades)
+}
+
+/// Gets the letters of `word` as numbers from 0 to 25.
+fn letters(word: &Word) -> [u8; 5] {
+    word.letters.map(|byte| byte - b'a')
+}
+
+/// Gets a bitset of the letters in a word.
+fn letter_mask(letters: &[u8; 5]) -> u32 {
+    letters.iter().fold(0, |mask, &letter| mask | 1 << letter)
+}
+
+/// Grades `guess` against `answer` with a slow but obviously correct
+/// implementation of Wordle's rules.
+///
+/// This follows the rules the way a person would: first every letter in the
+/// right place is marked correct and crossed out of the answer, and then,
+/// from left to right, every other letter is marked almost if it can still
+/// be found in the answer, crossing out the letter it was found as. It is
+/// meant as a reference for checking faster graders with
+/// [`verify_grader()`], not for use in strategies.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordle_rs::strategy::{reference_grade, Grade::*, Word};
+///
+/// let grades = reference_grade(&Word::from_str("spool")?, &Word::from_str("sober")?);
+/// assert_eq!(grades, [Correct, Incorrect, Almost, Incorrect, Incorrect]);
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn reference_grade<const N: usize>(guess: &Word<N>, answer: &Word<N>) -> [Grade; N] {
+    let guess: Vec<char> = guess.chars().collect();
+    let mut answer: Vec<Option<char>> = answer.chars().map(Some).collect();
+    let mut grades = [Grade::Incorrect; N];
+
+    for i in 0..N {
+        if answer[i] == Some(guess[i]) {
+            grades[i] = Grade::Correct;
+            answer[i] = None;
+        }
+    }
+
+    for i in 0..N {
+        if grades[i] == Grade::Correct {
+            continue;
+        }
+        if let Some(found) = answer.iter().position(|&c| c == Some(guess[i])) {
+            grades[i] = Grade::Almost;
+            answer[found] = None;
+        }
+    }
+
+    grades
+}
+
+/// Checks every grader in this crate against [`reference_grade()`].
+///
+/// The graders checked are the one behind [`Puzzle::check()`] and
+/// [`grade_batch()`]. With `samples` set to
+/// `None`, every allowed guess is graded against every possible answer,
+/// which takes a while outside of release builds. Otherwise, that many
+/// random pairs of guesses and answers are checked.
+///
+/// Returns an error describing the first mismatch found.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordle_rs::strategy::verify_grader;
+///
+/// verify_grader(Some(1000))?;
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn verify_grader(samples: Option<usize>) -> Result<()> {
+    let check_pair = |guess: &Word, answer: &Word, batched: u8| -> Result<()> {
+        let expected = reference_grade(guess, answer);
+        let found = [
+            ("Puzzle::check", Some(grade(guess, answer))),
+            ("grade_batch", decode_grades(batched)),
+        ];
+
+        for (grader, grades) in found {
+            if grades != Some(expected) {
+                return Err(PuzzleError::GraderMismatch {
+                    grader,
+                    guess: *guess,
+                    answer: *answer,
                 }
+                .into());
             }
         }
-
-        (res, correct)
-    }
-
-    fn hardmode_guard(&self, previous: &Word, grades: &[Grade], guess: &Word) -> Result<()> {
-        // We need to check that `guess` incorporates all _revealed_ guesses.
-        // That means that it uses the all of the almosts and correctly uses
-        // all of the corrects.
-        let mut almost_lookup = [0_u8; 26];
-        const A_ASCII: usize = 0x61;
-        let i = |c: char| c as usize - A_ASCII;
-
-        for (prev, grade, new) in previous
-            .chars()
-            .zip(grades.iter())
-            .zip(guess.chars())
-            .map(|c| (c.0 .0, c.0 .1, c.1))
-            .sorted_unstable_by_key(|c| c.1)
-        {
-            match grade {
-                Grade::Correct => {
-                    // make sure prev == new since they know where this letter goes
-                    if prev != new {
-                        return Err(PuzzleError::InvalidHardmodeGuess.into());
-                    }
-                }
-                Grade::Incorrect => {}
-                Grade::Almost => {
-                    // make sure that enough of this letter are in the word
-                    almost_lookup[i(prev)] += 1;
-                    if guess.chars().filter(|&c| c == prev).count()
-                        < almost_lookup[i(prev)] as usize
-                    {
-                        return Err(PuzzleError::InvalidHardmodeGuess.into());
-                    }
+        Ok(())
+    };
+
+    match samples {
+        None => {
+            let mut batched = vec![0; Word::ANSWERS.len()];
+            for guess in Word::ALL {
+                grade_batch(guess, Word::ANSWERS, &mut batched);
+                for (answer, &pattern) in Word::ANSWERS.iter().zip(&batched) {
+                    check_pair(guess, answer, pattern)?;
                 }
             }
         }
-
-        Ok(())
+        Some(samples) => {
+            let mut rng = rand::thread_rng();
+            for _ in 0..samples {
+                let guess = Word::ALL[rng.gen_range(0..Word::ALL.len())];
+                let answer = Word::ANSWERS[rng.gen_range(0..Word::ANSWERS.len())];
+                let mut batched = [0];
+                grade_batch(&guess, &[answer], &mut batched);
+                check_pair(&guess, &answer, batched[0])?;
+            }
+        }
     }
-}
-
-/// A Wordle "grade" that indicates the correctness of a letter in a guess.
-///
-/// The [`Puzzle::check()`] function returns an array of five of these, one
-/// corresponding to each letter in the guess, to indicate how correct
-/// each letter is. `Correct` means that the letter is in the correct position.
-/// `Almost` means that the letter is in the word, but not in that position.
-/// `Incorrect` means that the word does not contain that letter.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
-pub enum Grade {
-    /// A grade that indicates the letter guessed is in the correct position.
-    Correct,
 
-    /// A grade that indicates the letter guessed is in the word, but not there.
-    Almost,
-
-    /// A grade that indicates the letter guesses is not in the word.
-    Incorrect,
+    Ok(())
 }
 
 /// A key provided to [`Strategy::solve()`] to produce [`Attempts`].
@@ -341,7 +1128,7 @@ impl AttemptsKey {
     }
 
     /// Use the key to produce an instance of [`Attempts`].
-    pub fn unlock(self) -> Attempts {
+    pub fn unlock<const N: usize>(self) -> Attempts<N> {
         Attempts::new(self.hard, self.cheat)
     }
 }
@@ -373,13 +1160,27 @@ impl AttemptsKey {
 /// assert!(!attempts.finished());
 /// ```
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
-pub struct Attempts {
-    inner: Vec<Word>,
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        crate = "serde_crate",
+        bound(
+            serialize = "Word<N>: Serialize, [Grade; N]: Serialize",
+            deserialize = "Word<N>: Deserialize<'de>, [Grade; N]: Deserialize<'de>"
+        )
+    )
+)]
+pub struct Attempts<const N: usize = 5> {
+    inner: Vec<Word<N>>,
+    grades: Vec<[Grade; N]>,
     pub(crate) hard: bool,
     pub(crate) cheat: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    reported: Vec<Option<usize>>,
 }
 
-impl Attempts {
+impl<const N: usize> Attempts<N> {
     /// Creates a new [`Attempts`].
     pub(crate) fn new(hard: bool, cheat: bool) -> Self {
         Attempts {
@@ -401,13 +1202,43 @@ impl Attempts {
         }
     }
 
-    /// Adds an attempt to an [`Attempts`].
+    /// Creates an [`Attempts`] from a known list of guesses, such as a saved
+    /// replay or a transcript of a real game.
+    ///
+    /// No puzzle is involved, so the guesses are not checked and their grades
+    /// are unknown: [`grades()`](Self::grades()) is empty. The instance is
+    /// always marked as cheating, so passing it to [`Puzzle::check()`]
+    /// poisons the puzzle like one made by [`cheat()`](Self::cheat()).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::{Attempts, Word};
+    /// let guesses = vec![Word::from_str("ratio")?, Word::from_str("earth")?];
+    /// let attempts = Attempts::from_words_unchecked(guesses);
+    ///
+    /// assert_eq!(attempts.len(), 2);
+    /// assert!(attempts.solved(&Word::from_str("earth")?));
+    /// assert!(attempts.grades().is_empty());
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn from_words_unchecked(words: Vec<Word<N>>) -> Self {
+        Attempts {
+            inner: words,
+            cheat: true,
+            ..Self::default()
+        }
+    }
+
+    /// Adds an attempt and the grades it received to an [`Attempts`].
     ///
     /// This will return an error if `inner` already has six elements.
     /// Otherwise, this function will succeed.
-    pub(crate) fn push(&mut self, word: Word) -> Result<usize> {
+    pub(crate) fn push(&mut self, word: Word<N>, grades: [Grade; N]) -> Result<usize> {
         if self.inner.len() < 6 {
             self.inner.push(word);
+            self.grades.push(grades);
             Ok(self.inner.len() - 1)
         } else {
             Err(PuzzleError::OutOfGuesses.into())
@@ -415,10 +1246,114 @@ impl Attempts {
     }
 
     /// Returns a slice into the underlying data.
-    pub fn inner(&self) -> &[Word] {
+    pub fn inner(&self) -> &[Word<N>] {
         self.inner.as_slice()
     }
 
+    /// Returns an iterator over the guesses, in the order they were made.
+    pub fn iter(&self) -> std::slice::Iter<'_, Word<N>> {
+        self.inner.iter()
+    }
+
+    /// Returns the number of guesses made.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if no guesses have been made.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the grades that each attempt received.
+    ///
+    /// The grades are in the same order as the words returned by
+    /// [`inner()`](Self::inner()), so the `n`th element holds the grades
+    /// [`Puzzle::check()`] returned for the `n`th guess.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::{Attempts, Puzzle, Word, Grade::*};
+    /// let mut puzzle = Puzzle::new(Word::from_str("earth")?);
+    /// let mut attempts = Attempts::cheat(false);
+    ///
+    /// let _ = puzzle.check(&Word::from_str("ratio")?, &mut attempts)?;
+    /// assert_eq!(attempts.grades(), &[[Almost, Correct, Almost, Incorrect, Incorrect]]);
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn grades(&self) -> &[[Grade; N]] {
+        self.grades.as_slice()
+    }
+
+    /// Records how many answers the strategy believes are still possible
+    /// after its most recent guess.
+    ///
+    /// Strategies that track the possible answers can call this after each
+    /// guess so that the harness can report how quickly they narrow down the
+    /// answer, as seen by the strategy itself. Calling it again before the
+    /// next guess replaces the earlier count, and calling it before the first
+    /// guess does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::{Attempts, Puzzle, Word};
+    /// let mut puzzle = Puzzle::new(Word::from_str("earth")?);
+    /// let mut attempts = Attempts::cheat(false);
+    ///
+    /// let _ = puzzle.check(&Word::from_str("ratio")?, &mut attempts)?;
+    /// let _ = puzzle.check(&Word::from_str("heart")?, &mut attempts)?;
+    /// attempts.report_candidates(1);
+    /// assert_eq!(attempts.reported_candidates(), &[None, Some(1)]);
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn report_candidates(&mut self, remaining: usize) {
+        let guesses = self.inner.len();
+        if guesses == 0 {
+            return;
+        }
+
+        if self.reported.len() < guesses {
+            self.reported.resize(guesses, None);
+        }
+        self.reported[guesses - 1] = Some(remaining);
+    }
+
+    /// Returns the number of possible answers the strategy reported after
+    /// each guess with [`report_candidates()`](Self::report_candidates()).
+    ///
+    /// The `n`th element belongs to the `n`th guess and is `None` if nothing
+    /// was reported for it. Guesses after the last report are left out.
+    pub fn reported_candidates(&self) -> &[Option<usize>] {
+        self.reported.as_slice()
+    }
+
+    /// Returns the familiar Wordle share grid for these attempts, with one
+    /// line of colored squares per guess.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use wordle_rs::strategy::{Attempts, Puzzle, Word};
+    /// let mut puzzle = Puzzle::new(Word::from_str("earth")?);
+    /// let mut attempts = Attempts::cheat(false);
+    ///
+    /// let _ = puzzle.check(&Word::from_str("ratio")?, &mut attempts)?;
+    /// let _ = puzzle.check(&Word::from_str("earth")?, &mut attempts)?;
+    /// assert_eq!(attempts.share_grid(), "🟨🟩🟨⬛⬛\n🟩🟩🟩🟩🟩");
+    /// #
+    /// # Ok::<_, wordle_rs::WordleError>(())
+    /// ```
+    pub fn share_grid(&self) -> String {
+        self.grades
+            .iter()
+            .map(|grades| Grade::row_emoji(grades))
+            .join("\n")
+    }
+
     /// Returns true if this instance is full and false otherwise.
     ///
     /// An instance of [`Attempts`] is full when it has been used for six
@@ -428,12 +1363,24 @@ impl Attempts {
     }
 
     /// Returns true if the last word in this attempt list matches `word`.
-    pub(crate) fn solved(&self, word: &Word) -> bool {
+    ///
+    /// Together with [`Puzzle::reveal()`], this tells you whether a strategy
+    /// solved a puzzle.
+    pub fn solved(&self, word: &Word<N>) -> bool {
         matches!(self.inner().last(), Some(s) if s == word)
     }
 }
 
-impl Display for Attempts {
+impl<'a, const N: usize> IntoIterator for &'a Attempts<N> {
+    type Item = &'a Word<N>;
+    type IntoIter = std::slice::Iter<'a, Word<N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> Display for Attempts<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some((last, rest)) = self.inner.split_last() {
             for word in rest {
@@ -448,6 +1395,8 @@ impl Display for Attempts {
 /// Trait defining a Wordle strategy.
 ///
 /// To write a strategy, define a new struct and implement this trait on it.
+/// Strategies must be [`Send`] and [`Sync`], since the harness may solve
+/// puzzles on several threads and run on a background thread.
 ///
 /// # How to implement
 ///
@@ -541,7 +1490,7 @@ impl Display for Attempts {
 ///     }
 /// }
 /// ```
-pub trait Strategy: Display + Debug + Sync {
+pub trait Strategy: Display + Debug + Send + Sync {
     /// Tries to solve the given [`Puzzle`] and returns a list of attempts.
     ///
     /// This is the main function to implement in this trait. The list of
@@ -550,6 +1499,22 @@ pub trait Strategy: Display + Debug + Sync {
     /// [`AttemptsKey::unlock()`].
     fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts;
 
+    /// Tries to solve the given [`Puzzle`] with extra information about it.
+    ///
+    /// The test harness calls this function rather than
+    /// [`solve()`](Strategy::solve()). Override it instead of `solve()` to
+    /// use the [`SolveContext`], e.g. to guess only from its wordlist. The
+    /// default implementation ignores `ctx` and calls `solve()`.
+    fn solve_with_context(
+        &self,
+        puzzle: &mut Puzzle,
+        key: AttemptsKey,
+        ctx: &SolveContext,
+    ) -> Attempts {
+        let _ = ctx;
+        self.solve(puzzle, key)
+    }
+
     /// Provides a version for this strategy.
     ///
     /// You should ensure that this changes each time you update the logic of
@@ -565,12 +1530,334 @@ pub trait Strategy: Display + Debug + Sync {
     /// The value of this function should not change for a particular instance
     /// of the strategy after it is configured.
     fn hardmode(&self) -> bool;
+
+    /// Describes where this strategy came from and how it behaves.
+    ///
+    /// The metadata is recorded alongside the results of each run and shown
+    /// in reports, so that results from strategies written by different
+    /// people can be told apart. The default implementation provides no
+    /// author, description or tags, takes hardmode from
+    /// [`hardmode()`](Strategy::hardmode()) and marks the strategy as
+    /// deterministic.
+    fn metadata(&self) -> StrategyMetadata {
+        StrategyMetadata {
+            hardmode: self.hardmode(),
+            ..StrategyMetadata::new()
+        }
+    }
+
+    /// Prepares the strategy for a run of the test harness.
+    ///
+    /// The harness calls this once on each strategy before every run,
+    /// before any puzzles are solved. Use it to build expensive lookup tables
+    /// from the wordlists in `ctx` rather than computing them in every call
+    /// to [`solve()`](Strategy::solve()). The default implementation does
+    /// nothing.
+    fn prepare(&mut self, ctx: &RunContext) {
+        let _ = ctx;
+    }
+
+    /// Duplicates this strategy behind a new box, if it supports cloning.
+    ///
+    /// This lets a configured strategy be copied for another thread or run.
+    /// The default implementation returns `None`. Strategies that implement
+    /// [`Clone`] should override it:
+    ///
+    /// ```rust,ignore
+    /// fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+    ///     Some(Box::new(self.clone()))
+    /// }
+    /// ```
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        None
+    }
+}
+
+/// Boxed strategies are strategies too, passing every call through to the
+/// strategy inside, so wrapping a strategy preserves its name, version and
+/// [`metadata()`](Strategy::metadata()).
+impl<S: Strategy + ?Sized> Strategy for Box<S> {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        (**self).solve(puzzle, key)
+    }
+
+    fn solve_with_context(
+        &self,
+        puzzle: &mut Puzzle,
+        key: AttemptsKey,
+        ctx: &SolveContext,
+    ) -> Attempts {
+        (**self).solve_with_context(puzzle, key, ctx)
+    }
+
+    fn version(&self) -> &'static str {
+        (**self).version()
+    }
+
+    fn hardmode(&self) -> bool {
+        (**self).hardmode()
+    }
+
+    fn prepare(&mut self, ctx: &RunContext) {
+        (**self).prepare(ctx)
+    }
+
+    fn metadata(&self) -> StrategyMetadata {
+        (**self).metadata()
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        (**self).boxed_clone()
+    }
+}
+
+/// Information about an upcoming run of the test harness, passed to
+/// [`Strategy::prepare()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RunContext<'a> {
+    guesses: &'a [Word],
+    answers: &'a [Word],
+    num_puzzles: usize,
+    parallel: bool,
+}
+
+impl<'a> RunContext<'a> {
+    /// Creates a context for a run on `num_puzzles` puzzles using the
+    /// built-in wordlists.
+    pub(crate) fn new(num_puzzles: usize, parallel: bool) -> Self {
+        RunContext {
+            guesses: Word::ALL,
+            answers: Word::ANSWERS,
+            num_puzzles,
+            parallel,
+        }
+    }
+
+    /// Gets every word that may be guessed during the run.
+    pub fn guesses(&self) -> &'a [Word] {
+        self.guesses
+    }
+
+    /// Gets every word that may be an answer during the run.
+    ///
+    /// The answers to the puzzles in the run are drawn from this list, but
+    /// which ones are chosen is not revealed.
+    pub fn answers(&self) -> &'a [Word] {
+        self.answers
+    }
+
+    /// Gets the number of puzzles the strategy will be asked to solve.
+    pub fn num_puzzles(&self) -> usize {
+        self.num_puzzles
+    }
+
+    /// Returns true if the harness will call [`Strategy::solve()`] from
+    /// several threads at once.
+    pub fn parallel(&self) -> bool {
+        self.parallel
+    }
+}
+
+/// Information about a single puzzle, passed to
+/// [`Strategy::solve_with_context()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SolveContext<'a> {
+    index: usize,
+    total: usize,
+    day: Option<u32>,
+    guesses: &'a [Word],
+}
+
+impl<'a> SolveContext<'a> {
+    /// The number of guesses a strategy may make on each puzzle.
+    pub const MAX_GUESSES: usize = 6;
+
+    /// Creates a context for the puzzle at `index` out of `total`, using the
+    /// built-in wordlists.
+    pub(crate) fn new(index: usize, total: usize, day: Option<u32>) -> Self {
+        SolveContext {
+            index,
+            total,
+            day,
+            guesses: Word::ALL,
+        }
+    }
+
+    /// Gets the position of this puzzle in the run, starting from 0.
+    ///
+    /// When the harness runs in parallel, puzzles may be solved out of order.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the total number of puzzles in the run.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Gets the day number of this puzzle, if it is a past daily puzzle.
+    ///
+    /// See [`words::daily`](crate::words::daily).
+    pub fn day(&self) -> Option<u32> {
+        self.day
+    }
+
+    /// Gets every word that may be guessed on this puzzle.
+    pub fn guesses(&self) -> &'a [Word] {
+        self.guesses
+    }
+
+    /// Gets the number of guesses allowed on this puzzle.
+    pub fn max_guesses(&self) -> usize {
+        Self::MAX_GUESSES
+    }
+}
+
+/// Information about a [`Strategy`] that is not needed to run it.
+///
+/// See [`Strategy::metadata()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use wordle_rs::strategy::StrategyMetadata;
+///
+/// let metadata = StrategyMetadata {
+///     author: Some("cgm616".to_string()),
+///     description: Some("Always guesses the first word in the list".to_string()),
+///     tags: vec!["example".to_string()],
+///     ..StrategyMetadata::new()
+/// };
+///
+/// assert!(metadata.deterministic);
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", default)
+)]
+pub struct StrategyMetadata {
+    /// The person or group who wrote the strategy.
+    pub author: Option<String>,
+
+    /// A short description of how the strategy works.
+    pub description: Option<String>,
+
+    /// Free-form tags used to group strategies, like `"entropy"`.
+    pub tags: Vec<String>,
+
+    /// Whether the strategy plays on hardmode.
+    pub hardmode: bool,
+
+    /// Whether the strategy always makes the same guesses for the same answer.
+    pub deterministic: bool,
+}
+
+impl StrategyMetadata {
+    /// Creates new metadata with no author, description or tags, for an
+    /// easymode, deterministic strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for StrategyMetadata {
+    fn default() -> Self {
+        StrategyMetadata {
+            author: None,
+            description: None,
+            tags: Vec::new(),
+            hardmode: false,
+            deterministic: true,
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{words::GUESSES, WordleError};
+    use crate::words::GUESSES;
+
+    #[test]
+    fn batch_grading() -> Result<()> {
+        let mut out = vec![0; Word::ANSWERS.len()];
+        for guess in ["crimp", "error", "llama", "fuzzy", "eerie"] {
+            let guess = Word::from_str(guess)?;
+            grade_batch(&guess, Word::ANSWERS, &mut out);
+
+            for (answer, &pattern) in Word::ANSWERS.iter().zip(out.iter()) {
+                let grades = grade(&guess, answer);
+                assert_eq!(pattern, encode_grades(&grades));
+                assert_eq!(decode_grades(pattern), Some(grades));
+            }
+        }
+
+        assert_eq!(encode_grades(&[Grade::Correct; 5]), 242);
+        assert_eq!(decode_grades(243), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hypothetical_grades() -> Result<()> {
+        let guess = Word::from_str("crimp")?;
+        let mut puzzle = Puzzle::new(Word::from_str("prick")?);
+
+        let attempts = AttemptsKey::new(false).unlock();
+        assert!(puzzle.grade_hypothetical(&guess, &attempts).is_err());
+        assert!(puzzle.poisoning().is_none());
+
+        let mut attempts = Attempts::cheat(false);
+        let hypothetical = puzzle.grade_hypothetical(&guess, &attempts)?;
+        assert!(attempts.inner().is_empty());
+        let poisoning = puzzle.poisoning().unwrap();
+        assert_eq!(poisoning.kind(), PoisonKind::HypotheticalGrade);
+        assert_eq!(poisoning.guess(), 1);
+
+        let (grades, _) = puzzle.check(&guess, &mut attempts)?;
+        assert_eq!(hypothetical, grades);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reference_grader() -> Result<()> {
+        let grades = |guess, answer| -> Result<[Grade; 5]> {
+            Ok(reference_grade(
+                &Word::from_str(guess)?,
+                &Word::from_str(answer)?,
+            ))
+        };
+        assert_eq!(grades("odors", "spoon")?, Grade::parse_row("aicia")?);
+        assert_eq!(grades("pines", "tills")?, Grade::parse_row("iciic")?);
+        assert_eq!(grades("alloy", "spill")?, Grade::parse_row("iaaii")?);
+        assert_eq!(grades("crimp", "crimp")?, Grade::parse_row("ccccc")?);
+
+        verify_grader(Some(20_000))
+    }
+
+    #[test]
+    fn boxed_strategies() -> Result<()> {
+        let strategy: Box<dyn Strategy> = Box::new(crate::mock::Mock::new(vec!["tithe"]));
+        let boxed = Box::new(strategy);
+
+        assert_eq!(boxed.to_string(), "Mock Some([\"tithe\"])");
+        assert_eq!(boxed.version(), "1.2.4");
+        assert_eq!(boxed.metadata().author.as_deref(), Some("wordle_rs"));
+
+        let clone = boxed.boxed_clone().unwrap();
+        assert_eq!(clone.to_string(), boxed.to_string());
+        assert_eq!(clone.metadata(), boxed.metadata());
+
+        let word = Word::from_str("tithe")?;
+        let attempts = clone.solve(&mut Puzzle::new(word), AttemptsKey::new(false));
+        assert!(attempts.solved(&word));
+
+        assert!(stupid::Stupid.boxed_clone().is_some());
+
+        Ok(())
+    }
 
     #[test]
     fn word_from_index() -> Result<()> {
@@ -594,6 +1881,94 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn word_answers() -> Result<()> {
+        for (i, &index) in ANSWERS.iter().enumerate().step_by(97) {
+            let word = Word::from_answer_index(i)?;
+            assert_eq!(word, Word::from_index(index)?);
+            assert!(word.is_possible_answer());
+        }
+        Word::from_answer_index(ANSWERS.len()).unwrap_err();
+
+        assert!(!Word::from_str("aahed")?.is_possible_answer());
+        assert!(Word::from_str("crimp")?.is_possible_answer());
+
+        Ok(())
+    }
+
+    #[test]
+    fn word_conversions() -> Result<()> {
+        let tithe: Word = "tithe".parse()?;
+        assert_eq!(tithe, Word::from_str("tithe")?);
+        assert_eq!(tithe, Word::try_from("tithe")?);
+        assert_eq!(tithe, Word::try_from(tithe.index())?);
+        assert_eq!(tithe.as_ref(), "tithe");
+        assert_eq!(tithe, "tithe");
+        assert_eq!("tithe", tithe);
+        assert_ne!(tithe, "doubt");
+
+        "tlamp".parse::<Word>().unwrap_err();
+        Word::try_from(GUESSES.len()).unwrap_err();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn word_serde() -> Result<()> {
+        let tithe = Word::from_str("tithe")?;
+        let json = serde_json::to_string(&tithe).unwrap();
+        assert_eq!(json, format!("{{\"index\":{}}}", tithe.index()));
+        assert_eq!(serde_json::from_str::<Word>(&json).unwrap(), tithe);
+
+        let out_of_range = format!("{{\"index\":{}}}", GUESSES.len());
+        assert!(serde_json::from_str::<Word>(&out_of_range).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_lengths() -> Result<()> {
+        let wordlist =
+            crate::words::Wordlist::<7>::new(["brewing", "cabbage", "gabbing"], ["beaming"])?;
+        let word = |w| wordlist.get(w);
+
+        for (guess, answer, row) in [
+            ("cabbage", "gabbing", "iccciai"),
+            ("gabbing", "beaming", "iaaiccc"),
+            ("beaming", "beaming", "ccccccc"),
+        ] {
+            let expected: Vec<Grade> = row.chars().map(|c| Grade::from_char(c).unwrap()).collect();
+            let grades = reference_grade(&word(guess)?, &word(answer)?);
+            assert_eq!(grades.to_vec(), expected);
+            assert_eq!(grade(&word(guess)?, &word(answer)?), grades);
+        }
+
+        let mut puzzle = Puzzle::new_recording(word("gabbing")?);
+        let mut attempts = Attempts::cheat(true);
+        let (grades, correct) = puzzle.check(&word("cabbage")?, &mut attempts)?;
+        assert!(!correct);
+        assert_eq!(Grade::row_letters(&grades), "iccciai");
+
+        // Hardmode needs the correct letters kept in place.
+        let history: Vec<_> = attempts
+            .iter()
+            .copied()
+            .zip(attempts.grades().iter().copied())
+            .collect();
+        assert!(!hardmode::is_valid_guess(&history, &word("brewing")?));
+        assert!(puzzle.check(&word("brewing")?, &mut attempts).is_err());
+        assert!(puzzle.check(&word("gabbing")?, &mut attempts)?.1);
+        assert!(attempts.solved(&word("gabbing")?));
+        assert_eq!(attempts.share_grid().lines().count(), 2);
+        assert_eq!(
+            puzzle.checks().unwrap()[1].outcome,
+            CheckOutcome::InvalidHardmodeGuess
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn fmt_word() {
         assert_eq!("tithe", format!("{}", Word::from_str("tithe").unwrap()));
@@ -605,27 +1980,51 @@ mod test {
     #[test]
     fn puzzle_poisoning() -> Result<()> {
         let mut puzzle = Puzzle::new(Word::from_str("nerds")?);
-        assert!(!puzzle.poisoned);
+        assert!(puzzle.poisoning().is_none());
 
         let safe_key = AttemptsKey::new(false);
         let mut safe_attempts = safe_key.unlock();
 
         let (safe_grades, safe_correct) =
             puzzle.check(&Word::from_str("doubt")?, &mut safe_attempts)?;
-        assert!(!puzzle.poisoned);
+        assert!(puzzle.poisoning().is_none());
 
         let cheat_key = AttemptsKey::new_cheat(false);
         let mut cheat_attempts = cheat_key.unlock();
 
         let (cheat_grades, cheat_correct) =
             puzzle.check(&Word::from_str("doubt")?, &mut cheat_attempts)?;
-        assert!(puzzle.poisoned);
+        let poisoning = puzzle.poisoning().unwrap();
+        assert_eq!(poisoning.kind(), PoisonKind::CheatAttempts);
+        assert_eq!(poisoning.guess(), 2);
 
         assert_eq!(safe_grades, cheat_grades);
         assert_eq!(safe_correct, cheat_correct);
+        assert_eq!(safe_attempts.grades(), &[safe_grades]);
+        assert_eq!(cheat_attempts.grades(), &[cheat_grades]);
 
         let _ = puzzle.check(&Word::from_str("gorge")?, &mut safe_attempts)?;
-        assert!(puzzle.poisoned);
+        let _ = puzzle.grade_hypothetical(&Word::from_str("gorge")?, &cheat_attempts)?;
+        assert_eq!(puzzle.poisoning(), Some(poisoning));
+
+        Ok(())
+    }
+
+    #[test]
+    fn attempts_from_words() -> Result<()> {
+        let (ratio, earth) = (Word::from_str("ratio")?, Word::from_str("earth")?);
+        let attempts = Attempts::from_words_unchecked(vec![ratio, earth]);
+
+        assert_eq!(attempts.len(), 2);
+        assert!(!attempts.is_empty());
+        assert_eq!(attempts.iter().copied().collect::<Vec<_>>(), [ratio, earth]);
+        assert_eq!((&attempts).into_iter().count(), 2);
+        assert!(attempts.solved(&earth));
+
+        let mut attempts = attempts;
+        let mut puzzle = Puzzle::new(earth);
+        puzzle.check(&earth, &mut attempts)?;
+        assert!(puzzle.poisoning().is_some());
 
         Ok(())
     }
@@ -660,18 +2059,6 @@ mod test {
 
     // PUZZLE LOGIC TESTS
 
-    fn str_to_grades(input: &str) -> [Grade; 5] {
-        let mut res = [Grade::Incorrect; 5];
-        for (i, c) in input.chars().enumerate() {
-            match c {
-                'c' => res[i] = Grade::Correct,
-                'a' => res[i] = Grade::Almost,
-                _ => {}
-            }
-        }
-        res
-    }
-
     macro_rules! puzzle_test {
         (I $answer:expr; $puzzle:ident, $attempts:ident, $count:ident; $guess:expr, $works:expr, $res:expr) => {{
             if $works {
@@ -681,7 +2068,8 @@ mod test {
                 $count += 1;
                 assert_eq!($attempts.inner().len(), $count);
                 assert_eq!(correct, $answer == $guess);
-                assert_eq!(grades, str_to_grades($res));
+                assert_eq!(grades, Grade::parse_row($res)?);
+                assert_eq!($attempts.grades().last(), Some(&grades));
             } else {
                 assert!($puzzle
                     .check(&Word::from_str($guess)?, &mut $attempts)
@@ -0,0 +1,347 @@
+//! Loading strategies from native plugins.
+//!
+//! [`DylibWrapper`] is a [`Strategy`] that calls into a shared library
+//! (`.so`, `.dylib` or `.dll`) through a small, stable C ABI. This lets
+//! third-party strategies written in any language that can export C
+//! functions run at native speed, without being compiled into the harness.
+//!
+//! # ABI
+//!
+//! A plugin exports these functions, shown here in C:
+//!
+//! ```c
+//! #include <stdint.h>
+//!
+//! typedef int32_t (*wordle_grade_fn)(void *ctx, uint16_t guess);
+//!
+//! // Returns the ABI version the plugin was written for, `ABI_VERSION`.
+//! uint32_t wordle_abi_version(void);
+//!
+//! // Creates the strategy's state, which may be NULL.
+//! void *strategy_init(void);
+//!
+//! // Solves one puzzle by calling `grade` with each guess.
+//! void strategy_solve(void *state, uint32_t flags, wordle_grade_fn grade, void *ctx);
+//!
+//! // Frees the state created by `strategy_init()`.
+//! void strategy_free(void *state);
+//! ```
+//!
+//! Words are passed as indices into [`Word::ALL`]. To guess, the plugin
+//! calls `grade(ctx, guess)`, which returns the grades of the guess packed by
+//! [`encode_grades()`](super::encode_grades()), so `242` means the guess was
+//! correct. It returns `-1` if the guess was not accepted, e.g. because it
+//! breaks hardmode rules, or if the puzzle is already over; the plugin should
+//! then return from `strategy_solve()`. The plugin never learns the answer
+//! other than through `grade`.
+//!
+//! `flags` holds [`FLAG_HARDMODE`] when the strategy should play hardmode.
+//! Calls to `strategy_solve()` with the same state never overlap.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use wordle_rs::{strategy::plugin::DylibWrapper, Harness};
+//! let strategy = DylibWrapper::load("./libmy_strategy.so")?
+//!     .name("MyStrategy")
+//!     .version("0.1.0");
+//!
+//! let record = Harness::new()
+//!     .add_strategy(Box::new(strategy), None)
+//!     .run()?;
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::{
+    ffi::c_void,
+    fmt::Display,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use libloading::Library;
+
+use crate::{
+    strategy::{encode_grades, Attempts, AttemptsKey, Puzzle, Strategy, Word},
+    HarnessError, Result,
+};
+
+/// The version of the plugin ABI described in the
+/// [module-level documentation](self).
+pub const ABI_VERSION: u32 = 1;
+
+/// Set in the `flags` passed to `strategy_solve()` when the strategy should
+/// play hardmode.
+pub const FLAG_HARDMODE: u32 = 1;
+
+/// The callback a plugin calls to make a guess.
+pub type GradeFn = unsafe extern "C" fn(ctx: *mut c_void, guess: u16) -> i32;
+
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+type InitFn = unsafe extern "C" fn() -> *mut c_void;
+type SolveFn =
+    unsafe extern "C" fn(state: *mut c_void, flags: u32, grade: GradeFn, ctx: *mut c_void);
+type FreeFn = unsafe extern "C" fn(state: *mut c_void);
+
+/// A [`Strategy`] loaded from a native plugin.
+///
+/// See the [module-level documentation](self) for the functions the plugin
+/// must export.
+#[derive(Debug)]
+pub struct DylibWrapper {
+    path: PathBuf,
+    name: String,
+    version: &'static str,
+    hardmode: bool,
+    plugin: Plugin,
+}
+
+/// A loaded plugin and its state.
+#[derive(Debug)]
+struct Plugin {
+    solve: SolveFn,
+    free: FreeFn,
+    state: Mutex<State>,
+    // Keeps the functions above loaded, so it must be dropped last.
+    _library: Library,
+}
+
+/// The plugin's state, which is only used while holding the lock.
+#[derive(Debug)]
+struct State(*mut c_void);
+
+// SAFETY: the state is only passed to the plugin behind a mutex, and the ABI
+// does not tie it to the thread that created it.
+unsafe impl Send for State {}
+
+impl DylibWrapper {
+    /// Loads the plugin at `path` and creates its state.
+    ///
+    /// By default, the strategy is named after `path`, has version `0.0.0`
+    /// and plays easymode.
+    ///
+    /// Returns an error if the library cannot be loaded, does not export the
+    /// required functions or was written for a different [`ABI_VERSION`].
+    ///
+    /// # Safety
+    ///
+    /// Although this function is safe to call, loading a library runs its
+    /// initialization code, and the plugin must implement the ABI correctly.
+    /// Only load plugins you trust.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let load_error = |e: libloading::Error| HarnessError::PluginLoad(Box::new(e));
+
+        // SAFETY: see the documentation above.
+        unsafe {
+            let library = Library::new(path).map_err(load_error)?;
+
+            let abi_version = *library
+                .get::<AbiVersionFn>(b"wordle_abi_version\0")
+                .map_err(load_error)?;
+            let found = abi_version();
+            if found != ABI_VERSION {
+                return Err(HarnessError::PluginAbiMismatch(found).into());
+            }
+
+            let init = *library
+                .get::<InitFn>(b"strategy_init\0")
+                .map_err(load_error)?;
+            let solve = *library
+                .get::<SolveFn>(b"strategy_solve\0")
+                .map_err(load_error)?;
+            let free = *library
+                .get::<FreeFn>(b"strategy_free\0")
+                .map_err(load_error)?;
+
+            Ok(DylibWrapper {
+                path: path.to_path_buf(),
+                name: path.display().to_string(),
+                version: "0.0.0",
+                hardmode: false,
+                plugin: Plugin {
+                    solve,
+                    free,
+                    state: Mutex::new(State(init())),
+                    _library: library,
+                },
+            })
+        }
+    }
+
+    /// Sets the name the strategy is displayed with.
+    pub fn name(self, name: impl Into<String>) -> Self {
+        DylibWrapper {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    /// Sets the version of the strategy.
+    ///
+    /// See [`Strategy::version()`].
+    pub fn version(self, version: &'static str) -> Self {
+        DylibWrapper { version, ..self }
+    }
+
+    /// Sets whether the strategy plays hardmode.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        DylibWrapper { hardmode, ..self }
+    }
+}
+
+impl Strategy for DylibWrapper {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        let state = self.plugin.state.lock().unwrap();
+        let flags = if self.hardmode { FLAG_HARDMODE } else { 0 };
+
+        // SAFETY: the plugin implements the ABI, and `state` came from its
+        // `strategy_init()`.
+        unsafe { play(self.plugin.solve, state.0, flags, puzzle, key) }
+    }
+
+    fn version(&self) -> &'static str {
+        self.version
+    }
+
+    fn hardmode(&self) -> bool {
+        self.hardmode
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        let clone = DylibWrapper::load(&self.path).ok()?;
+        Some(Box::new(
+            clone
+                .name(&self.name)
+                .version(self.version)
+                .hardmode(self.hardmode),
+        ))
+    }
+}
+
+impl Display for DylibWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Drop for Plugin {
+    fn drop(&mut self) {
+        let state = self.state.get_mut().unwrap();
+        // SAFETY: `state` came from the plugin's `strategy_init()` and is not
+        // used again.
+        unsafe { (self.free)(state.0) }
+    }
+}
+
+/// The puzzle a plugin is working on, passed to [`grade()`] as its context.
+struct Game<'a> {
+    puzzle: &'a mut Puzzle,
+    attempts: Attempts,
+    over: bool,
+}
+
+/// Calls `solve` on one puzzle and collects the guesses it makes.
+///
+/// # Safety
+///
+/// `solve` and `state` must follow the plugin ABI.
+unsafe fn play(
+    solve: SolveFn,
+    state: *mut c_void,
+    flags: u32,
+    puzzle: &mut Puzzle,
+    key: AttemptsKey,
+) -> Attempts {
+    let mut game = Game {
+        puzzle,
+        attempts: key.unlock(),
+        over: false,
+    };
+
+    solve(state, flags, grade, &mut game as *mut Game as *mut c_void);
+
+    game.attempts
+}
+
+/// The [`GradeFn`] passed to plugins.
+unsafe extern "C" fn grade(ctx: *mut c_void, guess: u16) -> i32 {
+    // SAFETY: `ctx` is the `Game` created by `play()`, which outlives the
+    // call to `strategy_solve()`.
+    let game = &mut *(ctx as *mut Game);
+    if game.over {
+        return -1;
+    }
+
+    let guess = match Word::from_index(guess as usize) {
+        Ok(guess) => guess,
+        Err(_) => {
+            game.over = true;
+            return -1;
+        }
+    };
+
+    match game.puzzle.check(&guess, &mut game.attempts) {
+        Ok((grades, correct)) => {
+            game.over = correct || game.attempts.finished();
+            encode_grades(&grades) as i32
+        }
+        Err(_) => {
+            game.over = true;
+            -1
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Guesses "tithe" and then "doubt" until the puzzle is over.
+    unsafe extern "C" fn solve(state: *mut c_void, flags: u32, grade: GradeFn, ctx: *mut c_void) {
+        assert!(state.is_null());
+        assert_eq!(flags, 0);
+
+        let tithe = Word::from_str("tithe").unwrap().index() as u16;
+        let doubt = Word::from_str("doubt").unwrap().index() as u16;
+
+        let mut result = grade(ctx, tithe);
+        while result >= 0 && result != 242 {
+            result = grade(ctx, doubt);
+        }
+    }
+
+    #[test]
+    fn plays_through_abi() -> Result<()> {
+        let tithe = Word::from_str("tithe")?;
+        let doubt = Word::from_str("doubt")?;
+
+        let mut puzzle = Puzzle::new(doubt);
+        let attempts = unsafe {
+            play(
+                solve,
+                std::ptr::null_mut(),
+                0,
+                &mut puzzle,
+                AttemptsKey::new(false),
+            )
+        };
+        assert_eq!(attempts.inner(), [tithe, doubt]);
+
+        let mut puzzle = Puzzle::new(Word::from_str("parka")?);
+        let attempts = unsafe {
+            play(
+                solve,
+                std::ptr::null_mut(),
+                0,
+                &mut puzzle,
+                AttemptsKey::new(false),
+            )
+        };
+        assert_eq!(attempts.inner().len(), 6);
+
+        assert!(DylibWrapper::load("/does/not/exist.so").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,467 @@
+//! Running strategies behind a web service.
+//!
+//! [`RemoteStrategy`] is a [`Strategy`] that asks an HTTP endpoint for its
+//! guesses, so strategies written in other languages (or running on other
+//! machines) can be tested in the same harness and reports as native ones.
+//!
+//! # Protocol
+//!
+//! For every guess, the strategy sends a `POST` request to the endpoint with
+//! a JSON body describing the puzzle so far:
+//!
+//! ```json
+//! {
+//!     "hardmode": false,
+//!     "guesses": ["crane", "doubt"],
+//!     "grades": ["bbybb", "bgbbb"],
+//!     "index": 12,
+//!     "total": 100,
+//!     "day": null
+//! }
+//! ```
+//!
+//! The grades are encoded the same way as for a
+//! [`SubprocessWrapper`](super::subprocess::SubprocessWrapper): one letter
+//! per letter of the guess, `g` for correct, `y` for almost and `b` for
+//! incorrect. `index`, `total` and `day` come from the
+//! [`SolveContext`]. The service should respond with `200 OK` and a JSON
+//! body holding its next guess:
+//!
+//! ```json
+//! { "guess": "tithe" }
+//! ```
+//!
+//! Each request holds the whole puzzle, so the service does not need to
+//! keep any state between requests. If a request fails, times out or the
+//! response is not a valid guess, the puzzle ends unsolved.
+//!
+//! With the `formats` build feature, the request and response bodies can be
+//! sent as [MessagePack](https://msgpack.org) instead, with the same fields,
+//! by setting [`RemoteStrategy::payload()`] to [`Payload::MessagePack`].
+//!
+//! Only plain `http://` endpoints are supported.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use wordle_rs::{strategy::remote::RemoteStrategy, Harness};
+//! let strategy = RemoteStrategy::new("http://localhost:8000/guess")?
+//!     .name("PythonStrategy")
+//!     .version("0.1.0");
+//!
+//! let record = Harness::new()
+//!     .add_strategy(Box::new(strategy), None)
+//!     .run()?;
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::{
+    fmt::Display,
+    io::{self, Read, Write},
+    net::TcpStream,
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+
+use super::subprocess::encode;
+use crate::{
+    strategy::{Attempts, AttemptsKey, Puzzle, SolveContext, Strategy, Word},
+    HarnessError, Result,
+};
+
+/// A [`Strategy`] that gets its guesses from an HTTP endpoint.
+///
+/// See the [module-level documentation](self) for the protocol the endpoint
+/// must follow.
+#[derive(Debug, Clone)]
+pub struct RemoteStrategy {
+    endpoint: String,
+    host: String,
+    port: u16,
+    path: String,
+    name: String,
+    version: &'static str,
+    hardmode: bool,
+    timeout: Duration,
+    payload: Payload,
+}
+
+/// The formats a [`RemoteStrategy`] can send and receive bodies in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Payload {
+    /// [JSON](https://www.json.org), sent as `application/json`.
+    Json,
+
+    /// [MessagePack](https://msgpack.org), sent as `application/msgpack`.
+    #[cfg(feature = "formats")]
+    MessagePack,
+}
+
+impl Payload {
+    /// Gets the media type bodies in this format are sent with.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Payload::Json => "application/json",
+            #[cfg(feature = "formats")]
+            Payload::MessagePack => "application/msgpack",
+        }
+    }
+
+    /// Serializes `value` in this format.
+    fn encode(&self, value: &Value) -> io::Result<Vec<u8>> {
+        match self {
+            Payload::Json => Ok(serde_json::to_vec(value)?),
+            #[cfg(feature = "formats")]
+            Payload::MessagePack => rmp_serde::to_vec_named(value)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+
+    /// Deserializes a value in this format from `body`.
+    fn decode(&self, body: &[u8]) -> io::Result<Value> {
+        match self {
+            Payload::Json => Ok(serde_json::from_slice(body)?),
+            #[cfg(feature = "formats")]
+            Payload::MessagePack => rmp_serde::from_slice(body)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+}
+
+impl RemoteStrategy {
+    /// Creates a new strategy that gets its guesses from `endpoint`, a URL
+    /// like `http://localhost:8000/guess`.
+    ///
+    /// By default, the strategy is named after `endpoint`, has version
+    /// `0.0.0`, plays easymode, waits up to 30 seconds for each guess and
+    /// sends and receives JSON.
+    ///
+    /// Returns an error if `endpoint` is not an `http://` URL.
+    pub fn new(endpoint: &str) -> Result<Self> {
+        let invalid = || HarnessError::InvalidEndpoint(endpoint.to_string());
+
+        let rest = endpoint.strip_prefix("http://").ok_or_else(invalid)?;
+        let (authority, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, "/"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| invalid())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid().into());
+        }
+
+        Ok(RemoteStrategy {
+            endpoint: endpoint.to_string(),
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+            name: endpoint.to_string(),
+            version: "0.0.0",
+            hardmode: false,
+            timeout: Duration::from_secs(30),
+            payload: Payload::Json,
+        })
+    }
+
+    /// Sets the name the strategy is displayed with.
+    pub fn name(self, name: impl Into<String>) -> Self {
+        RemoteStrategy {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    /// Sets the version of the strategy.
+    ///
+    /// See [`Strategy::version()`].
+    pub fn version(self, version: &'static str) -> Self {
+        RemoteStrategy { version, ..self }
+    }
+
+    /// Sets whether the strategy plays hardmode.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        RemoteStrategy { hardmode, ..self }
+    }
+
+    /// Sets how long to wait for the endpoint to respond with each guess.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        RemoteStrategy { timeout, ..self }
+    }
+
+    /// Sets the format of the request and response bodies.
+    pub fn payload(self, payload: Payload) -> Self {
+        RemoteStrategy { payload, ..self }
+    }
+
+    /// Gets the endpoint the strategy gets its guesses from.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Asks the endpoint for the next guess.
+    fn next_guess(&self, attempts: &Attempts, ctx: &SolveContext) -> io::Result<Word> {
+        let body = json!({
+            "hardmode": self.hardmode,
+            "guesses": attempts.inner().iter().map(|w| w.to_string()).collect::<Vec<_>>(),
+            "grades": attempts.grades().iter().map(|g| encode(g)).collect::<Vec<_>>(),
+            "index": ctx.index(),
+            "total": ctx.total(),
+            "day": ctx.day(),
+        });
+
+        let response = self.post(&self.payload.encode(&body)?)?;
+        let value = self.payload.decode(&response)?;
+        value["guess"]
+            .as_str()
+            .and_then(|guess| Word::from_str(guess).ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid guess"))
+    }
+
+    /// Sends `body` to the endpoint and returns the body of the response.
+    fn post(&self, body: &[u8]) -> io::Result<Vec<u8>> {
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port))?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        // The port is only left out of the host when it is the default.
+        let host = match self.port {
+            80 => self.host.clone(),
+            port => format!("{}:{}", self.host, port),
+        };
+
+        // HTTP/1.0 keeps the server from chunking the response, and the
+        // connection closes once the response is sent.
+        write!(
+            stream,
+            "POST {} HTTP/1.0\r\nHost: {}\r\nContent-Type: {}\r\nAccept: {}\r\nContent-Length: {}\r\n\r\n",
+            self.path,
+            host,
+            self.payload.content_type(),
+            self.payload.content_type(),
+            body.len(),
+        )?;
+        stream.write_all(body)?;
+        stream.flush()?;
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+
+        let split = response
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "malformed response"))?;
+        let head = String::from_utf8_lossy(&response[..split]);
+        match head.split_whitespace().nth(1) {
+            Some("200") => Ok(response[split + 4..].to_vec()),
+            _ => Err(io::Error::new(io::ErrorKind::Other, "request failed")),
+        }
+    }
+}
+
+impl Strategy for RemoteStrategy {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        self.solve_with_context(puzzle, key, &SolveContext::new(0, 1, None))
+    }
+
+    fn solve_with_context(
+        &self,
+        puzzle: &mut Puzzle,
+        key: AttemptsKey,
+        ctx: &SolveContext,
+    ) -> Attempts {
+        let mut attempts = key.unlock();
+
+        while !attempts.finished() {
+            let guess = match self.next_guess(&attempts, ctx) {
+                Ok(guess) => guess,
+                Err(_) => break,
+            };
+
+            match puzzle.check(&guess, &mut attempts) {
+                Ok((_, false)) => {}
+                _ => break,
+            }
+        }
+
+        attempts
+    }
+
+    fn version(&self) -> &'static str {
+        self.version
+    }
+
+    fn hardmode(&self) -> bool {
+        self.hardmode
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl Display for RemoteStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+    use crate::{Harness, WordSelection};
+
+    /// A request the test server received, and the `Host` it was sent to.
+    type Request = (String, Value);
+
+    /// Serves `n` requests in `payload`, guessing "tithe" and then "doubt".
+    fn serve(n: usize, payload: Payload) -> (String, thread::JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/guess", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for stream in listener.incoming().take(n) {
+                let mut reader = BufReader::new(stream.unwrap());
+                let (mut length, mut host) = (0, String::new());
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.strip_prefix("Content-Length: ") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if let Some(value) = line.strip_prefix("Content-Type: ") {
+                        assert_eq!(value.trim(), payload.content_type());
+                    }
+                    if let Some(value) = line.strip_prefix("Host: ") {
+                        host = value.trim().to_string();
+                    }
+                }
+
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let request = payload.decode(&body).unwrap();
+
+                let guess = match request["guesses"].as_array().unwrap().len() {
+                    0 => "tithe",
+                    _ => "doubt",
+                };
+                let response = payload.encode(&json!({ "guess": guess })).unwrap();
+                let stream = reader.get_mut();
+                write!(
+                    stream,
+                    "HTTP/1.0 200 OK\r\nContent-Length: {}\r\n\r\n",
+                    response.len()
+                )
+                .unwrap();
+                stream.write_all(&response).unwrap();
+
+                requests.push((host, request));
+            }
+            requests
+        });
+
+        (endpoint, handle)
+    }
+
+    #[test]
+    fn plays_through_protocol() -> Result<()> {
+        let (endpoint, server) = serve(2, Payload::Json);
+        let strategy = RemoteStrategy::new(&endpoint)?.name("Remote");
+
+        let mut puzzle = Puzzle::new(Word::from_str("doubt")?);
+        let attempts = strategy.solve(&mut puzzle, AttemptsKey::new(false));
+        assert_eq!(
+            attempts.inner(),
+            [Word::from_str("tithe")?, Word::from_str("doubt")?]
+        );
+
+        let requests = server.join().unwrap();
+        // The server is not on port 80, so its port is part of the host.
+        let host = endpoint
+            .trim_start_matches("http://")
+            .trim_end_matches("/guess");
+        assert_eq!(requests[0].0, host);
+        assert_eq!(requests[0].1["guesses"], json!([]));
+        assert_eq!(requests[1].1["guesses"], json!(["tithe"]));
+        assert_eq!(requests[1].1["grades"], json!(["ybbbb"]));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "formats")]
+    #[test]
+    fn plays_in_msgpack() -> Result<()> {
+        let (endpoint, server) = serve(2, Payload::MessagePack);
+        let strategy = RemoteStrategy::new(&endpoint)?.payload(Payload::MessagePack);
+
+        let mut puzzle = Puzzle::new(Word::from_str("doubt")?);
+        let attempts = strategy.solve(&mut puzzle, AttemptsKey::new(false));
+        assert_eq!(
+            attempts.inner(),
+            [Word::from_str("tithe")?, Word::from_str("doubt")?]
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[1].1["guesses"], json!(["tithe"]));
+        assert_eq!(requests[1].1["hardmode"], json!(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn runs_in_harness() -> Result<()> {
+        let (endpoint, server) = serve(6, Payload::Json);
+        let strategy = RemoteStrategy::new(&endpoint)?
+            .name("Remote")
+            .version("1.0.0");
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(strategy), None)
+            .words(WordSelection::Explicit(vec![Word::from_str("parka")?]))
+            .run()?;
+        assert_eq!(record[0].to_string(), "Remote v1.0.0");
+        assert_eq!(record[0].num_solved(), 0);
+        assert_eq!(record[0].tries()[0].1.inner().len(), 6);
+        assert_eq!(server.join().unwrap()[5].1["total"], 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn endpoints() {
+        let strategy = RemoteStrategy::new("http://example.com:8080/api/guess").unwrap();
+        assert_eq!(strategy.host, "example.com");
+        assert_eq!(strategy.port, 8080);
+        assert_eq!(strategy.path, "/api/guess");
+        assert_eq!(format!("{}", strategy), "http://example.com:8080/api/guess");
+
+        let strategy = RemoteStrategy::new("http://localhost").unwrap();
+        assert_eq!((strategy.port, strategy.path.as_str()), (80, "/"));
+
+        assert!(RemoteStrategy::new("https://example.com").is_err());
+        assert!(RemoteStrategy::new("http://example.com:http/").is_err());
+        assert!(RemoteStrategy::new("http:///guess").is_err());
+
+        // Nothing is listening, so the puzzle ends without any guesses.
+        let strategy = RemoteStrategy::new("http://127.0.0.1:1/").unwrap();
+        let mut puzzle = Puzzle::new(Word::from_str("tithe").unwrap());
+        assert!(strategy
+            .solve(&mut puzzle, AttemptsKey::new(false))
+            .inner()
+            .is_empty());
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::fmt::Display;
 
-use crate::strategy::{Attempts, AttemptsKey, Puzzle, Strategy, Word};
+use crate::strategy::{Attempts, AttemptsKey, Puzzle, Strategy, StrategyMetadata, Word};
 
 /// A Wordle strategy that only ever guesses the first few words in the wordlist.
 ///
@@ -35,6 +35,19 @@ impl Strategy for Stupid {
     fn hardmode(&self) -> bool {
         false
     }
+
+    fn metadata(&self) -> StrategyMetadata {
+        StrategyMetadata {
+            author: Some("cgm616".to_string()),
+            description: Some("Guesses the first six words in the wordlist".to_string()),
+            tags: vec!["example".to_string()],
+            ..StrategyMetadata::new()
+        }
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 impl Display for Stupid {
@@ -0,0 +1,403 @@
+//! Running strategies in a separate process.
+//!
+//! [`SubprocessWrapper`] is a [`Strategy`] that asks a child process for its
+//! guesses. Because the strategy runs in its own process, a crash or memory
+//! leak in it cannot take down the harness, and the operating system can
+//! limit the resources it uses, e.g. by starting it through `prlimit` or
+//! `ulimit`. This also allows strategies to be written in any language.
+//!
+//! # Protocol
+//!
+//! The wrapper and the child process exchange lines of text over the child's
+//! stdin and stdout. Each puzzle goes like this:
+//!
+//! 1. The wrapper sends `new`.
+//! 2. The child replies with a guess, like `crane`.
+//! 3. If the puzzle continues, the wrapper replies with the grades of the
+//!    guess, one letter per letter of the guess: `g` for
+//!    [`Correct`](Grade::Correct), `y` for [`Almost`](Grade::Almost) and `b`
+//!    for [`Incorrect`](Grade::Incorrect), like `bygbb`. The child replies
+//!    with its next guess, and this step repeats.
+//! 4. Once the puzzle is solved, the child has run out of guesses or it has
+//!    made an invalid guess, the wrapper sends `done` instead of grades. The
+//!    child should not reply, and should wait for the next `new`.
+//!
+//! The same child process is used for every puzzle. If it exits or stops
+//! following the protocol, the puzzle it was working on ends unsolved and a
+//! new process is started for the next puzzle.
+//!
+//! A strategy written in Rust can be served over this protocol by calling
+//! [`serve()`] from a binary's `main()`.
+//!
+//! # Examples
+//!
+//! A child process that always guesses "crane" and then "doubt" could be
+//! this shell script:
+//!
+//! ```sh
+//! while read line; do
+//!     case "$line" in
+//!         new) echo crane ;;
+//!         done) ;;
+//!         *) echo doubt ;;
+//!     esac
+//! done
+//! ```
+//!
+//! which can be run by the harness like this:
+//!
+//! ```rust,no_run
+//! # use wordle_rs::{strategy::subprocess::SubprocessWrapper, Harness};
+//! let strategy = SubprocessWrapper::new("sh")
+//!     .arg("crane_doubt.sh")
+//!     .name("CraneDoubt")
+//!     .version("0.1.0");
+//!
+//! let record = Harness::new()
+//!     .add_strategy(Box::new(strategy), None)
+//!     .run()?;
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::{
+    ffi::OsString,
+    fmt::Display,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::Mutex,
+};
+
+use crate::{
+    strategy::{assist::Assistant, Attempts, AttemptsKey, Grade, Puzzle, Strategy, Word},
+    Result,
+};
+
+/// A [`Strategy`] that runs in a child process.
+///
+/// See the [module-level documentation](self) for the protocol the child
+/// process must follow. The process is started when the first puzzle is
+/// solved. Since there is only one process, puzzles are solved one at a time
+/// even when the harness runs in parallel; add several wrappers to run
+/// several processes.
+#[derive(Debug)]
+pub struct SubprocessWrapper {
+    program: PathBuf,
+    args: Vec<OsString>,
+    name: String,
+    version: &'static str,
+    hardmode: bool,
+    process: Mutex<Option<Process>>,
+}
+
+impl SubprocessWrapper {
+    /// Creates a new wrapper that runs `program`.
+    ///
+    /// By default, the strategy is named after `program`, has version `0.0.0`
+    /// and plays easymode.
+    pub fn new(program: impl Into<PathBuf>) -> Self {
+        let program = program.into();
+        SubprocessWrapper {
+            name: program.display().to_string(),
+            program,
+            args: Vec::new(),
+            version: "0.0.0",
+            hardmode: false,
+            process: Mutex::new(None),
+        }
+    }
+
+    /// Adds an argument to pass to the program.
+    pub fn arg(self, arg: impl Into<OsString>) -> Self {
+        let mut args = self.args;
+        args.push(arg.into());
+        SubprocessWrapper { args, ..self }
+    }
+
+    /// Adds several arguments to pass to the program.
+    pub fn args<I, S>(self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        args.into_iter().fold(self, Self::arg)
+    }
+
+    /// Sets the name the strategy is displayed with.
+    pub fn name(self, name: impl Into<String>) -> Self {
+        SubprocessWrapper {
+            name: name.into(),
+            ..self
+        }
+    }
+
+    /// Sets the version of the strategy.
+    ///
+    /// See [`Strategy::version()`].
+    pub fn version(self, version: &'static str) -> Self {
+        SubprocessWrapper { version, ..self }
+    }
+
+    /// Sets whether the strategy plays hardmode.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        SubprocessWrapper { hardmode, ..self }
+    }
+}
+
+impl Strategy for SubprocessWrapper {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        let mut attempts = key.unlock();
+        let mut process = self.process.lock().unwrap();
+
+        if process.is_none() {
+            *process = Process::spawn(&self.program, &self.args).ok();
+        }
+
+        if let Some(running) = process.as_mut() {
+            if running.play(puzzle, &mut attempts).is_err() {
+                *process = None;
+            }
+        }
+
+        attempts
+    }
+
+    fn version(&self) -> &'static str {
+        self.version
+    }
+
+    fn hardmode(&self) -> bool {
+        self.hardmode
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(
+            SubprocessWrapper::new(&self.program)
+                .args(&self.args)
+                .name(&self.name)
+                .version(self.version)
+                .hardmode(self.hardmode),
+        ))
+    }
+}
+
+impl Display for SubprocessWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A running child process and the pipes to talk to it.
+#[derive(Debug)]
+struct Process {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl Process {
+    fn spawn(program: &Path, args: &[OsString]) -> io::Result<Self> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+
+        Ok(Process {
+            child,
+            stdin,
+            stdout,
+        })
+    }
+
+    /// Plays one puzzle, adding the guesses to `attempts`.
+    ///
+    /// Returns an error if the process stopped following the protocol.
+    fn play(&mut self, puzzle: &mut Puzzle, attempts: &mut Attempts) -> io::Result<()> {
+        self.send("new")?;
+
+        loop {
+            let guess = match Word::from_str(self.receive()?.trim()) {
+                Ok(guess) => guess,
+                Err(_) => break,
+            };
+
+            match puzzle.check(&guess, attempts) {
+                Ok((grades, false)) if !attempts.finished() => self.send(&encode(&grades))?,
+                _ => break,
+            }
+        }
+
+        self.send("done")
+    }
+
+    fn send(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.stdin, "{}", line)?;
+        self.stdin.flush()
+    }
+
+    fn receive(&mut self) -> io::Result<String> {
+        let mut line = String::new();
+        if self.stdout.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(line)
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Plays the child's side of the protocol for `strategy` over stdin and
+/// stdout, until stdin is closed.
+///
+/// The strategy is asked for each guess by an [`Assistant`], so it must make
+/// the same guesses each time it is given the same grades.
+///
+/// # Examples
+///
+/// A binary like this can be run by a [`SubprocessWrapper`]:
+///
+/// ```rust,no_run
+/// # use wordle_rs::strategy::{stupid::Stupid, subprocess::serve};
+/// fn main() -> wordle_rs::Result<()> {
+///     serve(&Stupid)
+/// }
+/// ```
+pub fn serve(strategy: &dyn Strategy) -> Result<()> {
+    let stdin = io::stdin();
+    serve_on(strategy, stdin.lock(), io::stdout())
+}
+
+fn serve_on(strategy: &dyn Strategy, input: impl BufRead, mut output: impl Write) -> Result<()> {
+    let mut current = None;
+    for line in input.lines() {
+        let line = line?;
+        let assistant = match line.trim() {
+            "new" => current.insert(Assistant::new(strategy)),
+            "done" => {
+                current = None;
+                continue;
+            }
+            grades => match current.as_mut() {
+                Some(assistant) => {
+                    assistant.feed(Grade::parse_row(grades)?)?;
+                    assistant
+                }
+                None => continue,
+            },
+        };
+
+        // A line that is not a word ends the puzzle, for when the strategy
+        // has run out of guesses.
+        let guess = assistant.suggest()?.map(|guess| guess.to_string());
+        writeln!(output, "{}", guess.unwrap_or_default())?;
+        output.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Encodes grades as they are sent to the child process.
+pub(super) fn encode(grades: &[Grade]) -> String {
+    grades
+        .iter()
+        .map(|grade| match grade {
+            Grade::Correct => 'g',
+            Grade::Almost => 'y',
+            Grade::Incorrect => 'b',
+        })
+        .collect()
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, strategy::grade, Harness, WordSelection};
+
+    const SCRIPT: &str = r#"
+        while read line; do
+            case "$line" in
+                new) echo tithe ;;
+                done) ;;
+                *) echo doubt ;;
+            esac
+        done
+    "#;
+
+    #[test]
+    fn plays_through_protocol() -> Result<()> {
+        let tithe = Word::from_str("tithe")?;
+        let doubt = Word::from_str("doubt")?;
+
+        let strategy = SubprocessWrapper::new("sh").args(["-c", SCRIPT]);
+        assert_eq!(format!("{}", strategy), "sh");
+
+        let mut puzzle = Puzzle::new(tithe);
+        let attempts = strategy.solve(&mut puzzle, AttemptsKey::new(false));
+        assert_eq!(attempts.inner(), [tithe]);
+
+        let mut puzzle = Puzzle::new(Word::from_str("parka")?);
+        let attempts = strategy.solve(&mut puzzle, AttemptsKey::new(false));
+        assert_eq!(attempts.inner(), [tithe, doubt, doubt, doubt, doubt, doubt]);
+
+        let mut puzzle = Puzzle::new(doubt);
+        let attempts = strategy.solve(&mut puzzle, AttemptsKey::new(false));
+        assert_eq!(attempts.inner(), [tithe, doubt]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn survives_crashes() -> Result<()> {
+        let strategy = SubprocessWrapper::new("sh")
+            .args(["-c", "read line; echo tithe; exit 1"])
+            .name("Crashes")
+            .version("1.0.0");
+
+        let record = Harness::new()
+            .verbose(false)
+            .add_strategy(Box::new(strategy), None)
+            .words(WordSelection::Range(0..5))
+            .run()?;
+        assert_eq!(record[0].to_string(), "Crashes v1.0.0");
+        assert_eq!(record[0].num_tried(), 5);
+        assert!(record[0].tries().iter().all(|(_, a)| a.inner().len() == 1));
+
+        let missing = SubprocessWrapper::new("/does/not/exist");
+        let mut puzzle = Puzzle::new(Word::from_str("tithe")?);
+        assert!(missing
+            .solve(&mut puzzle, AttemptsKey::new(false))
+            .inner()
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serves_protocol() -> Result<()> {
+        let answer = Word::from_str("point")?;
+        let mut input = String::from("new\n");
+        for guess in ["nerds", "tithe", "doubt"] {
+            input.push_str(&encode(&grade(&Word::from_str(guess)?, &answer)));
+            input.push('\n');
+        }
+        input.push_str("done\nnew\n");
+
+        let mut output = Vec::new();
+        serve_on(&Mock::new(None), input.as_bytes(), &mut output)?;
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "nerds\ntithe\ndoubt\npoint\nnerds\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+//! Helpers for unit testing strategies without the test harness.
+//!
+//! The [test harness](crate::Harness) is the right tool for measuring how
+//! well a strategy performs, but it is heavy for checking that a strategy
+//! behaves correctly on a few puzzles. This module runs a strategy on single
+//! puzzles and provides assertions with readable failure messages, so that
+//! strategy authors can write focused tests.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use wordle_rs::{strategy::stupid::Stupid, testkit::ScriptedPuzzle, Word};
+//!
+//! let mut puzzle = ScriptedPuzzle::new(Word::from_str("earth")?);
+//! puzzle.play(&Stupid)?;
+//!
+//! assert!(!puzzle.solved());
+//! assert_eq!(puzzle.guesses().len(), 6);
+//! #
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::fmt::Display;
+
+use crate::{
+    strategy::hardmode::is_valid_guess, AttemptsKey, Grade, HarnessError, Puzzle, Result,
+    SolveContext, Strategy, Word,
+};
+
+/// A puzzle with a known answer that records the feedback a strategy gets.
+///
+/// Since the answer is chosen by the test, the grades for each guess are
+/// predictable, and the recorded transcript can be checked guess by guess.
+/// The [`Display`] implementation prints the transcript with one guess and
+/// its grades per line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScriptedPuzzle {
+    answer: Word,
+    hardmode: Option<bool>,
+    transcript: Vec<(Word, [Grade; 5])>,
+}
+
+impl ScriptedPuzzle {
+    /// Creates a new puzzle with the given answer.
+    pub fn new(answer: Word) -> Self {
+        ScriptedPuzzle {
+            answer,
+            hardmode: None,
+            transcript: Vec::new(),
+        }
+    }
+
+    /// Sets whether hardmode rules are enforced while playing.
+    ///
+    /// By default, they are enforced if the strategy reports that it plays
+    /// hardmode, like in the test harness.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        ScriptedPuzzle {
+            hardmode: Some(hardmode),
+            ..self
+        }
+    }
+
+    /// Runs `strategy` on this puzzle, replacing any earlier transcript.
+    ///
+    /// The strategy gets the same kind of [`AttemptsKey`] it would get from
+    /// the test harness. Returns an error if the strategy cheated.
+    pub fn play(&mut self, strategy: &dyn Strategy) -> Result<()> {
+        let hard = self.hardmode.unwrap_or_else(|| strategy.hardmode());
+        let mut puzzle = Puzzle::new(self.answer);
+        let attempts = strategy.solve_with_context(
+            &mut puzzle,
+            AttemptsKey::new(hard),
+            &SolveContext::new(0, 1, None),
+        );
+
+        self.transcript = attempts
+            .inner()
+            .iter()
+            .copied()
+            .zip(attempts.grades().iter().copied())
+            .collect();
+
+        match puzzle.poisoning() {
+            Some(poisoning) => Err(HarnessError::StrategyCheated {
+                strategy: strategy.to_string(),
+                word: self.answer,
+                poisoning,
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Gets the answer to the puzzle.
+    pub fn answer(&self) -> Word {
+        self.answer
+    }
+
+    /// Gets each guess made in the last [`play()`](Self::play()) and the
+    /// grades it received.
+    pub fn transcript(&self) -> &[(Word, [Grade; 5])] {
+        &self.transcript
+    }
+
+    /// Gets the guesses made in the last [`play()`](Self::play()).
+    pub fn guesses(&self) -> Vec<Word> {
+        self.transcript.iter().map(|(guess, _)| *guess).collect()
+    }
+
+    /// Returns true if the last guess was the answer.
+    pub fn solved(&self) -> bool {
+        matches!(self.transcript.last(), Some((guess, _)) if *guess == self.answer)
+    }
+
+    /// Asserts that the strategy made exactly the `expected` guesses.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the transcript if the guesses differ, or if a word in
+    /// `expected` is not a valid guess.
+    pub fn assert_guesses(&self, expected: &[&str]) {
+        let expected: Vec<Word> = expected
+            .iter()
+            .map(|w| Word::from_str(w).unwrap())
+            .collect();
+        assert!(
+            self.guesses() == expected,
+            "expected guesses {:?} for {}, but the strategy played:\n{}",
+            expected.iter().map(Word::to_string).collect::<Vec<_>>(),
+            self.answer,
+            self
+        );
+    }
+}
+
+impl Display for ScriptedPuzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (guess, grades)) in self.transcript.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{} ", guess)?;
+            for grade in grades {
+                write!(f, "{}", grade.emoji())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Asserts that `strategy` solves the puzzle with the given `answer` in at
+/// most `max_guesses` guesses.
+///
+/// # Panics
+///
+/// Panics with the guesses the strategy made if it does not solve the
+/// puzzle in time or if it cheats.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt::Display;
+/// # use wordle_rs::{Attempts, AttemptsKey, Puzzle, Strategy, Word};
+/// use wordle_rs::testkit::assert_solves;
+///
+/// /// Guesses "ratio" and then "earth".
+/// #[derive(Debug)]
+/// struct Earth;
+///
+/// impl Strategy for Earth {
+///     fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+///         let mut attempts = key.unlock();
+///         for guess in ["ratio", "earth"] {
+///             let guess = Word::from_str(guess).unwrap();
+///             if puzzle.check(&guess, &mut attempts).unwrap().1 {
+///                 break;
+///             }
+///         }
+///         attempts
+///     }
+///
+///     fn version(&self) -> &'static str {
+///         "0.1.0"
+///     }
+///
+///     fn hardmode(&self) -> bool {
+///         true
+///     }
+/// }
+/// #
+/// # impl Display for Earth {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         write!(f, "Earth")
+/// #     }
+/// # }
+///
+/// assert_solves(&Earth, Word::from_str("earth")?, 2);
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn assert_solves(strategy: &dyn Strategy, answer: Word, max_guesses: usize) {
+    let mut puzzle = ScriptedPuzzle::new(answer);
+    if let Err(e) = puzzle.play(strategy) {
+        panic!("{} failed on {}: {}", strategy, answer, e);
+    }
+
+    assert!(
+        puzzle.solved() && puzzle.transcript().len() <= max_guesses,
+        "{} did not solve {} in {} guesses:\n{}",
+        strategy,
+        answer,
+        max_guesses,
+        puzzle
+    );
+}
+
+/// Asserts that every guess `strategy` makes on the puzzles with the given
+/// `answers` follows the hardmode rules.
+///
+/// The strategy is run without hardmode being enforced, so that a guess
+/// breaking the rules is recorded instead of rejected, and each guess is
+/// then checked against the ones before it.
+///
+/// # Panics
+///
+/// Panics with the guesses the strategy made on the first puzzle where it
+/// breaks the rules or cheats.
+pub fn assert_hardmode_compliant(strategy: &dyn Strategy, answers: &[Word]) {
+    for &answer in answers {
+        let mut puzzle = ScriptedPuzzle::new(answer).hardmode(false);
+        if let Err(e) = puzzle.play(strategy) {
+            panic!("{} failed on {}: {}", strategy, answer, e);
+        }
+
+        let transcript = puzzle.transcript();
+        for (i, (guess, _)) in transcript.iter().enumerate() {
+            assert!(
+                is_valid_guess(&transcript[..i], guess),
+                "{} broke the hardmode rules with guess {} ({}) on {}:\n{}",
+                strategy,
+                i + 1,
+                guess,
+                answer,
+                puzzle
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mock::Mock, strategy::stupid::Stupid};
+
+    #[test]
+    fn records_transcripts() -> Result<()> {
+        let mut puzzle = ScriptedPuzzle::new(Word::from_str("tithe")?);
+        puzzle.play(&Mock::new(None))?;
+
+        assert!(puzzle.solved());
+        puzzle.assert_guesses(&["nerds", "tithe"]);
+        assert_eq!(puzzle.transcript()[1].1, [Grade::Correct; 5]);
+        assert_eq!(puzzle.to_string().lines().count(), 2);
+        assert!(puzzle.to_string().starts_with("nerds "));
+
+        puzzle.play(&Stupid)?;
+        assert!(!puzzle.solved());
+
+        Ok(())
+    }
+
+    #[test]
+    fn asserts_solves() -> Result<()> {
+        assert_solves(&Mock::new(None), Word::from_str("doubt")?, 3);
+
+        let result = std::panic::catch_unwind(|| {
+            assert_solves(&Mock::new(None), Word::from_str("doubt").unwrap(), 2)
+        });
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn asserts_hardmode() -> Result<()> {
+        let answers = [Word::from_str("tithe")?, Word::from_str("those")?];
+        assert_hardmode_compliant(&Mock::new(vec!["tithe"]), &answers);
+
+        // On "those", "doubt" does not keep the "t" from "tithe" in place.
+        let result = std::panic::catch_unwind(|| {
+            assert_hardmode_compliant(&Mock::new(vec!["tithe", "doubt"]), &answers)
+        });
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
 //! The wordlists used by Wordle.
 
+use crate::{strategy::Word, PuzzleError, Result};
+
+pub mod daily;
+
 /// Indicies into [GUESSES] of all possible correct answers to a Wordle puzzle.
 ///
 /// Wordle allows you to guess many words, but it will only select some
@@ -1484,4 +1488,261 @@ mod test {
     fn answers_well_defined() {
         assert!(ANSWERS.iter().all(|&n| n < GUESSES.len()))
     }
+
+    #[test]
+    fn custom_wordlists() -> Result<()> {
+        let wordlist = Wordlist::<4>::new(["word", "lord", "word"], ["ward", "word"])?;
+        assert_eq!(
+            wordlist.guesses(),
+            [
+                wordlist.get("lord")?,
+                wordlist.get("ward")?,
+                wordlist.get("word")?
+            ]
+        );
+        assert_eq!(
+            wordlist.answers(),
+            [wordlist.get("ward")?, wordlist.get("word")?]
+        );
+        assert_eq!(wordlist.get("ward")?.to_string(), "ward");
+        assert!(wordlist.get("wart").is_err());
+
+        assert!(Wordlist::<4>::new(["Word"], []).is_err());
+        assert!(Wordlist::<4>::new(["wörd"], []).is_err());
+        assert!(Wordlist::<4>::new([], ["words"]).is_err());
+
+        // Five letter words must be in the built-in wordlist.
+        let wordlist = Wordlist::<5>::new(["tithe"], ["doubt"])?;
+        assert_eq!(wordlist.get("doubt")?, Word::from_str("doubt")?);
+        assert!(Wordlist::<5>::new(["tlamp"], []).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn macro_wordlist_matches() {
+        let macro_guesses: Vec<&str> = include_str!("../wordle_rs_macro/src/guesses.txt")
+            .lines()
+            .collect();
+        assert_eq!(macro_guesses, GUESSES);
+    }
+}
+
+/// A pool of Wordle words that strategies can draw candidates from.
+///
+/// Every word in [`Answers`](WordPool::Answers) is also in
+/// [`Guesses`](WordPool::Guesses), so restricting a strategy to the answer
+/// pool only removes words.
+///
+/// # Examples
+///
+/// ```rust
+/// # use wordle_rs::{words::WordPool, Word};
+/// let cigar = Word::from_str("cigar")?;
+/// let aahed = Word::from_str("aahed")?;
+///
+/// assert!(WordPool::Answers.contains(&cigar));
+/// assert!(!WordPool::Answers.contains(&aahed));
+/// assert!(WordPool::Guesses.contains(&aahed));
+///
+/// assert_eq!(WordPool::Answers.len(), 2315);
+/// assert!(WordPool::Answers.iter().all(|word| word.is_possible_answer()));
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WordPool {
+    /// The words that Wordle may choose as answers, given by [`ANSWERS`].
+    Answers,
+
+    /// The words that Wordle accepts as guesses, given by [`GUESSES`].
+    Guesses,
+}
+
+impl WordPool {
+    /// Returns the number of words in the pool.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Answers => ANSWERS.len(),
+            Self::Guesses => GUESSES.len(),
+        }
+    }
+
+    /// Returns `false`, since neither pool is empty.
+    ///
+    /// This exists to accompany [`len()`](Self::len()).
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns true if `word` is in the pool.
+    pub fn contains(&self, word: &Word) -> bool {
+        match self {
+            Self::Answers => word.is_possible_answer(),
+            Self::Guesses => true,
+        }
+    }
+
+    /// Returns the `n`th word in the pool, if it exists.
+    ///
+    /// Both pools are sorted alphabetically.
+    pub fn get(&self, n: usize) -> Option<Word> {
+        match self {
+            Self::Answers => Word::from_answer_index(n).ok(),
+            Self::Guesses => Word::from_index(n).ok(),
+        }
+    }
+
+    /// Returns the words in the pool in alphabetical order.
+    pub fn words(&self) -> &'static [Word] {
+        match self {
+            Self::Answers => Word::ANSWERS,
+            Self::Guesses => Word::ALL,
+        }
+    }
+
+    /// Returns an iterator over the words in the pool in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = Word> + Clone + 'static {
+        self.words().iter().copied()
+    }
+}
+
+/// Returns an iterator over every word that Wordle accepts as a guess.
+///
+/// The words are in the same order as [`GUESSES`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use wordle_rs::words::{guesses, GUESSES};
+/// assert_eq!(guesses().count(), GUESSES.len());
+/// assert!(guesses().zip(GUESSES).all(|(word, s)| &*word == s));
+/// ```
+pub fn guesses() -> impl Iterator<Item = Word> + Clone + 'static {
+    WordPool::Guesses.iter()
+}
+
+/// Returns an iterator over every word that Wordle may choose as an answer.
+///
+/// The words are in the same order as [`ANSWERS`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use wordle_rs::words::{answers, ANSWERS};
+/// assert_eq!(answers().count(), ANSWERS.len());
+/// assert!(answers().all(|word| word.is_possible_answer()));
+/// ```
+pub fn answers() -> impl Iterator<Item = Word> + Clone + 'static {
+    WordPool::Answers.iter()
+}
+
+/// A wordlist for a variant of Wordle played with words of `N` letters.
+///
+/// The built-in wordlists only hold five letter words, so a wordlist is the
+/// only way to make [`Word`]s of other lengths, which can then be played
+/// with a [`Puzzle`](crate::strategy::Puzzle) of the same length. The
+/// [test harness](crate::Harness) and [`Strategy`](crate::Strategy) still
+/// only play five letter words.
+///
+/// Every answer is also a guess, and both lists are kept in alphabetical
+/// order without duplicates. Since every five letter [`Word`] is in
+/// [`GUESSES`], a five letter wordlist can only hold words from there.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordle_rs::words::Wordlist;
+///
+/// let wordlist = Wordlist::<6>::new(["planet", "plenty", "pliant"], ["plenty"])?;
+/// assert_eq!(wordlist.guesses().len(), 3);
+/// assert_eq!(wordlist.answers()[0], "plenty");
+///
+/// assert!(wordlist.get("plants").is_err());
+/// assert!(Wordlist::<6>::new(["planets"], []).is_err());
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wordlist<const N: usize> {
+    guesses: Vec<Word<N>>,
+    answers: Vec<Word<N>>,
+}
+
+impl<const N: usize> Wordlist<N> {
+    /// Creates a wordlist that accepts `guesses` and `answers` as guesses
+    /// and may choose any of `answers` as the answer.
+    ///
+    /// Returns an error if any word is not made of `N` lowercase ASCII
+    /// letters.
+    pub fn new<'a>(
+        guesses: impl IntoIterator<Item = &'a str>,
+        answers: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self> {
+        let parse = |word: &str| -> Result<Word<N>> {
+            let parsed = Word::from_letters(word).ok_or_else(|| PuzzleError::InvalidWord {
+                word: word.to_string(),
+                len: N,
+            })?;
+            if N == 5 && GUESSES.binary_search(&word).is_err() {
+                return Err(PuzzleError::NotInWordlist(word.to_string()).into());
+            }
+            Ok(parsed)
+        };
+
+        let mut answers = answers.into_iter().map(parse).collect::<Result<Vec<_>>>()?;
+        answers.sort_unstable();
+        answers.dedup();
+
+        let mut guesses = guesses.into_iter().map(parse).collect::<Result<Vec<_>>>()?;
+        guesses.extend_from_slice(&answers);
+        guesses.sort_unstable();
+        guesses.dedup();
+
+        Ok(Wordlist { guesses, answers })
+    }
+
+    /// Gets the word `word` from the wordlist.
+    ///
+    /// Returns an error if `word` is not one of its guesses.
+    pub fn get(&self, word: &str) -> Result<Word<N>> {
+        self.guesses
+            .binary_search_by(|guess| (**guess).cmp(word))
+            .map(|index| self.guesses[index])
+            .map_err(|_| PuzzleError::NotInWordlist(word.to_string()).into())
+    }
+
+    /// Gets every word the wordlist accepts as a guess, in alphabetical
+    /// order.
+    pub fn guesses(&self) -> &[Word<N>] {
+        &self.guesses
+    }
+
+    /// Gets every word the wordlist may choose as an answer, in alphabetical
+    /// order.
+    pub fn answers(&self) -> &[Word<N>] {
+        &self.answers
+    }
+}
+
+/// Builds an array containing the [`Word`] for each entry of [`GUESSES`].
+pub(crate) const fn all_guesses() -> [Word; GUESSES.len()] {
+    let mut words = [Word::from_guess_index(0); GUESSES.len()];
+    let mut i = 0;
+    while i < words.len() {
+        words[i] = Word::from_guess_index(i);
+        i += 1;
+    }
+    words
+}
+
+/// Builds an array containing the [`Word`] for each entry of [`ANSWERS`].
+pub(crate) const fn all_answers() -> [Word; ANSWERS.len()] {
+    let mut words = [Word::from_guess_index(0); ANSWERS.len()];
+    let mut i = 0;
+    while i < words.len() {
+        words[i] = Word::from_guess_index(ANSWERS[i]);
+        i += 1;
+    }
+    words
 }
@@ -0,0 +1,359 @@
+//! The answers to past daily Wordle puzzles.
+//!
+//! Wordle numbers its daily puzzles starting from day 0 on June 19th, 2021.
+//! This module maps those day numbers and their dates to the answers, so
+//! strategies can be scored on the puzzles people actually played instead of
+//! random samples.
+//!
+//! A list of answers is built in, but it only covers the early puzzles. Use
+//! [`DailyAnswers::load_updates()`] or [`DailyAnswers::set()`] to supply
+//! answers for later days.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use wordle_rs::words::daily::{Date, DailyAnswers};
+//!
+//! let daily = DailyAnswers::builtin();
+//! assert_eq!(daily.get(0).unwrap(), "cigar");
+//!
+//! let date: Date = "2022-01-23".parse()?;
+//! assert_eq!(date.day_number(), Some(218));
+//! assert_eq!(daily.on(date).unwrap(), "crimp");
+//! #
+//! # Ok::<_, wordle_rs::WordleError>(())
+//! ```
+
+use std::{fmt::Display, ops::Range, path::Path, str::FromStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{strategy::Word, HarnessError, Result, WordleError};
+
+/// The answers to the first daily puzzles, indexed by day number.
+const BUILTIN: [&str; 241] = [
+    "cigar", "rebut", "sissy", "humph", "awake", "blush", "focal", "evade", "naval", "serve",
+    "heath", "dwarf", "model", "karma", "stink", "grade", "quiet", "bench", "abate", "feign",
+    "major", "death", "fresh", "crust", "stool", "colon", "abase", "marry", "react", "batty",
+    "pride", "floss", "helix", "croak", "staff", "paper", "unfed", "whelp", "trawl", "outdo",
+    "adobe", "crazy", "sower", "repay", "digit", "crate", "cluck", "spike", "mimic", "pound",
+    "maxim", "linen", "unmet", "flesh", "booby", "forth", "first", "stand", "belly", "ivory",
+    "seedy", "print", "yearn", "drain", "bribe", "stout", "panel", "crass", "flume", "offal",
+    "agree", "error", "swirl", "argue", "bleed", "delta", "flick", "totem", "wooer", "front",
+    "shrub", "parry", "biome", "lapel", "start", "greet", "goner", "golem", "lusty", "loopy",
+    "round", "audit", "lying", "gamma", "labor", "islet", "civic", "forge", "corny", "moult",
+    "basic", "salad", "agate", "spicy", "spray", "essay", "fjord", "spend", "kebab", "guild",
+    "aback", "motor", "alone", "hatch", "hyper", "thumb", "dowry", "ought", "belch", "dutch",
+    "pilot", "tweed", "comet", "jaunt", "enema", "steed", "abyss", "growl", "fling", "dozen",
+    "boozy", "erode", "world", "gouge", "click", "briar", "great", "altar", "pulpy", "blurt",
+    "coast", "duchy", "groin", "fixer", "group", "rogue", "badly", "smart", "pithy", "gaudy",
+    "chill", "heron", "vodka", "finer", "surer", "radio", "rouge", "perch", "retch", "wrote",
+    "clock", "tilde", "store", "prove", "bring", "solve", "cheat", "grime", "exult", "usher",
+    "epoch", "triad", "break", "rhino", "viral", "conic", "masse", "sonic", "vital", "trace",
+    "using", "peach", "champ", "baton", "brake", "pluck", "craze", "gripe", "weary", "picky",
+    "acute", "ferry", "aside", "tapir", "troll", "unify", "rebus", "boost", "truss", "siege",
+    "tiger", "banal", "slump", "crank", "gorge", "query", "drink", "favor", "abbey", "tangy",
+    "panic", "solar", "shire", "proxy", "point", "robot", "prick", "wince", "crimp", "knoll",
+    "sugar", "whack", "mount", "perky", "could", "wrung", "light", "those", "moist", "shard",
+    "pleat", "aloft", "skill", "elder", "frame", "humor", "pause", "ulcer", "ultra", "robin",
+    "cynic",
+];
+
+/// A calendar date, used to look up daily puzzles.
+///
+/// Dates can be parsed from and displayed as "YYYY-MM-DD".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// The date of the first daily puzzle, day 0.
+    pub const FIRST_PUZZLE: Date = Date {
+        year: 2021,
+        month: 6,
+        day: 19,
+    };
+
+    /// Creates a new date, returning an error if it does not exist.
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            _ => 0,
+        };
+
+        if day == 0 || day > days_in_month {
+            return Err(HarnessError::InvalidDate(format!("{}-{}-{}", year, month, day)).into());
+        }
+
+        Ok(Date { year, month, day })
+    }
+
+    /// Gets the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Gets the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Gets the day of the month, starting from 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Gets the date of the puzzle with the given day number.
+    pub fn of_puzzle(day: u32) -> Self {
+        Self::from_days(Self::FIRST_PUZZLE.days() + day as i64)
+    }
+
+    /// Gets the day number of the puzzle on this date, or `None` if the date
+    /// is before the first puzzle.
+    pub fn day_number(&self) -> Option<u32> {
+        let days = self.days() - Self::FIRST_PUZZLE.days();
+        if days >= 0 {
+            Some(days as u32)
+        } else {
+            None
+        }
+    }
+
+    /// Counts the days since 1970-01-01.
+    fn days(&self) -> i64 {
+        // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil.
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let month = self.month as i64;
+        let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// The inverse of [`days()`](Self::days()).
+    fn from_days(days: i64) -> Self {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days.
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+
+        Date { year, month, day }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = WordleError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || WordleError::from(HarnessError::InvalidDate(s.to_string()));
+
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let year = next()?.parse().map_err(|_| invalid())?;
+        let month = next()?.parse().map_err(|_| invalid())?;
+        let day = next()?.parse().map_err(|_| invalid())?;
+
+        Date::new(year, month, day)
+    }
+}
+
+/// The answers to daily puzzles, indexed by day number.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DailyAnswers {
+    answers: Vec<Option<Word>>,
+}
+
+impl DailyAnswers {
+    /// Creates a list holding the built-in answers.
+    pub fn builtin() -> Self {
+        DailyAnswers {
+            answers: BUILTIN
+                .iter()
+                .map(|s| Some(Word::from_str(s).unwrap()))
+                .collect(),
+        }
+    }
+
+    /// Creates an empty list with no known answers.
+    pub fn empty() -> Self {
+        DailyAnswers {
+            answers: Vec::new(),
+        }
+    }
+
+    /// Gets the number of days up to and including the last known answer.
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Returns true if no answers are known.
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    /// Gets the answer to the puzzle with the given day number, if known.
+    pub fn get(&self, day: u32) -> Option<Word> {
+        self.answers.get(day as usize).copied().flatten()
+    }
+
+    /// Gets the answer to the puzzle on the given date, if known.
+    pub fn on(&self, date: Date) -> Option<Word> {
+        self.get(date.day_number()?)
+    }
+
+    /// Sets the answer to the puzzle with the given day number, replacing any
+    /// answer already known for that day.
+    pub fn set(&mut self, day: u32, answer: Word) {
+        let day = day as usize;
+        if day >= self.answers.len() {
+            self.answers.resize(day + 1, None);
+        }
+        self.answers[day] = Some(answer);
+    }
+
+    /// Reads answers from a file and adds them to this list.
+    ///
+    /// Each non-empty line of the file must hold a day, either as a day
+    /// number or as a date in the form "YYYY-MM-DD", followed by whitespace
+    /// and the answer. Lines starting with `#` are ignored.
+    ///
+    /// ```text
+    /// # later puzzles
+    /// 241 aroma
+    /// 2022-02-16 awful
+    /// ```
+    pub fn load_updates(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| HarnessError::WordsRead(Box::new(e)))?;
+        self.apply_updates(&contents)
+    }
+
+    fn apply_updates(&mut self, contents: &str) -> Result<()> {
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let (day, word) = match (parts.next(), parts.next()) {
+                (Some(day), Some(word)) => (day, word),
+                _ => return Err(HarnessError::InvalidDate(line.to_string()).into()),
+            };
+
+            let day = match day.parse::<u32>() {
+                Ok(n) => n,
+                Err(_) => day
+                    .parse::<Date>()?
+                    .day_number()
+                    .ok_or_else(|| HarnessError::InvalidDate(day.to_string()))?,
+            };
+
+            self.set(day, Word::from_str(word)?);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the answers to every puzzle from the start of `dates` up to but
+    /// not including the end.
+    ///
+    /// Returns an error if the answer for any of the days is not known.
+    pub fn range(&self, dates: Range<Date>) -> Result<Vec<Word>> {
+        let start = dates.start.day_number().unwrap_or(0);
+        let end = dates.end.day_number().unwrap_or(0);
+
+        (start..end)
+            .map(|day| {
+                self.get(day)
+                    .ok_or_else(|| HarnessError::NoDailyAnswer(Date::of_puzzle(day)).into())
+            })
+            .collect()
+    }
+}
+
+impl Default for DailyAnswers {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn builtin_answers_are_valid() {
+        let daily = DailyAnswers::builtin();
+        assert_eq!(daily.len(), BUILTIN.len());
+        for day in 0..daily.len() as u32 {
+            assert!(daily.get(day).unwrap().is_possible_answer());
+        }
+    }
+
+    #[test]
+    fn dates_round_trip() -> Result<()> {
+        assert_eq!(Date::of_puzzle(0), Date::FIRST_PUZZLE);
+        assert_eq!(Date::of_puzzle(196), Date::new(2022, 1, 1)?);
+        assert_eq!(Date::new(2022, 1, 1)?.day_number(), Some(196));
+        assert_eq!(Date::new(2021, 6, 18)?.day_number(), None);
+
+        for day in 0..2000 {
+            let date = Date::of_puzzle(day);
+            assert_eq!(date.day_number(), Some(day));
+            assert_eq!(date.to_string().parse::<Date>()?, date);
+        }
+
+        assert!(Date::new(2022, 2, 29).is_err());
+        assert!(Date::new(2024, 2, 29).is_ok());
+        assert!("2022-13-01".parse::<Date>().is_err());
+        assert!("yesterday".parse::<Date>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn updates() -> Result<()> {
+        let mut daily = DailyAnswers::builtin();
+        daily.apply_updates("# comment\n\n241 aroma\n2022-02-17 awful\n")?;
+
+        assert_eq!(daily.get(241).unwrap(), "aroma");
+        assert_eq!(daily.get(242), None);
+        assert_eq!(daily.get(243).unwrap(), "awful");
+
+        assert!(daily.apply_updates("241 zzzzz").is_err());
+        assert!(daily.apply_updates("aroma").is_err());
+
+        let words = daily.range(Date::of_puzzle(240)..Date::of_puzzle(242))?;
+        assert_eq!(words.len(), 2);
+        assert!(daily
+            .range(Date::of_puzzle(240)..Date::of_puzzle(244))
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! Procedural macros for [`wordle_rs`](https://docs.rs/wordle_rs).
+//!
+//! These are re-exported by `wordle_rs` with the `macros` feature, which is
+//! enabled by default, so there is no need to depend on this crate directly.
+
+#![deny(missing_docs)]
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Every word that Wordle accepts as a guess, in the same order as
+/// `wordle_rs::words::GUESSES`.
+const GUESSES: &str = include_str!("guesses.txt");
+
+/// Creates a [`Word`](https://docs.rs/wordle_rs/latest/wordle_rs/strategy/struct.Word.html)
+/// from a string literal, checking that it is in the wordlist at compile time.
+///
+/// This expands to an index into `Word::ALL`, so it cannot fail at runtime
+/// and can be used in constants.
+///
+/// # Examples
+///
+/// ```rust
+/// use wordle_rs::{strategy::Word, word};
+///
+/// const FIRST: Word = word!("crane");
+/// assert_eq!(FIRST, Word::from_str("crane")?);
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+///
+/// Words that are not in the wordlist do not compile:
+///
+/// ```rust,compile_fail
+/// use wordle_rs::word;
+///
+/// let typo = word!("crnae");
+/// ```
+#[proc_macro]
+pub fn word(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        (Some(tree), _) => return error("expected a single string literal", tree.span()),
+        (None, _) => return error("expected a single string literal", Span::call_site()),
+    };
+
+    let repr = literal.to_string();
+    let word = match repr.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(word) => word,
+        None => return error("expected a single string literal", literal.span()),
+    };
+
+    match GUESSES.lines().position(|guess| guess == word) {
+        Some(index) => format!("::wordle_rs::strategy::Word::ALL[{}]", index)
+            .parse()
+            .unwrap(),
+        None => error(
+            &format!("the string \"{}\" is not in the Wordle wordlist", word),
+            literal.span(),
+        ),
+    }
+}
+
+/// Produces a `compile_error!` invocation with `message` pointing at `span`.
+fn error(message: &str, span: Span) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::from(message).into());
+    args.set_span(span);
+
+    [
+        TokenTree::from(Ident::new("compile_error", span)),
+        bang.into(),
+        args.into(),
+    ]
+    .into_iter()
+    .collect()
+}
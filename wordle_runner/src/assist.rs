@@ -0,0 +1,73 @@
+//! Solving the real daily puzzle with a strategy's help.
+
+use std::io::{self, BufRead, Write};
+
+use clap::Args;
+use wordle_rs::{strategy::assist::Assistant, Grade, WordleError};
+
+use crate::StrategyName;
+
+#[derive(Debug, Args)]
+pub struct AssistArgs {
+    /// The strategy to suggest guesses
+    #[clap(arg_enum)]
+    strategy: StrategyName,
+
+    /// Makes the strategy follow hardmode rules
+    #[clap(long)]
+    hardmode: bool,
+}
+
+/// Suggests guesses and reads back the grades the official game gave them.
+pub fn assist(args: AssistArgs) -> Result<(), WordleError> {
+    let strategy = args.strategy.build();
+    let mut assistant = Assistant::new(strategy.as_ref()).hardmode(args.hardmode);
+
+    println!("Play each suggested guess, then type the colors the game shows for it:");
+    println!("g for green, y for yellow and b for gray, like \"bygbb\". Type \"quit\" to stop.");
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    while let Some(guess) = assistant.suggest()? {
+        println!();
+        println!("Guess {}: {}", assistant.history().len() + 1, guess);
+
+        loop {
+            print!("colors> ");
+            io::stdout().flush()?;
+            let input = match lines.next() {
+                Some(line) => line?,
+                None => return Ok(()),
+            };
+
+            if input.trim() == "quit" {
+                return Ok(());
+            }
+            match Grade::parse_row(&input) {
+                Ok(grades) => {
+                    assistant.feed(grades)?;
+                    break;
+                }
+                Err(e) => println!("{}", e),
+            }
+        }
+    }
+
+    println!();
+    if assistant.solved() {
+        let rows: Vec<_> = assistant
+            .history()
+            .iter()
+            .map(|(_, grades)| Grade::row_emoji(grades))
+            .collect();
+        println!(
+            "Solved in {}!\n\n{}",
+            assistant.history().len(),
+            rows.join("\n")
+        );
+    } else {
+        println!("{} has no more guesses to suggest.", args.strategy.name());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! Managing the summaries saved as baselines.
+
+use std::{fs, io, path::Path, time::SystemTime};
+
+use clap::Subcommand;
+use wordle_rs::{harness::HarnessBuilder, perf::Format, HarnessError, Summary, WordleError};
+
+use crate::{config, StrategyName};
+
+#[derive(Debug, Subcommand)]
+pub enum BaselineCommand {
+    /// Runs a strategy and saves its summary as a baseline
+    Save {
+        /// The strategy to run
+        #[clap(arg_enum)]
+        strategy: StrategyName,
+
+        /// The name to save the baseline with [default: the strategy's name]
+        #[clap(long)]
+        name: Option<String>,
+
+        /// Runs on this many random answers instead of all of them
+        #[clap(long, short)]
+        num: Option<usize>,
+
+        /// Seeds the choice of random answers
+        #[clap(long)]
+        seed: Option<u64>,
+
+        /// Overwrites a baseline with the same name
+        #[clap(long)]
+        force: bool,
+    },
+
+    /// Lists the saved baselines
+    List,
+
+    /// Prints a saved baseline
+    Show {
+        /// The name of the baseline
+        name: String,
+    },
+
+    /// Compares two saved baselines
+    Diff {
+        /// The name of the baseline to compare
+        current: String,
+
+        /// The name of the baseline to compare against
+        baseline: String,
+
+        /// The significance level of the statistical tests
+        #[clap(long, default_value = "0.05")]
+        alpha: f64,
+    },
+
+    /// Removes a saved baseline
+    Rm {
+        /// The name of the baseline
+        name: String,
+    },
+
+    /// Removes all but the most recently saved baselines
+    Prune {
+        /// How many baselines to keep
+        #[clap(long)]
+        keep: usize,
+
+        /// Prints the baselines that would be removed without removing them
+        #[clap(long)]
+        dry_run: bool,
+    },
+}
+
+pub fn baseline(command: BaselineCommand, dir: &Path) -> Result<(), WordleError> {
+    match command {
+        BaselineCommand::Save {
+            strategy,
+            name,
+            num,
+            seed,
+            force,
+        } => {
+            let mut builder = HarnessBuilder::new();
+            builder
+                .verbose(true)
+                .add_strategy(strategy.build(), None)
+                .words(config::words(num, seed));
+
+            let record = builder.build()?.run()?;
+            let name = name.as_deref().unwrap_or_else(|| strategy.name());
+            let path = record[0].to_summary().save(name, dir, force)?;
+            println!("Saved the baseline to {}", path.display());
+            Ok(())
+        }
+        BaselineCommand::List => list(dir),
+        BaselineCommand::Show { name } => Summary::from_saved(&name, dir)?
+            .print(Summary::print_options().histogram(true).diagnostics(true)),
+        BaselineCommand::Diff {
+            current,
+            baseline,
+            alpha,
+        } => diff(&current, &baseline, alpha, dir),
+        BaselineCommand::Rm { name } => remove(&name, dir),
+        BaselineCommand::Prune { keep, dry_run } => prune(keep, dry_run, dir),
+    }
+}
+
+/// Prints the comparison between two saved summaries.
+pub fn diff(current: &str, baseline: &str, alpha: f64, dir: &Path) -> Result<(), WordleError> {
+    let current = Summary::from_saved(current, dir)?;
+    let baseline = Summary::from_saved(baseline, dir)?;
+    current.print(
+        Summary::print_options()
+            .histogram(true)
+            .diagnostics(true)
+            .alpha(alpha)
+            .compare(&baseline),
+    )
+}
+
+/// Finds the name and format of each summary saved in `dir`.
+fn saved(dir: &Path) -> Result<Vec<(String, Format)>, WordleError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(HarnessError::BaselineRead(Box::new(e)).into()),
+    };
+
+    let mut baselines = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| HarnessError::BaselineRead(Box::new(e)))?
+            .path();
+        let format = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Format::from_extension);
+        let name = path.file_stem().and_then(|stem| stem.to_str());
+        if let (Some(format), Some(name)) = (format, name) {
+            // Records are saved alongside baselines as "[name].record.json".
+            if !name.ends_with(".record") {
+                baselines.push((name.to_string(), format));
+            }
+        }
+    }
+    baselines.sort();
+
+    Ok(baselines)
+}
+
+fn list(dir: &Path) -> Result<(), WordleError> {
+    let baselines = saved(dir)?;
+    if baselines.is_empty() {
+        println!("No baselines saved in {}", dir.display());
+    }
+
+    for (name, _) in baselines {
+        match Summary::from_saved(&name, dir) {
+            Ok(summary) => println!(
+                "{:<20} {} ({} words, {:.2}% solved)",
+                name,
+                summary,
+                summary.num_tried(),
+                summary.frac_solved() * 100.
+            ),
+            Err(e) => println!("{:<20} could not be read: {}", name, e),
+        }
+    }
+
+    Ok(())
+}
+
+fn remove(name: &str, dir: &Path) -> Result<(), WordleError> {
+    let (_, format) = saved(dir)?
+        .into_iter()
+        .find(|(saved, _)| saved == name)
+        .ok_or_else(|| {
+            HarnessError::BaselineRead(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no baseline named {} in {}", name, dir.display()),
+            )))
+        })?;
+
+    let path = dir.join(name).with_extension(format.extension());
+    fs::remove_file(&path).map_err(|e| HarnessError::BaselineRead(Box::new(e)))?;
+    println!("Removed {}", path.display());
+
+    Ok(())
+}
+
+fn prune(keep: usize, dry_run: bool, dir: &Path) -> Result<(), WordleError> {
+    let mut baselines = saved(dir)?
+        .into_iter()
+        .map(|(name, format)| {
+            let path = dir.join(name).with_extension(format.extension());
+            let modified = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            (modified, path)
+        })
+        .collect::<Vec<_>>();
+    // Newest first, so everything past `keep` is removed.
+    baselines.sort_by(|a, b| b.cmp(a));
+
+    let pruned = baselines.iter().skip(keep).collect::<Vec<_>>();
+    if pruned.is_empty() {
+        println!("Nothing to prune in {}", dir.display());
+    }
+    for (_, path) in pruned {
+        if dry_run {
+            println!("Would remove {}", path.display());
+        } else {
+            fs::remove_file(path).map_err(|e| HarnessError::BaselineRead(Box::new(e)))?;
+            println!("Removed {}", path.display());
+        }
+    }
+
+    Ok(())
+}
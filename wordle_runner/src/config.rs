@@ -0,0 +1,114 @@
+//! Configuring the runner with environment variables.
+//!
+//! Each option is taken from the first of these that sets it:
+//!
+//! 1. its command line flag,
+//! 2. its `WORDLE_RS_*` environment variable,
+//! 3. a `.env` file in the current directory, and
+//! 4. the default.
+//!
+//! Empty variables are treated as unset.
+
+use std::{fmt::Display, fs, io, path::Path, str::FromStr};
+
+use clap::ErrorKind;
+use wordle_rs::harness::WordSelection;
+
+/// Describes the environment variables in `wordle_runner help`.
+pub const ENV_HELP: &str = "\
+ENVIRONMENT:
+    WORDLE_RS_WORDS          How many random answers to run on, like --num
+    WORDLE_RS_SEED           Seeds the choice of random answers, like --seed
+    WORDLE_RS_PARALLEL       Set to false to run one puzzle at a time, like --sequential
+    WORDLE_RS_BASELINE_DIR   The directory baselines are saved in, like --dir
+    WORDLE_RS_FORMAT         The format of reports, like --format
+
+Flags take precedence over these variables, which take precedence over a .env
+file in the current directory.";
+
+/// Sets the variables from a `.env` file that are not already set.
+///
+/// Each line of the file is `NAME=value`, optionally after `export` and with
+/// the value in quotes. Blank lines and lines starting with `#` are skipped.
+/// A missing file is not an error.
+pub fn load_dotenv(path: &Path) -> io::Result<()> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if let Some((name, value)) = line.split_once('=') {
+            let name = name.trim();
+            let value = unquote(value.trim());
+            if std::env::var_os(name).is_none() {
+                std::env::set_var(name, value);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if value.len() >= 2 && value.starts_with(quote) && value.ends_with(quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+    value
+}
+
+/// Reads and parses an environment variable.
+///
+/// Exits with a usage error, like an invalid flag would, if the value does
+/// not parse.
+pub fn var<T>(name: &str) -> Option<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    var_with(name, str::parse)
+}
+
+/// Reads an environment variable and parses it with `parse`.
+///
+/// See [`var()`].
+pub fn var_with<T, E: Display>(name: &str, parse: impl FnOnce(&str) -> Result<T, E>) -> Option<T> {
+    let value = std::env::var(name).ok().filter(|value| !value.is_empty())?;
+    match parse(&value) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => clap::Error::raw(
+            ErrorKind::InvalidValue,
+            format!("Invalid value {:?} for {}: {}\n", value, name, e),
+        )
+        .exit(),
+    }
+}
+
+/// Parses a boolean like `true`, `0` or `no`.
+pub fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Ok(true),
+        "false" | "0" | "no" | "off" => Ok(false),
+        _ => Err("expected true or false".to_string()),
+    }
+}
+
+/// Chooses the answers to run on from `--num` and `--seed`, falling back to
+/// the environment, or all of the answers.
+pub fn words(num: Option<usize>, seed: Option<u64>) -> WordSelection {
+    match num.or_else(|| var("WORDLE_RS_WORDS")) {
+        Some(n) => WordSelection::RandomSample {
+            n,
+            seed: seed.or_else(|| var("WORDLE_RS_SEED")),
+        },
+        None => WordSelection::All,
+    }
+}
@@ -1,22 +1,446 @@
-use wordle_rs::{harness::Harness, strategy::Word, WordleError};
-use wordle_strategies::{Basic, Common};
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::{ArgEnum, Args, Parser, Subcommand};
+use wordle_rs::{
+    harness::{get_save_dir, HarnessBuilder, PlannedBaseline, WordSelection},
+    scaffold::Scaffold,
+    Harness, HarnessError, Metric, Record, RegressionThresholds, Schedule, Strategy, Summary,
+    WordleError,
+};
+use wordle_strategies::{Basic, Common, CommonEasy, Mcts, Minimax, Positional};
+
+mod assist;
+mod baseline;
+mod config;
+mod play;
+mod watch;
+
+/// Runs and compares Wordle strategies written with wordle_rs.
+#[derive(Debug, Parser)]
+#[clap(version, about, after_help = config::ENV_HELP)]
+struct Cli {
+    /// The directory baselines and records are saved in [default:
+    /// $WORDLE_RS_BASELINE_DIR, $WORDLE_BASELINE_DIR, or ./wordle_baseline]
+    #[clap(long, global = true)]
+    dir: Option<PathBuf>,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Runs strategies in the test harness and prints a report
+    Run(RunArgs),
+
+    /// Compares two saved summaries, like `baseline diff`
+    Compare {
+        /// The name of the summary to compare
+        current: String,
+
+        /// The name of the summary to compare against
+        baseline: String,
+    },
+
+    /// Lists the words that each strategy now solves, misses, or needs more
+    /// or fewer guesses for, between two saved records
+    Diff {
+        /// The name of the record to compare
+        current: String,
+
+        /// The name of the record to compare against
+        baseline: String,
+    },
+
+    /// Runs a strategy several times on the same answers and lists any word
+    /// it did not play the same way each time
+    Determinism {
+        /// The strategy to check
+        #[clap(arg_enum)]
+        strategy: StrategyName,
+
+        /// The number of times to run the strategy
+        #[clap(long, default_value = "3")]
+        trials: usize,
+
+        /// Runs on this many random answers instead of all of them
+        #[clap(long, short)]
+        num: Option<usize>,
+    },
+
+    /// Renders a saved record as a report
+    Report {
+        /// The name the record was saved with
+        name: String,
+
+        /// The format of the report [default: markdown]
+        #[clap(long, arg_enum)]
+        format: Option<ReportFormat>,
+
+        /// Writes the report to a file instead of printing it
+        #[clap(long, short)]
+        output: Option<PathBuf>,
+    },
+
+    /// Saves, inspects and removes baselines
+    #[clap(subcommand)]
+    Baseline(baseline::BaselineCommand),
+
+    /// Plays Wordle in the terminal
+    Play(play::PlayArgs),
+
+    /// Suggests guesses for a puzzle played elsewhere, like the daily puzzle
+    Assist(assist::AssistArgs),
+
+    /// Rebuilds and reruns a strategy crate each time it changes
+    Watch(watch::WatchArgs),
+
+    /// Generates a crate for a new strategy in the current directory
+    New {
+        /// The name of the crate
+        name: String,
+
+        /// Makes the strategy play hardmode
+        #[clap(long)]
+        hardmode: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// The strategies to run
+    #[clap(arg_enum, required = true)]
+    strategies: Vec<StrategyName>,
+
+    /// Runs on this many random answers instead of all of them
+    #[clap(long, short)]
+    num: Option<usize>,
+
+    /// Seeds the choice of random answers
+    #[clap(long)]
+    seed: Option<u64>,
+
+    /// Runs on the words that the strategies in a record saved with
+    /// --save-record found hardest, as many as --num [default: 100]
+    #[clap(long, value_name = "RECORD", conflicts_with = "seed")]
+    hardest: Option<String>,
+
+    /// Compares the other strategies against the first one
+    #[clap(long)]
+    baseline: bool,
+
+    /// Compares the strategies against the saved baselines with this name,
+    /// where * matches anything, like "minimax-*"; can be given more than
+    /// once
+    #[clap(long, value_name = "NAME", multiple_occurrences = true)]
+    load_baseline: Vec<String>,
+
+    /// Saves each strategy's summary as a baseline named after it
+    #[clap(long)]
+    save: bool,
+
+    /// Replaces the baseline with this name by the best strategy of the run
+    /// if it is significantly better, or saves it if there is none yet
+    #[clap(long, value_name = "NAME")]
+    update_baseline: Option<String>,
+
+    /// The measure --update-baseline uses to pick the best strategy
+    #[clap(long, arg_enum, default_value = "solve-rate")]
+    metric: MetricName,
+
+    /// Saves every guess made in the run as a record with this name
+    #[clap(long, value_name = "NAME")]
+    save_record: Option<String>,
+
+    /// Runs the strategies one puzzle at a time
+    #[clap(long)]
+    sequential: bool,
+
+    /// Runs each strategy on each word separately, so fast strategies do not
+    /// wait for slow ones
+    #[clap(long, conflicts_with = "sequential")]
+    interleave: bool,
+
+    /// Prints a report at the end, or each result as a line of JSON as soon
+    /// as it is done
+    #[clap(long, arg_enum, default_value = "report")]
+    output: RunOutput,
+
+    /// Exits with status 3 if a strategy regressed from its baselines: the
+    /// ones from --load-baseline, or else the one saved under its name
+    #[clap(long)]
+    fail_on_regression: bool,
+
+    /// Exits with status 3 if a strategy solves less than this fraction of
+    /// the puzzles, like 0.98
+    #[clap(long, value_name = "RATE")]
+    fail_below_solve_rate: Option<f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ArgEnum)]
+pub enum StrategyName {
+    Basic,
+    Common,
+    CommonEasy,
+    Minimax,
+    Mcts,
+    Positional,
+}
+
+impl StrategyName {
+    fn name(self) -> &'static str {
+        match self {
+            StrategyName::Basic => "basic",
+            StrategyName::Common => "common",
+            StrategyName::CommonEasy => "common-easy",
+            StrategyName::Minimax => "minimax",
+            StrategyName::Mcts => "mcts",
+            StrategyName::Positional => "positional",
+        }
+    }
+
+    fn build(self) -> Box<dyn Strategy> {
+        match self {
+            StrategyName::Basic => Box::new(Basic::new()),
+            StrategyName::Common => Box::new(Common),
+            StrategyName::CommonEasy => Box::new(CommonEasy),
+            StrategyName::Minimax => Box::new(Minimax::new()),
+            StrategyName::Mcts => Box::new(Mcts::new()),
+            StrategyName::Positional => Box::new(Positional::new()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ArgEnum)]
+enum MetricName {
+    SolveRate,
+    MeanGuesses,
+}
+
+impl From<MetricName> for Metric {
+    fn from(metric: MetricName) -> Self {
+        match metric {
+            MetricName::SolveRate => Metric::SolveRate,
+            MetricName::MeanGuesses => Metric::MeanGuesses,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ArgEnum)]
+enum RunOutput {
+    Report,
+    Jsonl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ArgEnum)]
+enum ReportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+/// The exit status when `run` finishes but a strategy fails a check from
+/// `--fail-on-regression` or `--fail-below-solve-rate`, or when
+/// `determinism` finds a word played differently.
+///
+/// Errors exit with 1, and invalid arguments with 2.
+const GATE_FAILED: i32 = 3;
 
 fn main() -> Result<(), WordleError> {
-    let harness = Harness::new()
+    config::load_dotenv(Path::new(".env"))?;
+    let cli = Cli::parse();
+    let dir = cli.dir.or_else(|| config::var("WORDLE_RS_BASELINE_DIR"));
+    let dir = get_save_dir(dir.as_deref())?;
+
+    match cli.command {
+        Command::Run(args) => run(args, &dir),
+        Command::Compare { current, baseline } => baseline::diff(&current, &baseline, 0.05, &dir),
+        Command::Diff { current, baseline } => {
+            let current = Record::load(&current, &dir)?;
+            print!("{}", current.diff(&Record::load(&baseline, &dir)?));
+            Ok(())
+        }
+        Command::Determinism {
+            strategy,
+            trials,
+            num,
+        } => {
+            let words = match num {
+                Some(n) => WordSelection::RandomSample { n, seed: None },
+                None => WordSelection::All,
+            };
+            let report = Harness::check_determinism(strategy.build(), words, trials)?;
+            print!("{}", report);
+            if !report.is_deterministic() {
+                std::process::exit(GATE_FAILED);
+            }
+            Ok(())
+        }
+        Command::Report {
+            name,
+            format,
+            output,
+        } => {
+            let record = Record::load(&name, &dir)?;
+            let format = format
+                .or_else(|| {
+                    config::var_with("WORDLE_RS_FORMAT", |s| ReportFormat::from_str(s, true))
+                })
+                .unwrap_or(ReportFormat::Markdown);
+            let report = match format {
+                ReportFormat::Markdown => record.to_markdown()?,
+                ReportFormat::Html => record.to_html()?,
+                ReportFormat::Json => record.to_json()?,
+            };
+            match output {
+                Some(path) => {
+                    fs::write(path, report).map_err(|e| HarnessError::RecordWrite(Box::new(e)))?
+                }
+                None => println!("{}", report),
+            }
+            Ok(())
+        }
+        Command::Baseline(command) => baseline::baseline(command, &dir),
+        Command::Play(args) => play::play(args),
+        Command::Assist(args) => assist::assist(args),
+        Command::Watch(args) => watch::watch(args),
+        Command::New { name, hardmode } => {
+            let path = Scaffold::new(name).hardmode(hardmode).write(".")?;
+            println!("Created {}", path.display());
+            Ok(())
+        }
+    }
+}
+
+fn run(args: RunArgs, dir: &Path) -> Result<(), WordleError> {
+    let parallel = !args.sequential
+        && config::var_with("WORDLE_RS_PARALLEL", config::parse_bool).unwrap_or(true);
+    let mut builder = HarnessBuilder::new();
+    builder
         .verbose(true)
-        .parallel(true)
-        .add_strategy(Box::new(Common), None)
-        .add_strategy(
-            Box::new(Basic::new().first_word(Word::from_str("qajaq").unwrap())),
-            None,
-        )
-        // .add_strategy(Box::new(
-        //     Basic::new().first_word(Word::from_str("qajaq").unwrap()),
-        // ))
-        .load_baseline("basic_pints", None)?
-        // .test_num(200);
-        .test_all();
-    let perfs = harness.run()?;
-
-    perfs.print_report()
+        .parallel(parallel)
+        .words(match &args.hardest {
+            Some(name) => WordSelection::Hardest {
+                from_record: dir.join(format!("{}.record.json", name)),
+                n: args
+                    .num
+                    .or_else(|| config::var("WORDLE_RS_WORDS"))
+                    .unwrap_or(100),
+            },
+            None => config::words(args.num, args.seed),
+        });
+    if args.interleave {
+        builder.schedule(Schedule::Interleaved);
+    }
+
+    for (i, strategy) in args.strategies.iter().enumerate() {
+        builder.add_strategy(strategy.build(), None);
+        if i == 0 && args.baseline {
+            builder.and_baseline();
+        }
+    }
+    for pattern in &args.load_baseline {
+        builder.load_baselines(pattern, dir);
+    }
+    if let Some(name) = &args.update_baseline {
+        builder.update_baseline_if_better(name, dir, args.metric.into());
+    }
+    let mut harness = builder.build()?;
+
+    // These are loaded before the run, since --save overwrites them.
+    let baselines = if !args.fail_on_regression {
+        Vec::new()
+    } else if args.load_baseline.is_empty() {
+        args.strategies
+            .iter()
+            .filter_map(|strategy| match Summary::from_saved(strategy.name(), dir) {
+                Ok(summary) => Some(summary),
+                Err(_) => {
+                    eprintln!("No baseline for {}, so it cannot regress", strategy.name());
+                    None
+                }
+            })
+            .collect()
+    } else {
+        harness
+            .plan()
+            .baselines
+            .iter()
+            .filter_map(|baseline| match baseline {
+                PlannedBaseline::Saved { name, .. } => Some(Summary::from_saved(name, dir)),
+                PlannedBaseline::Run(_) => None,
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    let record = match args.output {
+        RunOutput::Report => {
+            let record = harness.run()?;
+            record.print_report()?;
+            record
+        }
+        RunOutput::Jsonl => {
+            let (handle, results) = harness.run_streaming();
+            let stdout = io::stdout();
+            let mut stdout = stdout.lock();
+            for result in results {
+                writeln!(stdout, "{}", result.to_json()?)?;
+                stdout.flush()?;
+            }
+            handle.join()?
+        }
+    };
+
+    // The harness would save to the default directory and refuse to
+    // overwrite, so the baselines are saved here instead.
+    if args.save {
+        for (strategy, perf) in args.strategies.iter().zip(record.iter()) {
+            if perf.disqualification().is_none() {
+                let path = perf.to_summary().save(strategy.name(), dir, true)?;
+                eprintln!("Saved the baseline to {}", path.display());
+            }
+        }
+    }
+
+    if let Some(name) = &args.save_record {
+        let path = record.save(name, dir, true)?;
+        eprintln!("Saved the record to {}", path.display());
+    }
+
+    if let Some(update) = record.info().and_then(|info| info.baseline_update()) {
+        eprintln!("{}", update);
+    }
+
+    let mut failures = Vec::new();
+    if let Err(report) = record.check_regressions(&baselines, RegressionThresholds::new()) {
+        failures.extend(report.regressions.iter().map(ToString::to_string));
+    }
+    if let Some(rate) = args.fail_below_solve_rate {
+        for perf in record.iter() {
+            if let Some(disqualification) = perf.disqualification() {
+                failures.push(format!("{} was disqualified: {}", perf, disqualification));
+            } else if perf.frac_solved() < rate {
+                failures.push(format!(
+                    "{}: solved {:.1}% of puzzles, below {:.1}%",
+                    perf,
+                    perf.frac_solved() * 100.,
+                    rate * 100.
+                ));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        eprintln!("{} check(s) failed:", failures.len());
+        for failure in &failures {
+            eprintln!("- {}", failure);
+        }
+        std::process::exit(GATE_FAILED);
+    }
+
+    Ok(())
 }
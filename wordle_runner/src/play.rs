@@ -0,0 +1,150 @@
+//! Playing Wordle in the terminal.
+
+use std::io::{self, BufRead, Write};
+
+use clap::Args;
+use wordle_rs::{
+    harness::WordSelection,
+    testkit::ScriptedPuzzle,
+    words::daily::{DailyAnswers, Date},
+    Attempts, Grade, HarnessError, Puzzle, PuzzleError, Word, WordleError,
+};
+
+use crate::StrategyName;
+
+#[derive(Debug, Args)]
+pub struct PlayArgs {
+    /// Plays the daily puzzle from a date, like 2022-01-23, instead of a
+    /// random answer
+    #[clap(long)]
+    date: Option<String>,
+
+    /// Plays hardmode, where each guess must use every hint revealed so far
+    #[clap(long)]
+    hardmode: bool,
+
+    /// Lets you ask a strategy for hints
+    #[clap(long, arg_enum, value_name = "STRATEGY")]
+    hint: Option<StrategyName>,
+}
+
+/// Plays one game, reading guesses from stdin.
+///
+/// The guesses are graded by [`Puzzle::check()`], so the rules, including
+/// hardmode, are the ones strategies play by in the test harness.
+pub fn play(args: PlayArgs) -> Result<(), WordleError> {
+    let answer = match &args.date {
+        Some(date) => {
+            let date: Date = date.parse()?;
+            DailyAnswers::builtin()
+                .on(date)
+                .ok_or(HarnessError::NoDailyAnswer(date))?
+        }
+        None => WordSelection::RandomSample { n: 1, seed: None }.resolve()?[0],
+    };
+
+    // A hint is the guess the strategy made at the same point in its own
+    // game on this puzzle, played by its own rules.
+    let hints = match args.hint {
+        Some(strategy) => {
+            let mut game = ScriptedPuzzle::new(answer);
+            game.play(strategy.build().as_ref())?;
+            Some(game.guesses())
+        }
+        None => None,
+    };
+
+    // Only strategies get honest attempts. Cheating attempts are checked by
+    // the same rules, and poisoning this puzzle does not matter.
+    let mut puzzle = Puzzle::new(answer);
+    let mut attempts = Attempts::cheat(args.hardmode);
+    let color = std::env::var_os("NO_COLOR").is_none();
+
+    println!(
+        "Guess the five-letter word in six tries{}.",
+        if args.hardmode { " on hardmode" } else { "" }
+    );
+    match args.hint {
+        Some(strategy) => println!(
+            "Type \"hint\" to see what {} would guess, or \"quit\" to give up.",
+            strategy.name()
+        ),
+        None => println!("Type \"quit\" to give up."),
+    }
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    while !attempts.finished() && !attempts.solved(&answer) {
+        print!("{}> ", attempts.len() + 1);
+        io::stdout().flush()?;
+        let input = match lines.next() {
+            Some(line) => line?.trim().to_lowercase(),
+            None => break,
+        };
+
+        match input.as_str() {
+            "" => continue,
+            "quit" => break,
+            "hint" => {
+                match &hints {
+                    Some(hints) => match hints.get(attempts.len()) {
+                        Some(guess) => println!("Hint: {}", guess),
+                        None => println!("The strategy has no more guesses to suggest."),
+                    },
+                    None => println!("Start the game with --hint <STRATEGY> to get hints."),
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        let guess = match Word::from_str(&input) {
+            Ok(guess) => guess,
+            Err(_) => {
+                println!("\"{}\" is not in the word list.", input);
+                continue;
+            }
+        };
+        match puzzle.check(&guess, &mut attempts) {
+            Ok((grades, _)) => println!("   {}", tiles(&guess, &grades, color)),
+            Err(WordleError::Puzzle {
+                kind: PuzzleError::InvalidHardmodeGuess,
+            }) => println!("On hardmode, each guess must use every hint revealed so far."),
+            Err(e) => return Err(e),
+        }
+    }
+
+    if attempts.solved(&answer) {
+        println!("Solved in {}!\n\n{}", attempts.len(), attempts.share_grid());
+    } else {
+        println!("The word was {}.", answer);
+    }
+
+    Ok(())
+}
+
+/// Shows a guess as colored tiles, or next to its row of the share grid when
+/// `color` is false.
+fn tiles(guess: &Word, grades: &[Grade; 5], color: bool) -> String {
+    if !color {
+        return format!("{} {}", guess, Grade::row_emoji(grades));
+    }
+
+    guess
+        .to_string()
+        .chars()
+        .zip(grades)
+        .map(|(letter, grade)| {
+            let background = match grade {
+                Grade::Correct => 42,
+                Grade::Almost => 43,
+                Grade::Incorrect => 100,
+            };
+            format!(
+                "\x1b[1;30;{}m {} \x1b[0m",
+                background,
+                letter.to_ascii_uppercase()
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,166 @@
+//! Rerunning a strategy crate in the test harness as it changes.
+
+use std::{
+    env::consts::EXE_SUFFIX,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use clap::Args;
+use wordle_rs::{
+    harness::{HarnessBuilder, WordSelection},
+    strategy::subprocess::SubprocessWrapper,
+    Summary, WordleError,
+};
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// The strategy crate to watch, like one made by `wordle_runner new`
+    #[clap(default_value = ".")]
+    path: PathBuf,
+
+    /// The binary that serves the strategy [default: the crate's directory
+    /// name]
+    #[clap(long)]
+    bin: Option<String>,
+
+    /// Runs on this many random answers
+    #[clap(long, short, default_value = "200")]
+    num: usize,
+
+    /// Seeds the choice of random answers, so each run uses the same ones
+    #[clap(long, default_value = "2022")]
+    seed: u64,
+
+    /// Holds the strategy to the hardmode rules
+    #[clap(long)]
+    hardmode: bool,
+
+    /// How often to check for changes, in milliseconds
+    #[clap(long, default_value = "500")]
+    interval: u64,
+
+    /// Where to build the crate [default: its own target directory]
+    #[clap(long)]
+    target_dir: Option<PathBuf>,
+}
+
+/// Rebuilds and reruns the strategy crate each time its sources change,
+/// printing each run's summary against the run before it.
+///
+/// This runs until it is interrupted. Build and harness errors are printed
+/// and the crate is watched for the change that fixes them.
+pub fn watch(args: WatchArgs) -> Result<(), WordleError> {
+    let root = fs::canonicalize(&args.path)?;
+    let bin = match &args.bin {
+        Some(bin) => bin.clone(),
+        None => root
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+    let target_dir = args
+        .target_dir
+        .clone()
+        .unwrap_or_else(|| root.join("target"));
+    let program = target_dir
+        .join("release")
+        .join(format!("{}{}", bin, EXE_SUFFIX));
+
+    println!(
+        "Watching {} and running {} on {} words (seed {})",
+        root.display(),
+        bin,
+        args.num,
+        args.seed
+    );
+
+    let mut last_change = None;
+    let mut previous: Option<Summary> = None;
+    loop {
+        let change = latest_change(&root);
+        if change.is_none() || change == last_change {
+            thread::sleep(Duration::from_millis(args.interval));
+            continue;
+        }
+        last_change = change;
+
+        println!("\nBuilding {}...", bin);
+        let status = Command::new("cargo")
+            .args(["build", "--release", "--bin", &bin, "--target-dir"])
+            .arg(&target_dir)
+            .current_dir(&root)
+            .status()?;
+        if !status.success() {
+            println!("The build failed. Waiting for changes...");
+            continue;
+        }
+
+        match run(&program, &bin, &args) {
+            Ok(summary) => {
+                // Runs that solve too few puzzles cannot be compared, so
+                // those are printed on their own.
+                let options = Summary::print_options();
+                match previous.as_ref().filter(|p| summary.compare(p).is_ok()) {
+                    Some(previous) => summary.print(options.compare(previous))?,
+                    None => summary.print(options)?,
+                }
+                previous = Some(summary);
+            }
+            Err(e) => println!("The run failed: {}", e),
+        }
+        println!("Waiting for changes...");
+    }
+}
+
+/// Runs the built strategy on the watched words and summarizes its
+/// performance.
+fn run(program: &Path, bin: &str, args: &WatchArgs) -> Result<Summary, WordleError> {
+    let strategy = SubprocessWrapper::new(program)
+        .name(bin)
+        .hardmode(args.hardmode);
+
+    let mut builder = HarnessBuilder::new();
+    builder
+        .verbose(false)
+        .add_strategy(Box::new(strategy), None)
+        .words(WordSelection::RandomSample {
+            n: args.num,
+            seed: Some(args.seed),
+        });
+    let record = builder.build()?.run()?;
+
+    if let Some(disqualification) = record[0].disqualification() {
+        println!("{} was disqualified: {}", bin, disqualification);
+    }
+    Ok(record[0].to_summary())
+}
+
+/// Finds when the crate's manifest or sources last changed.
+fn latest_change(root: &Path) -> Option<SystemTime> {
+    let manifest = modified(&root.join("Cargo.toml"));
+    let sources = latest_in(&root.join("src"));
+    manifest.max(sources)
+}
+
+fn latest_in(dir: &Path) -> Option<SystemTime> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                latest_in(&path)
+            } else {
+                modified(&path)
+            }
+        })
+        .max()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).ok()?.modified().ok()
+}
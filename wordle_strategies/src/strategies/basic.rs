@@ -113,6 +113,10 @@ impl Strategy for Basic {
     fn hardmode(&self) -> bool {
         true
     }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 impl Display for Basic {
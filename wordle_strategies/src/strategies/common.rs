@@ -81,6 +81,10 @@ impl Strategy for Common {
     fn hardmode(&self) -> bool {
         true
     }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 impl Display for Common {
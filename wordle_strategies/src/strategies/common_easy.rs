@@ -45,6 +45,10 @@ impl Strategy for CommonEasy {
     fn hardmode(&self) -> bool {
         false
     }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
 }
 
 impl Display for CommonEasy {
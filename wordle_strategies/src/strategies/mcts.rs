@@ -0,0 +1,213 @@
+use std::fmt::Display;
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use wordle_rs::strategy::{
+    encode_grades, grade_batch, Attempts, AttemptsKey, Puzzle, SolveContext, Strategy,
+    StrategyMetadata, Word,
+};
+
+use crate::util::remaining;
+
+/// An easymode Wordle strategy that estimates the value of each guess with
+/// random playouts.
+///
+/// Each turn, the strategy picks a few of the answers that are still possible
+/// as candidate guesses. For each candidate, it plays out a number of random
+/// games: it samples an answer, makes the candidate guess, and then keeps
+/// guessing possible answers at random until it is correct. The candidate
+/// that needed the fewest guesses on average is guessed for real.
+///
+/// The strategy is configured by the number of [rollouts](Mcts::rollouts)
+/// per candidate and the number of candidates, its [width](Mcts::width).
+/// More of either makes better guesses but takes longer.
+///
+/// Because it is random, the strategy only makes the same guesses for the
+/// same puzzle if it is given a [seed](Mcts::seed). The random number
+/// generator for each puzzle is seeded from this seed and the puzzle's
+/// index in the run, so seeded runs can be reproduced exactly even when the
+/// harness runs in parallel.
+///
+/// ```rust
+/// # use wordle_strategies::Mcts;
+/// let strategy = Mcts::new().rollouts(50).width(10).seed(42);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mcts {
+    rollouts: usize,
+    width: usize,
+    seed: Option<u64>,
+}
+
+impl Default for Mcts {
+    fn default() -> Self {
+        Mcts {
+            rollouts: 20,
+            width: 20,
+            seed: None,
+        }
+    }
+}
+
+impl Mcts {
+    /// Creates a new instance with default configuration.
+    ///
+    /// By default, the strategy plays 20 rollouts for each of 20 candidate
+    /// guesses and is not seeded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many random games to play out for each candidate guess.
+    ///
+    /// At least one rollout is always played.
+    pub fn rollouts(self, rollouts: usize) -> Self {
+        Mcts {
+            rollouts: rollouts.max(1),
+            ..self
+        }
+    }
+
+    /// Sets how many candidate guesses to consider each turn.
+    ///
+    /// At least one candidate is always considered.
+    pub fn width(self, width: usize) -> Self {
+        Mcts {
+            width: width.max(1),
+            ..self
+        }
+    }
+
+    /// Seeds the random number generator, making the strategy deterministic.
+    pub fn seed(self, seed: u64) -> Self {
+        Mcts {
+            seed: Some(seed),
+            ..self
+        }
+    }
+
+    /// Solves the puzzle at `index` in the run.
+    fn play(&self, puzzle: &mut Puzzle, key: AttemptsKey, index: usize) -> Attempts {
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(index as u64)),
+            None => StdRng::from_entropy(),
+        };
+        let mut attempts = key.unlock();
+        let mut candidates = Word::ANSWERS.to_vec();
+
+        while !attempts.finished() {
+            let guess = self.choose(&candidates, &mut rng);
+
+            let (grades, got_it) = puzzle.check(&guess, &mut attempts).unwrap();
+            if got_it {
+                break;
+            }
+
+            candidates = remaining(&candidates, &guess, encode_grades(&grades));
+            attempts.report_candidates(candidates.len());
+            if candidates.is_empty() {
+                break;
+            }
+        }
+
+        attempts
+    }
+
+    /// Chooses a guess when `candidates` are the possible answers.
+    fn choose(&self, candidates: &[Word], rng: &mut StdRng) -> Word {
+        if candidates.len() <= 2 {
+            return candidates[0];
+        }
+
+        let pool: Vec<Word> = candidates
+            .choose_multiple(rng, self.width)
+            .copied()
+            .collect();
+
+        pool.into_iter()
+            .map(|guess| (self.estimate(&guess, candidates, rng), guess))
+            .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap())
+            .map(|(_, guess)| guess)
+            .unwrap()
+    }
+
+    /// Estimates the number of guesses needed to solve the puzzle by
+    /// guessing `guess` next.
+    fn estimate(&self, guess: &Word, candidates: &[Word], rng: &mut StdRng) -> f64 {
+        let total: usize = (0..self.rollouts)
+            .map(|_| {
+                let answer = *candidates.choose(rng).unwrap();
+                playout(guess, &answer, candidates, rng)
+            })
+            .sum();
+
+        total as f64 / self.rollouts as f64
+    }
+}
+
+/// Plays a game against `answer`, guessing `first` and then guessing possible
+/// answers at random, and returns the number of guesses made.
+fn playout(first: &Word, answer: &Word, candidates: &[Word], rng: &mut StdRng) -> usize {
+    let mut candidates = candidates.to_vec();
+    let mut guess = *first;
+    let mut guesses = 1;
+
+    while guess != *answer {
+        let mut pattern = [0];
+        grade_batch(&guess, std::slice::from_ref(answer), &mut pattern);
+        candidates = remaining(&candidates, &guess, pattern[0]);
+        guess = *candidates.choose(rng).unwrap();
+        guesses += 1;
+    }
+
+    guesses
+}
+
+impl Strategy for Mcts {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        self.play(puzzle, key, 0)
+    }
+
+    fn solve_with_context(
+        &self,
+        puzzle: &mut Puzzle,
+        key: AttemptsKey,
+        ctx: &SolveContext,
+    ) -> Attempts {
+        self.play(puzzle, key, ctx.index())
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn hardmode(&self) -> bool {
+        false
+    }
+
+    fn metadata(&self) -> StrategyMetadata {
+        StrategyMetadata {
+            description: Some("Estimates guesses with random playouts".to_string()),
+            deterministic: self.seed.is_some(),
+            ..StrategyMetadata::new()
+        }
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl Display for Mcts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wordle_strategies::Mcts (rollouts: {}, width: {})",
+            self.rollouts, self.width
+        )?;
+        if let Some(seed) = self.seed {
+            write!(f, " (seed: {})", seed)?;
+        }
+        Ok(())
+    }
+}
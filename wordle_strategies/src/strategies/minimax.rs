@@ -0,0 +1,229 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use wordle_rs::strategy::{
+    encode_grades, grade_batch, Attempts, AttemptsKey, Puzzle, RunContext, Strategy, Word,
+};
+
+use crate::util::remaining;
+
+/// The pattern of a correct guess, as packed by [`encode_grades()`].
+const CORRECT: u8 = 242;
+
+/// An easymode Wordle strategy that minimizes the worst case, looking one
+/// guess ahead.
+///
+/// Each guess splits the answers that are still possible into buckets by the
+/// grades it would receive. A plain minimax strategy picks the guess whose
+/// largest bucket is smallest. This strategy also looks at the follow-up
+/// guess: for each bucket, it finds the best next guess, and it scores a
+/// guess by the largest bucket left after that follow-up. This sits between
+/// greedy strategies and fully optimal decision trees.
+///
+/// Looking ahead is expensive, so it is limited by a budget: only the
+/// [`budget()`](Minimax::budget) best guesses by plain minimax are looked at
+/// more closely, and an optional [`time_limit()`](Minimax::time_limit) stops
+/// looking ahead early. With a budget of zero, this is plain minimax. Guesses
+/// are chosen from the possible answers, and ties are broken in favor of
+/// guesses that could be the answer.
+///
+/// The first guess is the same for every puzzle, so it is computed once
+/// when the harness calls [`Strategy::prepare()`], unless it is set with
+/// [`first_word()`](Minimax::first_word).
+///
+/// ```rust
+/// # use wordle_strategies::Minimax;
+/// use std::time::Duration;
+///
+/// let strategy = Minimax::new()
+///     .budget(20)
+///     .time_limit(Duration::from_millis(100));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Minimax {
+    budget: usize,
+    time_limit: Option<Duration>,
+    first_word: Option<Word>,
+}
+
+impl Default for Minimax {
+    fn default() -> Self {
+        Minimax {
+            budget: 10,
+            time_limit: None,
+            first_word: None,
+        }
+    }
+}
+
+impl Minimax {
+    /// Creates a new instance with default configuration.
+    ///
+    /// By default, the ten best guesses by plain minimax are looked at more
+    /// closely, with no time limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many of the best guesses by plain minimax to look ahead
+    /// from each turn.
+    pub fn budget(self, budget: usize) -> Self {
+        Minimax { budget, ..self }
+    }
+
+    /// Sets how long to spend looking ahead each turn.
+    ///
+    /// Once the limit is reached, the guesses that have not been looked at
+    /// yet are not considered.
+    pub fn time_limit(self, limit: Duration) -> Self {
+        Minimax {
+            time_limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Makes the strategy guess a specific first word instead of computing
+    /// the best one.
+    pub fn first_word(self, word: Word) -> Self {
+        Minimax {
+            first_word: Some(word),
+            ..self
+        }
+    }
+
+    /// Chooses the best guess when `candidates` are the possible answers.
+    fn choose(&self, candidates: &[Word]) -> Word {
+        if candidates.len() <= 2 {
+            return candidates[0];
+        }
+
+        let mut patterns = vec![0; candidates.len()];
+        let mut scored: Vec<(usize, bool, Word)> = Word::ANSWERS
+            .iter()
+            .map(|guess| {
+                grade_batch(guess, candidates, &mut patterns);
+                let worst = largest_bucket(&patterns);
+                (worst, !candidates.contains(guess), *guess)
+            })
+            .collect();
+        scored.sort_unstable();
+
+        let start = Instant::now();
+        let mut best: Option<(usize, (usize, bool, Word))> = None;
+        for &(worst, not_candidate, guess) in scored.iter().take(self.budget) {
+            if matches!(self.time_limit, Some(limit) if start.elapsed() > limit) {
+                break;
+            }
+
+            let score = (
+                self.lookahead(&guess, candidates),
+                (worst, not_candidate, guess),
+            );
+            if best.map_or(true, |best| score < best) {
+                best = Some(score);
+            }
+        }
+
+        match best {
+            Some((_, (_, _, guess))) => guess,
+            None => scored[0].2,
+        }
+    }
+
+    /// Returns the size of the largest bucket left after guessing `guess` and
+    /// then the best follow-up guess for its grades.
+    fn lookahead(&self, guess: &Word, candidates: &[Word]) -> usize {
+        let mut patterns = vec![0; candidates.len()];
+        grade_batch(guess, candidates, &mut patterns);
+
+        let mut buckets: Vec<Vec<Word>> = vec![Vec::new(); CORRECT as usize + 1];
+        for (&pattern, &candidate) in patterns.iter().zip(candidates) {
+            if pattern != CORRECT {
+                buckets[pattern as usize].push(candidate);
+            }
+        }
+
+        buckets
+            .iter()
+            .filter(|bucket| bucket.len() > 1)
+            .map(|bucket| {
+                let mut patterns = vec![0; bucket.len()];
+                bucket
+                    .iter()
+                    .map(|follow_up| {
+                        grade_batch(follow_up, bucket, &mut patterns);
+                        largest_bucket(&patterns)
+                    })
+                    .min()
+                    .unwrap()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Returns the number of words in the largest group of equal patterns,
+/// ignoring the correct pattern.
+fn largest_bucket(patterns: &[u8]) -> usize {
+    let mut counts = [0; CORRECT as usize];
+    for &pattern in patterns {
+        if pattern != CORRECT {
+            counts[pattern as usize] += 1;
+        }
+    }
+    counts.iter().copied().max().unwrap_or(0)
+}
+
+impl Strategy for Minimax {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        let mut attempts = key.unlock();
+        let mut candidates = Word::ANSWERS.to_vec();
+
+        while !attempts.finished() {
+            let guess = match (attempts.inner().is_empty(), self.first_word) {
+                (true, Some(first)) => first,
+                _ => self.choose(&candidates),
+            };
+
+            let (grades, got_it) = puzzle.check(&guess, &mut attempts).unwrap();
+            if got_it {
+                break;
+            }
+
+            candidates = remaining(&candidates, &guess, encode_grades(&grades));
+            attempts.report_candidates(candidates.len());
+            if candidates.is_empty() {
+                break;
+            }
+        }
+
+        attempts
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn hardmode(&self) -> bool {
+        false
+    }
+
+    fn prepare(&mut self, ctx: &RunContext) {
+        let _ = ctx;
+        if self.first_word.is_none() {
+            self.first_word = Some(self.choose(Word::ANSWERS));
+        }
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl Display for Minimax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wordle_strategies::Minimax (budget: {})", self.budget)
+    }
+}
@@ -14,3 +14,15 @@ pub use narrowing::Narrowing;
 
 mod common_easy;
 pub use common_easy::CommonEasy;
+
+mod precomputed;
+pub use precomputed::{Precomputed, TreeError};
+
+mod minimax;
+pub use minimax::Minimax;
+
+mod mcts;
+pub use mcts::Mcts;
+
+mod positional;
+pub use positional::Positional;
@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+use wordle_rs::strategy::{encode_grades, Attempts, AttemptsKey, Puzzle, Strategy, Word};
+
+use crate::util::remaining;
+
+/// A hard- or easymode Wordle strategy that guesses words whose letters are
+/// common in the positions they appear.
+///
+/// Each round, the strategy counts how often each letter appears in each of
+/// the five positions among the words that could still be the answer. A
+/// guess is scored by adding up these counts for its letters, counting each
+/// repeated letter only once. This is smarter than [Common](crate::Common),
+/// which ignores where letters appear, but much cheaper than strategies
+/// that compare every possible guess against every possible answer.
+///
+/// On hardmode, the strategy only guesses words that could be the answer. On
+/// easymode, it may guess any word, but prefers words that could be the
+/// answer when scores are tied. By default, only the Wordle answers are
+/// considered; with [`answers_only(false)`](Positional::answers_only), every
+/// allowed guess is treated as a possible answer.
+///
+/// ```rust
+/// # use wordle_strategies::Positional;
+/// let strategy = Positional::new().hardmode(true).answers_only(false);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Positional {
+    hardmode: bool,
+    answers_only: bool,
+}
+
+impl Default for Positional {
+    fn default() -> Self {
+        Positional {
+            hardmode: false,
+            answers_only: true,
+        }
+    }
+}
+
+impl Positional {
+    /// Creates a new instance with default configuration.
+    ///
+    /// By default, the strategy plays easymode and only considers the Wordle
+    /// answers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the strategy plays hardmode.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        Positional { hardmode, ..self }
+    }
+
+    /// Sets whether only the Wordle answers are considered as possible
+    /// answers and easymode guesses, instead of every allowed guess.
+    pub fn answers_only(self, answers_only: bool) -> Self {
+        Positional {
+            answers_only,
+            ..self
+        }
+    }
+
+    /// Gets the words that may be the answer before any guesses.
+    fn pool(&self) -> &'static [Word] {
+        if self.answers_only {
+            Word::ANSWERS
+        } else {
+            Word::ALL
+        }
+    }
+
+    /// Chooses the best guess when `candidates` are the possible answers.
+    fn choose(&self, candidates: &[Word]) -> Word {
+        if candidates.len() <= 2 {
+            return candidates[0];
+        }
+
+        let mut counts = [[0_u32; 26]; 5];
+        for word in candidates {
+            for (slot, c) in word.bytes().enumerate() {
+                counts[slot][(c - b'a') as usize] += 1;
+            }
+        }
+
+        let score = |word: &Word| -> u32 {
+            let bytes = word.as_bytes();
+            bytes
+                .iter()
+                .enumerate()
+                .filter(|&(slot, c)| !bytes[..slot].contains(c))
+                .map(|(slot, &c)| counts[slot][(c - b'a') as usize])
+                .sum()
+        };
+
+        let guesses = if self.hardmode {
+            candidates
+        } else {
+            self.pool()
+        };
+        let best = |guesses: &[Word]| {
+            guesses
+                .iter()
+                .copied()
+                .max_by_key(|word| (score(word), std::cmp::Reverse(word.index())))
+        };
+
+        match (best(guesses), best(candidates)) {
+            (Some(guess), Some(candidate)) if score(&candidate) >= score(&guess) => candidate,
+            (Some(guess), _) => guess,
+            (None, _) => candidates[0],
+        }
+    }
+}
+
+impl Strategy for Positional {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        let mut attempts = key.unlock();
+        let mut candidates = self.pool().to_vec();
+
+        while !attempts.finished() {
+            let guess = self.choose(&candidates);
+
+            let (grades, got_it) = puzzle.check(&guess, &mut attempts).unwrap();
+            if got_it {
+                break;
+            }
+
+            candidates = remaining(&candidates, &guess, encode_grades(&grades));
+            attempts.report_candidates(candidates.len());
+            if candidates.is_empty() {
+                break;
+            }
+        }
+
+        attempts
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn hardmode(&self) -> bool {
+        self.hardmode
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl Display for Positional {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wordle_strategies::Positional ({}, {})",
+            if self.hardmode {
+                "hardmode"
+            } else {
+                "easymode"
+            },
+            if self.answers_only {
+                "answers only"
+            } else {
+                "all words"
+            }
+        )
+    }
+}
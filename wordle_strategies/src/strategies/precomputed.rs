@@ -0,0 +1,297 @@
+use std::{collections::HashMap, error::Error, fmt::Display, path::Path};
+
+use wordle_rs::strategy::{
+    encode_grades, grade_batch, Attempts, AttemptsKey, Grade, Puzzle, Strategy, Word,
+};
+
+/// The pattern of a correct guess, as packed by [`encode_grades()`].
+const CORRECT: u8 = 242;
+
+/// A Wordle strategy that plays a precomputed decision tree.
+///
+/// Decision trees list the guess to make for every possible sequence of
+/// grades, so they can encode optimal play. Running one in the harness gives
+/// a reference ceiling for other strategies: the best known tree for the
+/// Wordle answers averages 3.4212 guesses.
+///
+/// Trees are read from text with one line per answer, listing each guess
+/// followed by its grades until the answer is guessed. Grades are written
+/// with one letter per letter of the guess: `G` for correct, `Y` for almost
+/// and `B` for incorrect. This is the format that published optimal trees
+/// commonly use, but any row accepted by
+/// [`Grade::parse_row()`](wordle_rs::strategy::Grade::parse_row) works.
+/// Blank lines and lines starting with `#` are ignored.
+///
+/// If the tree has no guess for the grades the strategy receives, e.g.
+/// because the answer is not in the tree, it stops guessing and the puzzle
+/// ends unsolved.
+///
+/// # Examples
+///
+/// ```rust
+/// # use wordle_rs::{Harness, Word, WordSelection};
+/// # use wordle_strategies::Precomputed;
+/// let tree = "\
+/// doubt GGGGG
+/// doubt BBBBY tithe GGGGG
+/// doubt BBBBB parka GGGGG
+/// ";
+///
+/// let strategy = Precomputed::parse(tree)?;
+/// assert!((strategy.expected_guesses() - 5. / 3.).abs() < 1e-9);
+///
+/// let words = ["doubt", "tithe", "parka"]
+///     .iter()
+///     .map(|w| Word::from_str(w))
+///     .collect::<Result<_, _>>()?;
+/// let record = Harness::new()
+///     .verbose(false)
+///     .add_strategy(Box::new(strategy), None)
+///     .words(WordSelection::Explicit(words))
+///     .run()?;
+/// assert_eq!(record[0].num_solved(), 3);
+/// #
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Trees are usually loaded from a file with [`load()`](Precomputed::load):
+///
+/// ```rust,no_run
+/// # use wordle_strategies::Precomputed;
+/// let strategy = Precomputed::load("salet.tree.txt")?;
+/// # Ok::<_, wordle_strategies::TreeError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Precomputed {
+    root: Node,
+    hardmode: bool,
+}
+
+/// A node of a decision tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    guess: Word,
+    // Whether a path in the tree ends with this guess being correct.
+    answer: bool,
+    // The next node for each pattern of grades, packed by `encode_grades()`.
+    children: HashMap<u8, Node>,
+}
+
+impl Node {
+    fn new(guess: Word) -> Self {
+        Node {
+            guess,
+            answer: false,
+            children: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of answers below this node and the total number of
+    /// guesses needed to solve them, with this node at `depth`.
+    fn count(&self, depth: u32) -> (u32, u32) {
+        self.children.values().fold(
+            if self.answer { (1, depth) } else { (0, 0) },
+            |(answers, guesses), child| {
+                let (a, g) = child.count(depth + 1);
+                (answers + a, guesses + g)
+            },
+        )
+    }
+}
+
+impl Precomputed {
+    /// Reads a decision tree from the file at `path`.
+    ///
+    /// See the [type-level documentation](Precomputed) for the format.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TreeError> {
+        let tree = std::fs::read_to_string(path).map_err(TreeError::Read)?;
+        Self::parse(&tree)
+    }
+
+    /// Parses a decision tree.
+    ///
+    /// See the [type-level documentation](Precomputed) for the format.
+    /// Returns an error if a line is malformed, its grades do not match its
+    /// answer or it disagrees with an earlier line about which word to guess.
+    pub fn parse(tree: &str) -> Result<Self, TreeError> {
+        let mut root: Option<Node> = None;
+
+        for (i, line) in tree.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |reason: String| TreeError::Parse {
+                line: i + 1,
+                reason,
+            };
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() % 2 != 0 {
+                return Err(error("expected pairs of guesses and grades".to_string()));
+            }
+            let steps = tokens
+                .chunks(2)
+                .map(|pair| {
+                    let guess = Word::from_str(pair[0])
+                        .map_err(|_| error(format!("invalid guess \"{}\"", pair[0])))?;
+                    let grades = Grade::parse_row(pair[1])
+                        .map_err(|_| error(format!("invalid grades \"{}\"", pair[1])))?;
+                    Ok((guess, encode_grades(&grades)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let answer = steps[steps.len() - 1].0;
+            for (k, (guess, pattern)) in steps.iter().enumerate() {
+                let mut expected = [0];
+                grade_batch(guess, &[answer], &mut expected);
+                if *pattern != expected[0] {
+                    return Err(error(format!("wrong grades for {}", guess)));
+                }
+                if *pattern == CORRECT && k != steps.len() - 1 {
+                    return Err(error("guesses continue after the answer".to_string()));
+                }
+            }
+            if steps[steps.len() - 1].1 != CORRECT {
+                return Err(error("the answer is never guessed".to_string()));
+            }
+
+            let mut node = root.get_or_insert_with(|| Node::new(steps[0].0));
+            for (k, (guess, pattern)) in steps.iter().enumerate() {
+                if node.guess != *guess {
+                    return Err(error(format!(
+                        "guesses {} where another line guesses {}",
+                        guess, node.guess
+                    )));
+                }
+                match steps.get(k + 1) {
+                    Some((next, _)) => {
+                        node = node
+                            .children
+                            .entry(*pattern)
+                            .or_insert_with(|| Node::new(*next));
+                    }
+                    None => node.answer = true,
+                }
+            }
+        }
+
+        root.map(|root| Precomputed {
+            root,
+            hardmode: false,
+        })
+        .ok_or(TreeError::Empty)
+    }
+
+    /// Sets whether the strategy plays hardmode.
+    ///
+    /// This only declares how the tree plays; if a tree that breaks the
+    /// hardmode rules is played in hardmode, the puzzle ends unsolved at the
+    /// first invalid guess.
+    pub fn hardmode(self, hardmode: bool) -> Self {
+        Precomputed { hardmode, ..self }
+    }
+
+    /// Gets the first guess of the tree.
+    pub fn first_guess(&self) -> Word {
+        self.root.guess
+    }
+
+    /// Gets the number of answers the tree can solve.
+    pub fn num_answers(&self) -> u32 {
+        self.root.count(1).0
+    }
+
+    /// Gets the average number of guesses the tree needs to solve each of
+    /// its answers.
+    pub fn expected_guesses(&self) -> f64 {
+        let (answers, guesses) = self.root.count(1);
+        guesses as f64 / answers as f64
+    }
+}
+
+impl Strategy for Precomputed {
+    fn solve(&self, puzzle: &mut Puzzle, key: AttemptsKey) -> Attempts {
+        let mut attempts = key.unlock();
+        let mut node = &self.root;
+
+        loop {
+            let (grades, correct) = match puzzle.check(&node.guess, &mut attempts) {
+                Ok(result) => result,
+                Err(_) => break,
+            };
+            if correct || attempts.finished() {
+                break;
+            }
+
+            match node.children.get(&encode_grades(&grades)) {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+
+        attempts
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn hardmode(&self) -> bool {
+        self.hardmode
+    }
+
+    fn boxed_clone(&self) -> Option<Box<dyn Strategy>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl Display for Precomputed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wordle_strategies::Precomputed (start: {})",
+            self.root.guess
+        )
+    }
+}
+
+/// The errors that can occur while loading a [`Precomputed`] decision tree.
+#[derive(Debug)]
+pub enum TreeError {
+    /// The file containing the tree could not be read.
+    Read(std::io::Error),
+
+    /// A line of the tree is invalid.
+    Parse {
+        /// The line number, starting from 1.
+        line: usize,
+
+        /// What is wrong with the line.
+        reason: String,
+    },
+
+    /// The tree has no lines.
+    Empty,
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TreeError::Read(e) => write!(f, "could not read decision tree: {}", e),
+            TreeError::Parse { line, reason } => {
+                write!(f, "invalid decision tree on line {}: {}", line, reason)
+            }
+            TreeError::Empty => write!(f, "the decision tree is empty"),
+        }
+    }
+}
+
+impl Error for TreeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TreeError::Read(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use regex::bytes::{Regex, RegexBuilder};
 
-use wordle_rs::strategy::{Grade, Word};
+use wordle_rs::strategy::{grade_batch, Grade, Word};
 
 fn generate_regex<'a>(
     correct: &[(usize, char)],
@@ -57,6 +57,36 @@ pub fn occurrences(c: char) -> u32 {
     }
 }
 
+/// Returns the words in `candidates` that would give `guess` the grades
+/// packed in `pattern` by [`encode_grades()`](wordle_rs::strategy::encode_grades),
+/// i.e. the answers that are still possible after the guess.
+///
+/// # Examples
+///
+/// ```
+/// # use wordle_rs::{strategy::encode_grades, Grade::*, Word};
+/// # use wordle_strategies::util::remaining;
+/// let words: Vec<Word> = ["doubt", "tithe", "parka"]
+///     .iter()
+///     .map(|w| Word::from_str(w))
+///     .collect::<Result<_, _>>()?;
+///
+/// let pattern = encode_grades(&[Incorrect, Incorrect, Incorrect, Incorrect, Almost]);
+/// assert_eq!(remaining(&words, &words[0], pattern), [words[1]]);
+/// #
+/// # Ok::<_, wordle_rs::WordleError>(())
+/// ```
+pub fn remaining(candidates: &[Word], guess: &Word, pattern: u8) -> Vec<Word> {
+    let mut patterns = vec![0; candidates.len()];
+    grade_batch(guess, candidates, &mut patterns);
+    candidates
+        .iter()
+        .zip(&patterns)
+        .filter(|(_, &p)| p == pattern)
+        .map(|(&word, _)| word)
+        .collect()
+}
+
 /// A struct that can track the information returned by
 /// [`Puzzle::check()`](wordle_rs::Puzzle::check).
 #[derive(Default, Debug)]